@@ -1,10 +1,27 @@
+use crate::internal::filter::Filter;
 use derive_builder::Builder;
+use kernel::entity::{MessageFilterRule, RateLimitRule};
+use std::collections::HashMap;
+use std::time::Duration;
 
+#[allow(clippy::too_many_arguments)]
 #[derive(Clone, Debug, Default, new, Builder)]
 #[builder(setter(into))]
 pub struct Config {
     pub(crate) auth: Auth,
     pub(crate) system: System,
+    #[builder(default)]
+    pub(crate) webhook: Webhook,
+    #[builder(default)]
+    pub(crate) message_filter: MessageFilter,
+    #[builder(default)]
+    pub(crate) rate_limit: RateLimit,
+    #[builder(default)]
+    pub(crate) health: Health,
+    #[builder(default)]
+    pub(crate) idempotency: Idempotency,
+    #[builder(default)]
+    pub(crate) analytics: Analytics,
 }
 #[derive(Clone, Debug, Default, new, Builder)]
 #[builder(setter(into))]
@@ -18,3 +35,120 @@ pub struct Auth {
     pub(crate) pepper: String,
     pub(crate) stretch_count: i64,
 }
+
+#[derive(Clone, Debug, Default, new, Builder)]
+#[builder(setter(into))]
+pub struct Webhook {
+    pub(crate) max_attempts: i32,
+    pub(crate) initial_backoff: Duration,
+    pub(crate) max_backoff: Duration,
+    /// deliveries in progress at once, across all webhooks.
+    #[builder(default = "16")]
+    pub(crate) max_concurrency: usize,
+    /// messages waiting for a webhook, more hold the subscription of its channel back.
+    #[builder(default = "100")]
+    pub(crate) queue_size: usize,
+}
+
+/// filter chains keyed by channel id. `*` is used for channels without their own chain.
+#[derive(Clone, Debug, Default, Builder)]
+#[builder(setter(into))]
+pub struct MessageFilter {
+    /// set as rules, whose patterns are compiled once rather than for each message.
+    #[builder(
+        setter(name = "rules"),
+        field(
+            type = "HashMap<String, Vec<MessageFilterRule>>",
+            build = "compile_filters(&self.filters)?"
+        )
+    )]
+    pub(crate) filters: HashMap<String, Vec<Filter>>,
+}
+
+fn compile_filters(
+    rules: &HashMap<String, Vec<MessageFilterRule>>,
+) -> Result<HashMap<String, Vec<Filter>>, MessageFilterBuilderError> {
+    let mut filters = HashMap::new();
+    for (channel_id, chain) in rules {
+        let chain = chain
+            .iter()
+            .map(Filter::compile)
+            .collect::<kernel::Result<Vec<_>>>()
+            .map_err(|e| MessageFilterBuilderError::ValidationError(e.to_string()))?;
+        filters.insert(channel_id.clone(), chain);
+    }
+    Ok(filters)
+}
+
+/// rate limit rules, the first one matching a request applies.
+#[derive(Clone, Debug, Default, new, Builder)]
+#[builder(setter(into), build_fn(validate = "Self::validate"))]
+pub struct RateLimit {
+    #[builder(default)]
+    pub(crate) rules: Vec<RateLimitRule>,
+    /// paths never limited, whatever the rules, e.g. the status probed by monitoring.
+    /// like the paths of the rules, they are whole request paths with the `/api/v1` prefix.
+    #[builder(default)]
+    pub(crate) exempt_paths: Vec<String>,
+}
+
+impl RateLimit {
+    pub const DEFAULT_EXEMPT_PATHS: &'static str = "/api/v1/status";
+}
+
+impl RateLimitBuilder {
+    fn validate(&self) -> Result<(), String> {
+        for rule in self.rules.iter().flatten() {
+            if rule.capacity == 0 || rule.refill_per_second <= 0.0 {
+                return Err(format!(
+                    "rate limit rule for {} must have a positive capacity and refill.",
+                    rule.path
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// how long a readiness check waits for each dependency.
+#[derive(Clone, Debug, new, Builder)]
+#[builder(setter(into))]
+pub struct Health {
+    pub(crate) timeout: Duration,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Health::new(Duration::from_secs(1))
+    }
+}
+
+#[derive(Clone, Debug, new, Builder)]
+#[builder(setter(into))]
+pub struct Idempotency {
+    /// how long a response is replayed for its key.
+    pub(crate) ttl: Duration,
+    /// how long a key stays locked by a request that never completes, e.g. on a crash.
+    pub(crate) lock_ttl: Duration,
+}
+
+impl Default for Idempotency {
+    fn default() -> Self {
+        Idempotency::new(Duration::from_secs(86400), Duration::from_secs(60))
+    }
+}
+
+#[derive(Clone, Debug, new, Builder)]
+#[builder(setter(into))]
+pub struct Analytics {
+    /// how many events are kept per tracking id, the oldest are dropped first.
+    pub(crate) max_events: usize,
+    /// how long the events of a tracking id are kept after its last event.
+    pub(crate) retention: Duration,
+}
+
+impl Default for Analytics {
+    fn default() -> Self {
+        Analytics::new(1000, Duration::from_secs(86400 * 90))
+    }
+}
@@ -0,0 +1,52 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+use std::fmt::{Display, Formatter};
+
+/// the dependencies a node needs to serve traffic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dependency {
+    /// the relational database, whichever backend it runs on.
+    Database,
+    RedisPrimary,
+    RedisReader,
+}
+
+impl Display for Dependency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Dependency::Database => write!(f, "database"),
+            Dependency::RedisPrimary => write!(f, "redis_primary"),
+            Dependency::RedisReader => write!(f, "redis_reader"),
+        }
+    }
+}
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait HealthGateway<Context>: Component {
+    async fn ping(&self, ctx: Context, dependency: Dependency) -> Result<()>;
+    /// the name the dependency is reported under, e.g. the backend of the database.
+    fn name(&self, ctx: &Context, dependency: Dependency) -> String;
+}
+
+pub trait UseHealthGateway<Context> {
+    type Gateway: HealthGateway<Context>;
+    fn health_gateway(&self) -> Self::Gateway;
+}
+
+#[cfg(test)]
+mock! {
+    pub HealthGateway{}
+    impl Clone for HealthGateway {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl HealthGateway<()> for HealthGateway {
+        async fn ping(&self, ctx: (), dependency: Dependency) -> Result<()>;
+        fn name(&self, ctx: &(), dependency: Dependency) -> String;
+    }
+}
@@ -6,11 +6,21 @@ use kernel::Result;
 use mockall::mock;
 use tokio::sync::mpsc::Receiver;
 
+/// what a subscription yields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionEvent {
+    Message(Vec<u8>),
+    /// the subscription was re-established after a dropped connection.
+    /// messages published in the meantime are lost.
+    Reconnected,
+}
+
 #[async_trait]
 #[blanket(derive(Arc))]
 pub trait PubSubGateway<Context>: Component {
     async fn publish(&self, ctx: Context, channel: String, message: &[u8]) -> Result<()>;
-    async fn subscribe(&self, ctx: Context, channel: String) -> Result<Receiver<Vec<u8>>>;
+    async fn subscribe(&self, ctx: Context, channel: String)
+        -> Result<Receiver<SubscriptionEvent>>;
 }
 
 pub trait UsePubSubGateway<Context> {
@@ -27,6 +37,7 @@ mock! {
     #[async_trait]
     impl PubSubGateway<()> for PubSubGateway {
         async fn publish(&self, ctx: (), channel: String, message: &[u8]) -> Result<()>;
-        async fn subscribe(&self, ctx: (), channel: String) -> Result<Receiver<Vec<u8>>>;
+        async fn subscribe(&self, ctx: (), channel: String)
+            -> Result<Receiver<SubscriptionEvent>>;
     }
 }
@@ -0,0 +1,53 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+use std::time::Duration;
+
+/// the state of a token bucket after trying to take a token from it.
+#[derive(Debug, Clone, PartialEq, Eq, new)]
+pub struct Bucket {
+    pub allowed: bool,
+    pub remaining: u32,
+    /// until a token is available again.
+    pub retry_after: Duration,
+    /// until the bucket is full again.
+    pub reset_after: Duration,
+}
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait RateLimitGateway<Context>: Component {
+    async fn acquire(
+        &self,
+        ctx: Context,
+        key: String,
+        capacity: u32,
+        refill_per_second: f64,
+    ) -> Result<Bucket>;
+}
+
+pub trait UseRateLimitGateway<Context> {
+    type Gateway: RateLimitGateway<Context>;
+    fn rate_limit_gateway(&self) -> Self::Gateway;
+}
+
+#[cfg(test)]
+mock! {
+    pub RateLimitGateway{}
+    impl Clone for RateLimitGateway {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl RateLimitGateway<()> for RateLimitGateway {
+        async fn acquire(
+            &self,
+            ctx: (),
+            key: String,
+            capacity: u32,
+            refill_per_second: f64,
+        ) -> Result<Bucket>;
+    }
+}
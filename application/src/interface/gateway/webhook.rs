@@ -0,0 +1,46 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+use std::net::SocketAddr;
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait WebhookGateway<Context>: Component {
+    async fn resolve(&self, ctx: Context, host: String, port: u16) -> Result<Vec<SocketAddr>>;
+    async fn post(&self, ctx: Context, request: WebhookRequest) -> Result<WebhookResponse>;
+}
+
+pub trait UseWebhookGateway<Context> {
+    type Gateway: WebhookGateway<Context>;
+    fn webhook_gateway(&self) -> Self::Gateway;
+}
+
+#[derive(new, Debug, Clone)]
+pub struct WebhookRequest {
+    pub url: String,
+    /// the checked addresses of the host, it is not resolved again when posting.
+    pub addrs: Vec<SocketAddr>,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+#[derive(new, Debug, Clone)]
+pub struct WebhookResponse {
+    pub status: u16,
+}
+
+#[cfg(test)]
+mock! {
+    pub WebhookGateway{}
+    impl Clone for WebhookGateway {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl WebhookGateway<()> for WebhookGateway {
+        async fn resolve(&self, ctx: (), host: String, port: u16) -> Result<Vec<SocketAddr>>;
+        async fn post(&self, ctx: (), request: WebhookRequest) -> Result<WebhookResponse>;
+    }
+}
@@ -2,6 +2,7 @@ use crate::interface::Component;
 use async_trait::async_trait;
 use blanket::blanket;
 use kernel::entity::Account;
+use kernel::i18n::Locale;
 use kernel::Result;
 #[cfg(test)]
 use mockall::mock;
@@ -11,6 +12,7 @@ use mockall::mock;
 pub trait AccountRepository<Context>: Component {
     async fn get(&self, ctx: Context, id: String) -> Result<Option<Account>>;
     async fn create(&self, ctx: Context, account: Account) -> Result<Account>;
+    async fn update_locale(&self, ctx: Context, id: String, locale: Locale) -> Result<()>;
 }
 
 pub trait UseAccountRepository<Context> {
@@ -28,5 +30,6 @@ mock! {
     impl AccountRepository<()> for AccountRepository {
         async fn get(&self, ctx: (), id: String) -> Result<Option<Account>>;
         async fn create(&self, ctx: (), account: Account) -> Result<Account>;
+        async fn update_locale(&self, ctx: (), id: String, locale: Locale) -> Result<()>;
     }
 }
@@ -0,0 +1,44 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use kernel::entity::AnalyticsEvent;
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+use std::time::Duration;
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait AnalyticsRepository<Context>: Component {
+    /// keeps the latest `max_events` per tracking id, for `retention` after the last one.
+    async fn record(
+        &self,
+        ctx: Context,
+        event: AnalyticsEvent,
+        max_events: usize,
+        retention: Duration,
+    ) -> Result<()>;
+}
+
+pub trait UseAnalyticsRepository<Context> {
+    type AnalyticsRepository: AnalyticsRepository<Context>;
+    fn analytics_repository(&self) -> Self::AnalyticsRepository;
+}
+
+#[cfg(test)]
+mock! {
+    pub AnalyticsRepository{}
+    impl Clone for AnalyticsRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl AnalyticsRepository<()> for AnalyticsRepository {
+        async fn record(
+            &self,
+            ctx: (),
+            event: AnalyticsEvent,
+            max_events: usize,
+            retention: Duration,
+        ) -> Result<()>;
+    }
+}
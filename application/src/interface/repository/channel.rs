@@ -0,0 +1,72 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use kernel::entity::{ChannelModerator, ReadPosition, UnreadCount};
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait ChannelRepository<Context>: Component {
+    async fn is_moderator(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<bool>;
+    /// the moderators of a channel, the earliest first.
+    async fn find_moderators(
+        &self,
+        ctx: Context,
+        channel_id: String,
+    ) -> Result<Vec<ChannelModerator>>;
+    async fn add_moderator(&self, ctx: Context, moderator: ChannelModerator) -> Result<()>;
+    async fn remove_moderator(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<()>;
+    async fn get_read_position(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<Option<ReadPosition>>;
+    async fn save_read_position(&self, ctx: Context, position: ReadPosition) -> Result<()>;
+    /// counts unread messages in every channel the account posted in, moderates or has read.
+    /// a channel without a read position is counted from its first message.
+    async fn count_unread(&self, ctx: Context, account_id: String) -> Result<Vec<UnreadCount>>;
+}
+
+pub trait UseChannelRepository<Context> {
+    type ChannelRepository: ChannelRepository<Context>;
+    fn channel_repository(&self) -> Self::ChannelRepository;
+}
+
+#[cfg(test)]
+mock! {
+    pub ChannelRepository{}
+    impl Clone for ChannelRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl ChannelRepository<()> for ChannelRepository {
+        async fn is_moderator(&self, ctx: (), channel_id: String, account_id: String)
+            -> Result<bool>;
+        async fn find_moderators(&self, ctx: (), channel_id: String)
+            -> Result<Vec<ChannelModerator>>;
+        async fn add_moderator(&self, ctx: (), moderator: ChannelModerator) -> Result<()>;
+        async fn remove_moderator(&self, ctx: (), channel_id: String, account_id: String)
+            -> Result<()>;
+        async fn get_read_position(
+            &self,
+            ctx: (),
+            channel_id: String,
+            account_id: String,
+        ) -> Result<Option<ReadPosition>>;
+        async fn save_read_position(&self, ctx: (), position: ReadPosition) -> Result<()>;
+        async fn count_unread(&self, ctx: (), account_id: String) -> Result<Vec<UnreadCount>>;
+    }
+}
@@ -0,0 +1,60 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use kernel::entity::IdempotencyRecord;
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+use std::time::Duration;
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait IdempotencyRepository<Context>: Component {
+    /// stores `record` unless the key is taken, returning the record already stored otherwise.
+    async fn insert(
+        &self,
+        ctx: Context,
+        key: String,
+        record: IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<Option<IdempotencyRecord>>;
+    async fn set(
+        &self,
+        ctx: Context,
+        key: String,
+        record: IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<()>;
+    async fn delete(&self, ctx: Context, key: String) -> Result<()>;
+}
+
+pub trait UseIdempotencyRepository<Context> {
+    type IdempotencyRepository: IdempotencyRepository<Context>;
+    fn idempotency_repository(&self) -> Self::IdempotencyRepository;
+}
+
+#[cfg(test)]
+mock! {
+    pub IdempotencyRepository{}
+    impl Clone for IdempotencyRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl IdempotencyRepository<()> for IdempotencyRepository {
+        async fn insert(
+            &self,
+            ctx: (),
+            key: String,
+            record: IdempotencyRecord,
+            ttl: Duration,
+        ) -> Result<Option<IdempotencyRecord>>;
+        async fn set(
+            &self,
+            ctx: (),
+            key: String,
+            record: IdempotencyRecord,
+            ttl: Duration,
+        ) -> Result<()>;
+        async fn delete(&self, ctx: (), key: String) -> Result<()>;
+    }
+}
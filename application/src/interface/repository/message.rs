@@ -0,0 +1,95 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use chrono::{DateTime, Utc};
+use kernel::entity::{Message, MessageEdit, Reaction, ReactionCount};
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait MessageRepository<Context>: Component {
+    async fn get(&self, ctx: Context, id: String) -> Result<Option<Message>>;
+    async fn create(&self, ctx: Context, message: Message) -> Result<Message>;
+    async fn update(&self, ctx: Context, message: Message) -> Result<()>;
+    async fn find_replies(&self, ctx: Context, parent_id: String) -> Result<Vec<Message>>;
+    /// the messages of the channel created after the given one, oldest first.
+    async fn find_after(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        message_id: String,
+        limit: u64,
+    ) -> Result<Vec<Message>>;
+    /// the oldest message of the channel, deleted or not.
+    async fn find_first(&self, ctx: Context, channel_id: String) -> Result<Option<Message>>;
+    async fn increment_reply_count(&self, ctx: Context, id: String, delta: i64) -> Result<()>;
+    async fn delete(
+        &self,
+        ctx: Context,
+        id: String,
+        deleted_by: String,
+        delete_time: DateTime<Utc>,
+    ) -> Result<()>;
+    async fn create_edit(&self, ctx: Context, edit: MessageEdit) -> Result<()>;
+    async fn find_edits(&self, ctx: Context, message_id: String) -> Result<Vec<MessageEdit>>;
+    async fn add_reaction(&self, ctx: Context, reaction: Reaction) -> Result<()>;
+    async fn remove_reaction(
+        &self,
+        ctx: Context,
+        message_id: String,
+        account_id: String,
+        emoji: String,
+    ) -> Result<()>;
+    async fn count_reactions(&self, ctx: Context, message_id: String)
+        -> Result<Vec<ReactionCount>>;
+}
+
+pub trait UseMessageRepository<Context> {
+    type MessageRepository: MessageRepository<Context>;
+    fn message_repository(&self) -> Self::MessageRepository;
+}
+
+#[cfg(test)]
+mock! {
+    pub MessageRepository{}
+    impl Clone for MessageRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl MessageRepository<()> for MessageRepository {
+        async fn get(&self, ctx: (), id: String) -> Result<Option<Message>>;
+        async fn create(&self, ctx: (), message: Message) -> Result<Message>;
+        async fn update(&self, ctx: (), message: Message) -> Result<()>;
+        async fn find_replies(&self, ctx: (), parent_id: String) -> Result<Vec<Message>>;
+        async fn find_after(
+            &self,
+            ctx: (),
+            channel_id: String,
+            message_id: String,
+            limit: u64,
+        ) -> Result<Vec<Message>>;
+        async fn find_first(&self, ctx: (), channel_id: String) -> Result<Option<Message>>;
+        async fn increment_reply_count(&self, ctx: (), id: String, delta: i64) -> Result<()>;
+        async fn delete(
+            &self,
+            ctx: (),
+            id: String,
+            deleted_by: String,
+            delete_time: DateTime<Utc>,
+        ) -> Result<()>;
+        async fn create_edit(&self, ctx: (), edit: MessageEdit) -> Result<()>;
+        async fn find_edits(&self, ctx: (), message_id: String) -> Result<Vec<MessageEdit>>;
+        async fn add_reaction(&self, ctx: (), reaction: Reaction) -> Result<()>;
+        async fn remove_reaction(
+            &self,
+            ctx: (),
+            message_id: String,
+            account_id: String,
+            emoji: String,
+        ) -> Result<()>;
+        async fn count_reactions(&self, ctx: (), message_id: String)
+            -> Result<Vec<ReactionCount>>;
+    }
+}
@@ -1,7 +1,12 @@
 pub mod account;
+pub mod analytics;
 pub mod authentication;
+pub mod channel;
 pub mod comment;
+pub mod idempotency;
+pub mod message;
 pub mod session;
+pub mod webhook;
 
 use crate::interface::Component;
 use async_trait::async_trait;
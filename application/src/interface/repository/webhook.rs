@@ -0,0 +1,52 @@
+use crate::interface::Component;
+use async_trait::async_trait;
+use blanket::blanket;
+use kernel::entity::{Webhook, WebhookDelivery};
+use kernel::Result;
+#[cfg(test)]
+use mockall::mock;
+
+#[async_trait]
+#[blanket(derive(Arc))]
+pub trait WebhookRepository<Context>: Component {
+    async fn get(&self, ctx: Context, id: String) -> Result<Option<Webhook>>;
+    async fn find_by_channel(&self, ctx: Context, channel_id: String) -> Result<Vec<Webhook>>;
+    async fn find_channel_ids(&self, ctx: Context) -> Result<Vec<String>>;
+    async fn create(&self, ctx: Context, webhook: Webhook) -> Result<Webhook>;
+    async fn delete(&self, ctx: Context, id: String) -> Result<()>;
+    async fn create_delivery(&self, ctx: Context, delivery: WebhookDelivery) -> Result<()>;
+    async fn update_delivery(&self, ctx: Context, delivery: WebhookDelivery) -> Result<()>;
+    async fn find_deliveries(
+        &self,
+        ctx: Context,
+        webhook_id: String,
+    ) -> Result<Vec<WebhookDelivery>>;
+}
+
+pub trait UseWebhookRepository<Context> {
+    type WebhookRepository: WebhookRepository<Context>;
+    fn webhook_repository(&self) -> Self::WebhookRepository;
+}
+
+#[cfg(test)]
+mock! {
+    pub WebhookRepository{}
+    impl Clone for WebhookRepository {
+        fn clone(&self) -> Self;
+    }
+    #[async_trait]
+    impl WebhookRepository<()> for WebhookRepository {
+        async fn get(&self, ctx: (), id: String) -> Result<Option<Webhook>>;
+        async fn find_by_channel(&self, ctx: (), channel_id: String) -> Result<Vec<Webhook>>;
+        async fn find_channel_ids(&self, ctx: ()) -> Result<Vec<String>>;
+        async fn create(&self, ctx: (), webhook: Webhook) -> Result<Webhook>;
+        async fn delete(&self, ctx: (), id: String) -> Result<()>;
+        async fn create_delivery(&self, ctx: (), delivery: WebhookDelivery) -> Result<()>;
+        async fn update_delivery(&self, ctx: (), delivery: WebhookDelivery) -> Result<()>;
+        async fn find_deliveries(
+            &self,
+            ctx: (),
+            webhook_id: String,
+        ) -> Result<Vec<WebhookDelivery>>;
+    }
+}
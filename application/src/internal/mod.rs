@@ -7,6 +7,7 @@ pub mod session {
     use helper::uuid::ToBase62;
     use helper::validation::Validation;
     use kernel::entity::{Account, ProvisionalAuthentication, ProvisionalSession, Session};
+    use kernel::i18n::Locale;
     use kernel::Result;
 
     pub async fn new_session<C>(
@@ -39,13 +40,14 @@ pub mod session {
         ctx: C,
         mail: String,
         password: String,
+        locale: Locale,
     ) -> Result<ProvisionalSession> {
         let code = uuid::new_v4().to_base62();
         let salt = uuid::new_v4().to_base62();
         let password_hash =
             stretch_password(&password, &salt, &cfg.auth.pepper, cfg.auth.stretch_count)?;
         let auth = ProvisionalAuthentication::new(mail, salt, password_hash).validate()?;
-        let session = ProvisionalSession::new(code, auth).validate()?;
+        let session = ProvisionalSession::new(code, auth, locale).validate()?;
         repo.set_provisional_session(ctx, session.clone()).await?;
         Ok(session)
     }
@@ -70,9 +72,9 @@ pub mod auth {
     use helper::uuid::ToBase62;
     use helper::validation::Validation;
     use kernel::entity::{Account, Authentication, ProvisionalAuthentication};
-    use kernel::error::Codes;
+    use kernel::error::{Codes, Error};
+    use kernel::i18n::Locale;
     use kernel::Result;
-    use kernel::{forbidden, unexpected};
 
     pub async fn signup_account<C: Component>(
         account_repo: impl AccountRepository<C>,
@@ -83,15 +85,17 @@ pub mod auth {
             salt,
             password_hash,
         }: ProvisionalAuthentication,
+        locale: Locale,
     ) -> Result<Account> {
         let id = uuid::new_v4().to_base62();
-        let new_account = Account::new(
+        let mut new_account = Account::new(
             id.to_string(),
             id.to_string(),
             id.to_string(),
             current_time(),
         )
         .validate()?;
+        new_account.locale = Some(locale);
         let account = account_repo.create(ctx.clone(), new_account).await?;
         let new_auth = Authentication::new(id.clone(), mail, salt, password_hash).validate()?;
         auth_repo.create(ctx.clone(), new_auth).await?;
@@ -105,11 +109,14 @@ pub mod auth {
         ctx: C,
         mail: String,
         password: String,
+        locale: Locale,
     ) -> Result<Account> {
         let Some(authentication) = auth_repo.get_by_mail(ctx.clone(), mail.to_string()).await? else {
             let _ = stretch_password(&password, "dummy", &cfg.auth.pepper, cfg.auth.stretch_count)?; // dummy stretching.
-            bail!(forbidden!("inputs: {}/{}", mail, password)
-                .with_codes(Codes::InvalidEmailOrPassword))
+            bail!(Error::forbidden(
+                Codes::InvalidEmailOrPassword,
+                locale.message("signin-failed-detail")
+            ))
         };
 
         let hash = stretch_password(
@@ -119,12 +126,585 @@ pub mod auth {
             cfg.auth.stretch_count,
         )?;
         if hash != authentication.password_hash {
-            bail!(forbidden!("inputs: {}/{}", mail, password)
-                .with_codes(Codes::InvalidEmailOrPassword))
+            bail!(Error::forbidden(
+                Codes::InvalidEmailOrPassword,
+                locale.message("signin-failed-detail")
+            ))
         }
         let Some(account) = account_repo.get(ctx.clone(), authentication.account_id).await? else {
-            bail!(unexpected!("Account NotFound"))
+            bail!(Error::unexpected(
+                Codes::AccountNotFound,
+                locale.message("account-not-found-detail")
+            ))
         };
         Ok(account)
     }
 }
+
+pub mod webhook {
+    use crate::interface::config::Config;
+    use crate::interface::gateway::webhook::{WebhookGateway, WebhookRequest};
+    use crate::interface::repository::webhook::WebhookRepository;
+    use crate::interface::Component;
+    use anyhow::bail;
+    use helper::auth::hmac_sha256;
+    use helper::time::current_time;
+    use helper::uuid;
+    use helper::uuid::ToBase62;
+    use kernel::entity::{Webhook, WebhookDelivery, WebhookDeliveryStatus};
+    use kernel::error::{Codes, Error};
+    use kernel::Result;
+    use std::net::{IpAddr, SocketAddr};
+    use std::time::Duration;
+    use url::{Host, Url};
+
+    pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+    pub const TIMESTAMP_HEADER: &str = "x-webhook-timestamp";
+    pub const DELIVERY_ID_HEADER: &str = "x-webhook-delivery-id";
+
+    /// signs `{timestamp}.{payload}` so receivers can reject replayed deliveries.
+    pub fn sign(secret: &str, timestamp: i64, payload: &[u8]) -> Result<String> {
+        let mut message = format!("{timestamp}.").into_bytes();
+        message.extend_from_slice(payload);
+        Ok(format!("sha256={}", hmac_sha256(secret, &message)?))
+    }
+
+    /// the addresses an https `url` points to, refusing any host that resolves into a network
+    /// that is not public, so webhooks can not reach internal services.
+    pub async fn resolve_url<C>(
+        gateway: &impl WebhookGateway<C>,
+        ctx: C,
+        url: &str,
+    ) -> Result<Vec<SocketAddr>> {
+        let invalid = |message: &str| Error::bad_request(Codes::InvalidWebhookUrl, message);
+        let Ok(url) = Url::parse(url) else {
+            bail!(invalid("webhook url is malformed."))
+        };
+        if url.scheme() != "https" {
+            bail!(invalid("webhook url must be https."))
+        }
+        let port = url.port_or_known_default().unwrap_or(443);
+        let addrs = match url.host() {
+            Some(Host::Ipv4(ip)) => vec![SocketAddr::new(ip.into(), port)],
+            Some(Host::Ipv6(ip)) => vec![SocketAddr::new(ip.into(), port)],
+            Some(Host::Domain(domain)) => gateway.resolve(ctx, domain.to_string(), port).await?,
+            None => bail!(invalid("webhook url has no host.")),
+        };
+        if addrs.is_empty() || !addrs.iter().all(|addr| is_public(addr.ip())) {
+            bail!(invalid("webhook url must resolve to public addresses."))
+        }
+        Ok(addrs)
+    }
+
+    /// false for loopback, private, shared, link-local (where cloud metadata endpoints live),
+    /// documentation, multicast and reserved addresses.
+    pub fn is_public(ip: IpAddr) -> bool {
+        match ip {
+            IpAddr::V4(ip) => {
+                let [a, b, c, _] = ip.octets();
+                !(ip.is_unspecified()
+                    || ip.is_loopback()
+                    || ip.is_private()
+                    || ip.is_link_local()
+                    || ip.is_broadcast()
+                    || ip.is_documentation()
+                    || ip.is_multicast()
+                    || a == 0
+                    || (a == 100 && (64..128).contains(&b))
+                    || (a == 192 && b == 0 && c == 0)
+                    || (a == 198 && (18..20).contains(&b))
+                    || a >= 240)
+            }
+            IpAddr::V6(ip) => {
+                if let Some(ip) = ip.to_ipv4_mapped() {
+                    return is_public(ip.into());
+                }
+                let [first, second, ..] = ip.segments();
+                !(ip.is_unspecified()
+                    || ip.is_loopback()
+                    || ip.is_multicast()
+                    || (first & 0xfe00) == 0xfc00
+                    || (first & 0xffc0) == 0xfe80
+                    || (first == 0x64 && second == 0xff9b)
+                    || (first == 0x2001 && second == 0xdb8))
+            }
+        }
+    }
+
+    pub fn backoff(cfg: &Config, attempts: i32) -> Duration {
+        let exponent = u32::try_from(attempts - 1).unwrap_or(0);
+        cfg.webhook
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(exponent))
+            .min(cfg.webhook.max_backoff)
+    }
+
+    pub async fn deliver<C: Component>(
+        cfg: &Config,
+        gateway: impl WebhookGateway<C>,
+        repo: impl WebhookRepository<C>,
+        ctx: C,
+        webhook: Webhook,
+        payload: Vec<u8>,
+    ) -> Result<WebhookDelivery> {
+        let now = current_time();
+        let mut delivery = WebhookDelivery::new(
+            uuid::new_v4().to_base62(),
+            webhook.id.clone(),
+            webhook.channel_id.clone(),
+            payload,
+            WebhookDeliveryStatus::Pending,
+            0,
+            None,
+            None,
+            now,
+            now,
+        );
+        repo.create_delivery(ctx.clone(), delivery.clone()).await?;
+        loop {
+            delivery.attempts += 1;
+            let timestamp = current_time().timestamp();
+            let headers = vec![
+                (
+                    SIGNATURE_HEADER.to_string(),
+                    sign(&webhook.secret, timestamp, &delivery.payload)?,
+                ),
+                (TIMESTAMP_HEADER.to_string(), timestamp.to_string()),
+                (DELIVERY_ID_HEADER.to_string(), delivery.id.clone()),
+            ];
+            // checked on every attempt, as the host may have been pointed elsewhere since.
+            let response = match resolve_url(&gateway, ctx.clone(), &webhook.url).await {
+                Ok(addrs) => {
+                    let request = WebhookRequest::new(
+                        webhook.url.clone(),
+                        addrs,
+                        headers,
+                        delivery.payload.clone(),
+                    );
+                    gateway.post(ctx.clone(), request).await
+                }
+                Err(e) => Err(e),
+            };
+            match response {
+                Ok(response) if (200..300).contains(&response.status) => {
+                    delivery.status = WebhookDeliveryStatus::Succeeded;
+                    delivery.response_status = Some(response.status.into());
+                    delivery.last_error = None;
+                }
+                Ok(response) => {
+                    delivery.response_status = Some(response.status.into());
+                    delivery.last_error = Some(format!("unexpected status: {}", response.status));
+                }
+                Err(e) => {
+                    delivery.response_status = None;
+                    delivery.last_error = Some(e.to_string());
+                }
+            }
+            if delivery.status != WebhookDeliveryStatus::Succeeded {
+                delivery.status = if delivery.attempts >= cfg.webhook.max_attempts {
+                    WebhookDeliveryStatus::DeadLetter
+                } else {
+                    WebhookDeliveryStatus::Retrying
+                };
+            }
+            delivery.update_time = current_time();
+            repo.update_delivery(ctx.clone(), delivery.clone()).await?;
+            if delivery.status != WebhookDeliveryStatus::Retrying {
+                return Ok(delivery);
+            }
+            tokio::time::sleep(backoff(cfg, delivery.attempts)).await;
+        }
+    }
+}
+
+pub mod channel {
+    use crate::interface::gateway::pubsub::{PubSubGateway, SubscriptionEvent};
+    use crate::interface::repository::message::MessageRepository;
+    use crate::interface::Component;
+    use anyhow::{bail, Context as _};
+    use kernel::entity::{ChannelEvent, Message, ThreadMarker};
+    use kernel::error::{Codes, Error};
+    use kernel::Result;
+    use log;
+    use std::collections::HashSet;
+    use tokio::sync::mpsc::Receiver;
+
+    /// the most messages replayed to a resuming client. past that, it gets a `reconnected` event
+    /// and refetches instead.
+    pub const MAX_REPLAY: u64 = 100;
+
+    pub fn topic(channel_id: &str) -> String {
+        format!("channel:{channel_id}")
+    }
+
+    /// a topic only the account itself subscribes to, for events private to it.
+    pub fn account_topic(account_id: &str) -> String {
+        format!("account:{account_id}")
+    }
+
+    pub async fn broadcast<C>(
+        gateway: impl PubSubGateway<C>,
+        ctx: C,
+        channel_id: &str,
+        event: &ChannelEvent,
+    ) -> Result<()> {
+        publish(gateway, ctx, topic(channel_id), event).await
+    }
+
+    /// publishes an event to the subscriptions of one account only.
+    pub async fn notify<C>(
+        gateway: impl PubSubGateway<C>,
+        ctx: C,
+        account_id: &str,
+        event: &ChannelEvent,
+    ) -> Result<()> {
+        publish(gateway, ctx, account_topic(account_id), event).await
+    }
+
+    async fn publish<C>(
+        gateway: impl PubSubGateway<C>,
+        ctx: C,
+        topic: String,
+        event: &ChannelEvent,
+    ) -> Result<()> {
+        let payload = encode(event)?;
+        gateway.publish(ctx, topic, payload.as_slice()).await
+    }
+
+    fn encode(event: &ChannelEvent) -> Result<Vec<u8>> {
+        serde_json::to_vec(event).with_context(|| {
+            Error::unexpected(
+                Codes::ChannelEventEncodingFailed,
+                "channel event serialize error",
+            )
+        })
+    }
+
+    /// subscribes a channel for clients, along with the events of the account if any.
+    /// a reconnect of the underlying subscription is delivered as a `reconnected` event,
+    /// so that clients know to refetch what they may have missed.
+    pub async fn subscribe<C: Component>(
+        gateway: impl PubSubGateway<C>,
+        ctx: C,
+        channel_id: &str,
+        account_id: Option<&str>,
+    ) -> Result<Receiver<Vec<u8>>> {
+        let (sender, rx) = tokio::sync::mpsc::channel(1000);
+        let reconnected = encode(&ChannelEvent::Reconnected)?;
+        let mut topics = vec![topic(channel_id)];
+        topics.extend(account_id.map(account_topic));
+        for topic in topics {
+            let mut receiver = gateway.subscribe(ctx.clone(), topic).await?;
+            let sender = sender.clone();
+            let reconnected = reconnected.clone();
+            tokio::spawn(async move {
+                while let Some(event) = receiver.recv().await {
+                    let message = match event {
+                        SubscriptionEvent::Message(message) => message,
+                        SubscriptionEvent::Reconnected => reconnected.clone(),
+                    };
+                    if let Err(e) = sender.send(message).await {
+                        log::debug!("send error: {}", e);
+                        break;
+                    }
+                }
+            });
+        }
+        Ok(rx)
+    }
+
+    /// delivers the messages created after `last_message_id` ahead of the subscription, for
+    /// clients resuming where they left off. the subscription is made before the lookup, so a
+    /// message created in between arrives on both and is delivered once.
+    pub async fn replay<C: Component>(
+        repo: impl MessageRepository<C>,
+        ctx: C,
+        channel_id: &str,
+        last_message_id: String,
+        mut rx: Receiver<Vec<u8>>,
+    ) -> Result<Receiver<Vec<u8>>> {
+        let missed = repo
+            .find_after(
+                ctx.clone(),
+                channel_id.to_string(),
+                last_message_id,
+                MAX_REPLAY + 1,
+            )
+            .await?;
+        let mut replayed = HashSet::new();
+        let mut payloads = vec![];
+        if missed.len() as u64 > MAX_REPLAY {
+            payloads.push(encode(&ChannelEvent::Reconnected)?);
+        } else {
+            for message in missed {
+                let thread = match &message.parent_id {
+                    Some(parent_id) => repo
+                        .get(ctx.clone(), parent_id.clone())
+                        .await?
+                        .map(|parent| ThreadMarker::new(parent.id, parent.reply_count)),
+                    None => None,
+                };
+                replayed.insert(message.id.clone());
+                payloads.push(encode(&ChannelEvent::MessageCreated { message, thread })?);
+            }
+        }
+        let (sender, replay_rx) = tokio::sync::mpsc::channel(1000);
+        tokio::spawn(async move {
+            for payload in payloads {
+                if sender.send(payload).await.is_err() {
+                    return;
+                }
+            }
+            while let Some(payload) = rx.recv().await {
+                if !replayed.is_empty() {
+                    if let Ok(ChannelEvent::MessageCreated { message, .. }) =
+                        serde_json::from_slice::<ChannelEvent>(&payload)
+                    {
+                        if replayed.remove(&message.id) {
+                            continue;
+                        }
+                    }
+                }
+                if let Err(e) = sender.send(payload).await {
+                    log::debug!("send error: {}", e);
+                    break;
+                }
+            }
+        });
+        Ok(replay_rx)
+    }
+
+    pub async fn get_message<C>(
+        repo: impl MessageRepository<C>,
+        ctx: C,
+        channel_id: &str,
+        message_id: &str,
+    ) -> Result<Message> {
+        match repo.get(ctx, message_id.to_string()).await? {
+            Some(message) if message.channel_id == channel_id && message.delete_time.is_none() => {
+                Ok(message)
+            }
+            _ => bail!(Error::not_found(
+                Codes::MessageNotFound,
+                "message not found."
+            )),
+        }
+    }
+
+    /// threads are one level deep, so a reply to a reply joins the thread of its parent.
+    pub async fn get_thread_root<C: Component>(
+        repo: impl MessageRepository<C>,
+        ctx: C,
+        channel_id: &str,
+        message_id: &str,
+    ) -> Result<Message> {
+        let message = get_message(repo.clone(), ctx.clone(), channel_id, message_id).await?;
+        match message.parent_id {
+            Some(parent_id) => get_message(repo, ctx, channel_id, &parent_id).await,
+            None => Ok(message),
+        }
+    }
+}
+
+pub mod filter {
+    use crate::interface::config::Config;
+    use anyhow::Context as _;
+    use kernel::entity::{MessageFilterRule, ProfanityAction};
+    use kernel::error::{Codes, Error};
+    use kernel::Result;
+    use regex::Regex;
+
+    pub const DEFAULT_CHANNEL: &str = "*";
+
+    pub enum FilterResult {
+        Pass,
+        Rewrite(String),
+        Reject(String),
+    }
+
+    /// a rule with its patterns compiled, which is done once when the configuration is built.
+    #[derive(Clone, Debug)]
+    pub enum Filter {
+        Profanity {
+            re: Option<Regex>,
+            action: ProfanityAction,
+        },
+        Link {
+            re: Regex,
+            allowed_hosts: Vec<String>,
+        },
+        Regex {
+            re: Regex,
+            replacement: Option<String>,
+            reason: Option<String>,
+        },
+        Length {
+            min: usize,
+            max: usize,
+        },
+    }
+
+    impl Filter {
+        pub fn compile(rule: &MessageFilterRule) -> Result<Filter> {
+            Ok(match rule {
+                MessageFilterRule::Profanity { words, action } => Filter::Profanity {
+                    re: profanity_pattern(words)?,
+                    action: *action,
+                },
+                MessageFilterRule::Link { allowed_hosts } => Filter::Link {
+                    re: compile(r"(?i)\b(?:[a-z][a-z0-9+.-]*://|www\.)[^\s<>]+")?,
+                    allowed_hosts: allowed_hosts.clone(),
+                },
+                MessageFilterRule::Regex {
+                    pattern,
+                    replacement,
+                    reason,
+                } => Filter::Regex {
+                    re: compile(pattern)?,
+                    replacement: replacement.clone(),
+                    reason: reason.clone(),
+                },
+                MessageFilterRule::Length { min, max } => Filter::Length {
+                    min: *min,
+                    max: *max,
+                },
+            })
+        }
+    }
+
+    /// runs the channel's filter chain. a rewritten message is passed on to the next filter.
+    pub fn apply(cfg: &Config, channel_id: &str, message: Vec<u8>) -> Result<Vec<u8>> {
+        let filters = &cfg.message_filter.filters;
+        let Some(filters) = filters
+            .get(channel_id)
+            .or_else(|| filters.get(DEFAULT_CHANNEL))
+        else {
+            return Ok(message);
+        };
+        if filters.is_empty() {
+            return Ok(message);
+        }
+        let Ok(mut text) = String::from_utf8(message) else {
+            return Err(reject("message must be utf-8 text."));
+        };
+        for rule in filters {
+            match filter(rule, &text) {
+                FilterResult::Pass => (),
+                FilterResult::Rewrite(rewritten) => text = rewritten,
+                FilterResult::Reject(reason) => return Err(reject(reason)),
+            }
+        }
+        Ok(text.into_bytes())
+    }
+
+    pub fn filter(rule: &Filter, text: &str) -> FilterResult {
+        match rule {
+            Filter::Profanity { re, action } => profanity(re.as_ref(), *action, text),
+            Filter::Link { re, allowed_hosts } => link(re, allowed_hosts, text),
+            Filter::Regex {
+                re,
+                replacement,
+                reason,
+            } => pattern_match(re, replacement.as_deref(), reason.as_deref(), text),
+            Filter::Length { min, max } => length(*min, *max, text),
+        }
+    }
+
+    fn profanity_pattern(words: &[String]) -> Result<Option<Regex>> {
+        if words.is_empty() {
+            return Ok(None);
+        }
+        let alternation = words
+            .iter()
+            .map(|w| regex::escape(w))
+            .collect::<Vec<_>>()
+            .join("|");
+        Ok(Some(compile(&format!(r"(?i)\b(?:{alternation})\b"))?))
+    }
+
+    fn profanity(re: Option<&Regex>, action: ProfanityAction, text: &str) -> FilterResult {
+        let Some(re) = re.filter(|re| re.is_match(text)) else {
+            return FilterResult::Pass;
+        };
+        match action {
+            ProfanityAction::Reject => {
+                FilterResult::Reject("message contains a prohibited word.".to_string())
+            }
+            ProfanityAction::Mask => FilterResult::Rewrite(
+                re.replace_all(text, |caps: &regex::Captures| {
+                    "*".repeat(caps[0].chars().count())
+                })
+                .into_owned(),
+            ),
+        }
+    }
+
+    fn link(re: &Regex, allowed_hosts: &[String], text: &str) -> FilterResult {
+        for m in re.find_iter(text) {
+            let url = m.as_str();
+            let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+            let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+            let host = authority
+                .rsplit('@')
+                .next()
+                .unwrap_or_default()
+                .split(':')
+                .next()
+                .unwrap_or_default()
+                .to_lowercase();
+            let allowed = allowed_hosts.iter().any(|allowed| {
+                let allowed = allowed.to_lowercase();
+                host == allowed || host.ends_with(&format!(".{allowed}"))
+            });
+            if !allowed {
+                return FilterResult::Reject(format!("link to {host} is not allowed."));
+            }
+        }
+        FilterResult::Pass
+    }
+
+    fn pattern_match(
+        re: &Regex,
+        replacement: Option<&str>,
+        reason: Option<&str>,
+        text: &str,
+    ) -> FilterResult {
+        if !re.is_match(text) {
+            return FilterResult::Pass;
+        }
+        match replacement {
+            Some(replacement) => {
+                FilterResult::Rewrite(re.replace_all(text, replacement).into_owned())
+            }
+            None => FilterResult::Reject(
+                reason
+                    .unwrap_or("message matches a blocked pattern.")
+                    .to_string(),
+            ),
+        }
+    }
+
+    fn length(min: usize, max: usize, text: &str) -> FilterResult {
+        let len = text.chars().count();
+        if len < min {
+            FilterResult::Reject(format!("message must be at least {min} characters."))
+        } else if len > max {
+            FilterResult::Reject(format!("message must be at most {max} characters."))
+        } else {
+            FilterResult::Pass
+        }
+    }
+
+    fn compile(pattern: &str) -> Result<Regex> {
+        Regex::new(pattern).with_context(|| {
+            Error::unexpected(
+                Codes::InvalidFilterPattern,
+                format!("invalid filter pattern: {pattern}"),
+            )
+        })
+    }
+
+    fn reject(reason: impl Into<String>) -> anyhow::Error {
+        Error::bad_request(Codes::MessageRejected, reason).into()
+    }
+}
@@ -6,7 +6,8 @@ use crate::usecase::UseCase;
 use anyhow::{bail, Result};
 use async_trait::async_trait;
 use kernel::entity::Account;
-use kernel::forbidden;
+use kernel::error::{Codes, Error};
+use kernel::i18n::Locale;
 use std::marker::PhantomData;
 use trait_set::trait_set;
 
@@ -33,10 +34,16 @@ where
         let ctx = self.deps.context().await?;
         if input.id == "me" {
             let Some(session_id) = input.session_id else {
-                bail!(forbidden!("required session."))
+                bail!(Error::forbidden(
+                    Codes::SessionRequired,
+                    "required session."
+                ))
             };
             let Some(session) = self.deps.session_repository().get(ctx.clone(), session_id).await? else {
-                bail!(forbidden!("required session."))
+                bail!(Error::forbidden(
+                    Codes::SessionRequired,
+                    "required session."
+                ))
             };
             return Ok(GetAccountOutput {
                 account: Some(session.account),
@@ -58,6 +65,66 @@ pub struct GetAccountOutput {
     pub account: Option<Account>,
 }
 
+#[derive(Clone, new)]
+pub struct UpdateLocaleUseCase<C, Deps> {
+    pub(crate) deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait UpdateLocaleUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseAccountRepository<C>
+    + UseSessionRepository<C>
+    ;
+}
+
+/// only the calling session picks up the new locale, the others keep theirs until they sign in again.
+#[async_trait]
+impl<C, Deps> UseCase<UpdateLocaleInput, UpdateLocaleOutput> for UpdateLocaleUseCase<C, Deps>
+where
+    C: Component,
+    Deps: UpdateLocaleUseCaseDeps<C>,
+{
+    async fn handle(&self, input: UpdateLocaleInput) -> Result<UpdateLocaleOutput> {
+        let Ok(account_locale) = input.account_locale.parse::<Locale>() else {
+            bail!(Error::bad_request(
+                Codes::AccountUnsupportedLocale,
+                input.locale.format(
+                    "account-unsupported-locale-detail",
+                    &[("locale", input.account_locale.as_str())]
+                )
+            ))
+        };
+        let ctx = self.deps.context().await?;
+        let session_repo = self.deps.session_repository();
+        let Some(mut session) = session_repo.get(ctx.clone(), input.session_id).await? else {
+            bail!(Error::forbidden(
+                Codes::SessionRequired,
+                "required session."
+            ))
+        };
+        self.deps
+            .account_repository()
+            .update_locale(ctx.clone(), session.account.id.clone(), account_locale)
+            .await?;
+        session.account.locale = Some(account_locale);
+        session_repo.set(ctx, session.clone()).await?;
+        Ok(UpdateLocaleOutput::new(session.account))
+    }
+}
+
+#[derive(new)]
+pub struct UpdateLocaleInput {
+    pub(crate) session_id: String,
+    pub(crate) account_locale: String,
+    pub(crate) locale: Locale,
+}
+
+#[derive(new)]
+pub struct UpdateLocaleOutput {
+    pub account: Account,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::interface::repository::account::{MockAccountRepository, UseAccountRepository};
@@ -65,12 +132,16 @@ mod tests {
     use std::sync::Arc;
 
     use crate::interface::UseContext;
-    use crate::usecase::account::{GetAccountInput, GetAccountUseCase};
+    use crate::usecase::account::{
+        GetAccountInput, GetAccountUseCase, UpdateLocaleInput, UpdateLocaleUseCase,
+    };
     use crate::usecase::UseCase;
     use async_trait::async_trait;
 
     use helper::time::current_time;
     use kernel::entity::Account;
+    use kernel::error::{Codes, Error};
+    use kernel::i18n::Locale;
     use kernel::Result;
     use mockall::predicate;
 
@@ -164,4 +235,59 @@ mod tests {
         let account = output.unwrap().account.unwrap();
         assert_eq!(account.id, "account_id".to_string());
     }
+    #[tokio::test]
+    async fn update_locale() {
+        let mut mock_account_repo = MockAccountRepository::default();
+        mock_account_repo
+            .expect_update_locale()
+            .with(
+                predicate::eq(()),
+                predicate::eq("account_id".to_string()),
+                predicate::eq(Locale::Ja),
+            )
+            .return_once(|_, _, _| Ok(()));
+        let mut mock_session_repo = MockSessionRepository::default();
+        let now = current_time();
+        mock_session_repo
+            .expect_get()
+            .with(predicate::eq(()), predicate::eq("session_id".to_string()))
+            .return_once(move |_, session_id| {
+                Ok(Some(kernel::entity::Session::new(
+                    session_id,
+                    Account::new(
+                        "account_id".to_string(),
+                        "name".to_string(),
+                        "display_name".to_string(),
+                        now,
+                    ),
+                    now,
+                )))
+            });
+        mock_session_repo
+            .expect_set()
+            .withf(|_, session| session.account.locale == Some(Locale::Ja))
+            .return_once(|_, _| Ok(()));
+        let mods = TestMods {
+            mock_account_repo: Arc::new(mock_account_repo),
+            mock_session_repo: Arc::new(mock_session_repo),
+        };
+        let interactor = UpdateLocaleUseCase::new(mods);
+        let input = UpdateLocaleInput::new("session_id".to_string(), "ja".to_string(), Locale::En);
+        let output = interactor.handle(input).await.unwrap();
+        assert_eq!(output.account.locale, Some(Locale::Ja));
+    }
+    #[tokio::test]
+    async fn update_locale_rejects_unsupported_locale() {
+        let mods = TestMods {
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+            mock_session_repo: Arc::new(MockSessionRepository::default()),
+        };
+        let interactor = UpdateLocaleUseCase::new(mods);
+        let input = UpdateLocaleInput::new("session_id".to_string(), "fr".to_string(), Locale::En);
+        let err = interactor.handle(input).await.err().unwrap();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::BadRequest(Codes::AccountUnsupportedLocale, _))
+        ));
+    }
 }
@@ -0,0 +1,147 @@
+use crate::interface::repository::analytics::{AnalyticsRepository, UseAnalyticsRepository};
+use crate::interface::{Component, UseConfig, UseContext};
+use crate::usecase::UseCase;
+use async_trait::async_trait;
+use helper::time::current_time;
+use helper::validation::Validation;
+use kernel::entity::AnalyticsEvent;
+use kernel::Result;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use trait_set::trait_set;
+
+#[derive(Clone, new)]
+pub struct RecordAnalyticsEventUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait RecordAnalyticsEventUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseAnalyticsRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<RecordAnalyticsEventInput, RecordAnalyticsEventOutput>
+    for RecordAnalyticsEventUseCase<C, Deps>
+where
+    C: Component,
+    Deps: RecordAnalyticsEventUseCaseDeps<C>,
+{
+    async fn handle(&self, input: RecordAnalyticsEventInput) -> Result<RecordAnalyticsEventOutput> {
+        let Some(tracking_id) = input.tracking_id else {
+            return Ok(RecordAnalyticsEventOutput::new(false));
+        };
+        let event = AnalyticsEvent::new(tracking_id, input.name, input.properties, current_time())
+            .validate()?;
+        let config = self.deps.config().analytics;
+        self.deps
+            .analytics_repository()
+            .record(
+                self.deps.context().await?,
+                event,
+                config.max_events,
+                config.retention,
+            )
+            .await?;
+        Ok(RecordAnalyticsEventOutput::new(true))
+    }
+}
+
+/// `tracking_id` is `None` when the client did not consent to tracking, the event is dropped then.
+#[derive(new)]
+pub struct RecordAnalyticsEventInput {
+    tracking_id: Option<String>,
+    name: String,
+    properties: HashMap<String, String>,
+}
+
+#[derive(new)]
+pub struct RecordAnalyticsEventOutput {
+    pub recorded: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::config::{Auth, Config, ConfigBuilder, System};
+    use crate::interface::repository::analytics::{
+        MockAnalyticsRepository, UseAnalyticsRepository,
+    };
+    use crate::interface::{UseConfig, UseContext};
+    use crate::usecase::analytics::{RecordAnalyticsEventInput, RecordAnalyticsEventUseCase};
+    use crate::usecase::UseCase;
+    use async_trait::async_trait;
+    use kernel::Result;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct TestMods {
+        mock_analytics_repository: Arc<MockAnalyticsRepository>,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseAnalyticsRepository<()> for TestMods {
+        type AnalyticsRepository = Arc<MockAnalyticsRepository>;
+
+        fn analytics_repository(&self) -> Self::AnalyticsRepository {
+            self.mock_analytics_repository.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn records_event_for_tracking_id() {
+        let mut mock_analytics_repository = MockAnalyticsRepository::default();
+        mock_analytics_repository
+            .expect_record()
+            .withf(|_, event, _, _| event.tracking_id == "tid" && event.name == "page_view")
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        let usecase = RecordAnalyticsEventUseCase::new(TestMods {
+            mock_analytics_repository: Arc::new(mock_analytics_repository),
+        });
+        let output = usecase
+            .handle(RecordAnalyticsEventInput::new(
+                Some("tid".to_string()),
+                "page_view".to_string(),
+                HashMap::from([("path".to_string(), "/".to_string())]),
+            ))
+            .await
+            .unwrap();
+        assert!(output.recorded);
+    }
+
+    #[tokio::test]
+    async fn drops_event_without_consent() {
+        let mut mock_analytics_repository = MockAnalyticsRepository::default();
+        mock_analytics_repository.expect_record().times(0);
+        let usecase = RecordAnalyticsEventUseCase::new(TestMods {
+            mock_analytics_repository: Arc::new(mock_analytics_repository),
+        });
+        let output = usecase
+            .handle(RecordAnalyticsEventInput::new(
+                None,
+                "page_view".to_string(),
+                HashMap::new(),
+            ))
+            .await
+            .unwrap();
+        assert!(!output.recorded);
+    }
+}
@@ -15,7 +15,8 @@ use async_trait::async_trait;
 use helper::auth::stretch_password;
 use helper::uuid::ToBase62;
 use kernel::entity::Session;
-use kernel::{forbidden, unexpected};
+use kernel::error::{Codes, Error};
+use kernel::i18n::Locale;
 use std::marker::PhantomData;
 use trait_set::trait_set;
 
@@ -38,7 +39,10 @@ where
     async fn handle(&self, input: GetAuthStatusInput) -> Result<GetAuthStatusOutput> {
         match input.session {
             Some(_) => Ok(GetAuthStatusOutput),
-            None => bail!(forbidden!("invalid session")),
+            None => bail!(Error::forbidden(
+                Codes::InvalidSession,
+                input.locale.message("session-invalid-detail")
+            )),
         }
     }
 }
@@ -72,7 +76,13 @@ where
             .get_by_mail(ctx.clone(), input.mail.clone())
             .await?;
         if auth.is_some() {
-            bail!(forbidden!("already exists. {}", input.mail.clone()))
+            bail!(Error::forbidden(
+                Codes::AccountAlreadyExists,
+                input.locale.format(
+                    "account-already-exists-detail",
+                    &[("mail", input.mail.as_str())]
+                )
+            ))
         }
         let session = internal::session::new_provisional_account_session(
             &cfg,
@@ -80,6 +90,7 @@ where
             ctx.clone(),
             input.mail.to_string(),
             input.password.to_string(),
+            input.locale,
         )
         .await?;
 
@@ -89,8 +100,10 @@ where
         let send_input = mail::SendEmailInput::new(
             format!("noreply@{}", cfg.system.mail_domain),
             input.mail.to_string(),
-            "signup link".to_string(),
-            format!("signup here! {signup_finish_url}"),
+            input.locale.message("signup-mail-subject"),
+            input
+                .locale
+                .format("signup-mail-body", &[("url", signup_finish_url.as_str())]),
         );
         self.deps
             .mail_gateway()
@@ -137,12 +150,19 @@ where
                     self.deps.authentication_repository(),
                     tx.clone(),
                     session.authentication,
+                    session.locale,
                 )
                 .await?;
                 internal::session::new_session(session_repo, tx.clone(), account).await?
             }
-            Some(s) => bail!(unexpected!("invalid session state: {:?}", s)),
-            None => bail!(unexpected!("session not found.")),
+            Some(_) => bail!(Error::bad_request(
+                Codes::InvalidSession,
+                input.locale.message("session-state-invalid-detail")
+            )),
+            None => bail!(Error::bad_request(
+                Codes::InvalidSession,
+                input.locale.message("session-not-found-detail")
+            )),
         };
         tx.commit().await?;
         Ok(SignUpFinishOutput {
@@ -180,6 +200,7 @@ where
             ctx.clone(),
             input.mail,
             input.password,
+            input.locale,
         )
         .await?;
         let session =
@@ -264,13 +285,21 @@ where
         let send_input = mail::SendEmailInput::new(
             format!("noreply@{}", cfg.system.mail_domain),
             input.mail.to_string(),
-            "password reset link".to_string(),
-            format!("password reset here! {password_reset_url}"),
+            input.locale.message("password-reset-mail-subject"),
+            input.locale.format(
+                "password-reset-mail-body",
+                &[("url", password_reset_url.as_str())],
+            ),
         );
         mail_gateway
             .send_email(ctx, send_input)
             .await
-            .with_context(|| unexpected!("failed to send mail"))?;
+            .with_context(|| {
+                Error::unexpected(
+                    Codes::MailDeliveryFailed,
+                    input.locale.message("mail-delivery-failed-detail"),
+                )
+            })?;
         Ok(ForgetPasswordOutput)
     }
 }
@@ -302,13 +331,17 @@ where
         let Some(password_reset_code) = auth_repo
             .get_password_reset_code(tx.clone(), input.code.clone())
             .await? else {
-            bail!(unexpected!("invalid password reset code. code not found."))
+            bail!(Error::bad_request(
+                Codes::InvalidPasswordResetCode,
+                input.locale.message("password-reset-code-not-found-detail")
+            ))
         };
         let Some(authentication) =  auth_repo
             .get_by_mail(tx.clone(), password_reset_code.mail.clone())
             .await? else {
-            bail!(unexpected!(
-                "invalid password reset code. account not found"
+            bail!(Error::bad_request(
+                Codes::InvalidPasswordResetCode,
+                input.locale.message("password-reset-account-not-found-detail")
             ))
         };
         let updated = UpdatePassword {
@@ -330,6 +363,7 @@ where
 #[derive(new)]
 pub struct GetAuthStatusInput {
     pub(crate) session: Option<Session>,
+    pub(crate) locale: Locale,
 }
 
 #[derive(new, Debug, PartialEq)]
@@ -340,6 +374,8 @@ pub struct SignUpInput {
     pub(crate) mail: String,
     pub(crate) password: String,
     pub(crate) site_url: String,
+    /// of the mail, and the preference of the account.
+    pub(crate) locale: Locale,
 }
 #[derive(new, Debug, PartialEq)]
 pub struct SignUpOutput;
@@ -347,6 +383,7 @@ pub struct SignUpOutput;
 #[derive(new)]
 pub struct SignUpFinishInput {
     pub(crate) code: String,
+    pub(crate) locale: Locale,
 }
 
 #[derive(new, Debug, PartialEq)]
@@ -359,6 +396,7 @@ pub struct SignInInput {
     pub(crate) mail: String,
     pub(crate) password: String,
     pub(crate) remember_me: bool,
+    pub(crate) locale: Locale,
 }
 
 #[derive(new, Debug, PartialEq)]
@@ -379,6 +417,7 @@ pub struct SignOutOutput;
 pub struct ForgetPasswordInput {
     pub(crate) mail: String,
     pub(crate) site_url: String,
+    pub(crate) locale: Locale,
 }
 
 #[derive(new, Debug, PartialEq)]
@@ -388,6 +427,7 @@ pub struct ForgetPasswordOutput;
 pub struct ResetPasswordInput {
     pub(crate) code: String,
     pub(crate) password: String,
+    pub(crate) locale: Locale,
 }
 
 #[derive(new, Debug, PartialEq)]
@@ -395,7 +435,7 @@ pub struct ResetPasswordOutput;
 
 #[cfg(test)]
 mod tests {
-    use crate::interface::config::{AuthBuilder, Config, SystemBuilder};
+    use crate::interface::config::{AuthBuilder, Config, ConfigBuilder, SystemBuilder};
     use crate::interface::gateway::mail::{MockMailGateway, UseMailGateway};
     use crate::interface::repository::authentication::{
         MockAuthenticationRepository, UseAuthenticationRepository,
@@ -406,6 +446,7 @@ mod tests {
     use crate::usecase::UseCase;
     use async_trait::async_trait;
     use helper::auth::stretch_password;
+    use kernel::i18n::Locale;
 
     use kernel::Result;
     use mockall::predicate;
@@ -426,17 +467,22 @@ mod tests {
     }
     impl UseConfig for TestMods {
         fn config(&self) -> Config {
-            Config::new(
-                AuthBuilder::default()
-                    .pepper("dummy_pepper".to_string())
-                    .stretch_count(10)
-                    .build()
-                    .unwrap(),
-                SystemBuilder::default()
-                    .mail_domain("example.com")
-                    .build()
-                    .unwrap(),
-            )
+            ConfigBuilder::default()
+                .auth(
+                    AuthBuilder::default()
+                        .pepper("dummy_pepper".to_string())
+                        .stretch_count(10)
+                        .build()
+                        .unwrap(),
+                )
+                .system(
+                    SystemBuilder::default()
+                        .mail_domain("example.com")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap()
         }
     }
     impl UseAuthenticationRepository<()> for TestMods {
@@ -480,6 +526,7 @@ mod tests {
             .with(predicate::eq(()), predicate::always())
             .return_once(move |_, session| {
                 assert_eq!(mail.to_string(), session.authentication.mail);
+                assert_eq!(Locale::En, session.locale);
                 assert_eq!(
                     stretch_password(password, &session.authentication.salt, "dummy_pepper", 10)
                         .unwrap(),
@@ -507,7 +554,12 @@ mod tests {
             mock_mail_gateway: Arc::new(mock_mail_gateway),
         };
         let interactor = SignUpUseCase::<(), TestMods>::new(mods);
-        let input = SignUpInput::new(mail.to_string(), password.to_string(), site_url.to_string());
+        let input = SignUpInput::new(
+            mail.to_string(),
+            password.to_string(),
+            site_url.to_string(),
+            Locale::En,
+        );
         let output = interactor.handle(input).await.unwrap();
         assert_eq!(output, SignUpOutput);
     }
@@ -1,10 +1,25 @@
+use crate::interface::config::Config;
 use crate::interface::gateway::pubsub::{PubSubGateway, UsePubSubGateway};
+use crate::interface::repository::account::{AccountRepository, UseAccountRepository};
+use crate::interface::repository::channel::{ChannelRepository, UseChannelRepository};
+use crate::interface::repository::message::{MessageRepository, UseMessageRepository};
+use crate::interface::repository::Transaction;
 use crate::interface::Component;
-use crate::interface::UseContext;
+use crate::interface::{UseConfig, UseContext};
+use crate::internal;
 use crate::usecase::UseCase;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_trait::async_trait;
+use helper::time::current_time;
+use helper::uuid;
+use helper::uuid::ToBase62;
+use helper::validation::Validation;
+use kernel::entity::{
+    ChannelEvent, ChannelModerator, Message, ReadPosition, ThreadMarker, UnreadCount,
+};
+use kernel::error::{Codes, Error};
 use log;
+use serde::Deserialize;
 use std::marker::PhantomData;
 use tokio::sync::mpsc::Receiver;
 use tokio_stream::wrappers::ReceiverStream;
@@ -29,14 +44,52 @@ where
     Deps: SubscribeUseCaseDeps<C>,
 {
     async fn handle(&self, input: SubscribeInput) -> Result<SubscribeOutput> {
-        let rx = self
-            .deps
-            .pubsub_gateway()
-            .subscribe(
-                self.deps.context().await?,
-                format!("channel:{}", input.channel_id),
-            )
-            .await?;
+        let rx = internal::channel::subscribe(
+            self.deps.pubsub_gateway(),
+            self.deps.context().await?,
+            &input.channel_id,
+            input.account_id.as_deref(),
+        )
+        .await?;
+        Ok(SubscribeOutput::new(rx))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct ResubscribeUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait ResubscribeUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseMessageRepository<C>
+    + UsePubSubGateway<C>
+    ;
+}
+#[async_trait]
+impl<C, Deps> UseCase<ResubscribeInput, SubscribeOutput> for ResubscribeUseCase<C, Deps>
+where
+    C: Component,
+    Deps: ResubscribeUseCaseDeps<C>,
+{
+    async fn handle(&self, input: ResubscribeInput) -> Result<SubscribeOutput> {
+        let ctx = self.deps.context().await?;
+        let rx = internal::channel::subscribe(
+            self.deps.pubsub_gateway(),
+            ctx.clone(),
+            &input.channel_id,
+            input.account_id.as_deref(),
+        )
+        .await?;
+        let rx = internal::channel::replay(
+            self.deps.message_repository(),
+            ctx,
+            &input.channel_id,
+            input.last_message_id,
+            rx,
+        )
+        .await?;
         Ok(SubscribeOutput::new(rx))
     }
 }
@@ -48,29 +101,104 @@ pub struct PublishUseCase<C, Deps> {
 }
 
 trait_set! {
-    pub trait PublishUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    pub trait PublishUseCaseDeps<C: Transaction> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseMessageRepository<C>
     + UsePubSubGateway<C>
     ;
 }
 #[async_trait]
 impl<C, Deps> UseCase<PublishInput, PublishOutput> for PublishUseCase<C, Deps>
 where
-    C: Component,
+    C: Transaction,
     Deps: PublishUseCaseDeps<C>,
 {
     async fn handle(&self, input: PublishInput) -> Result<PublishOutput> {
-        self.deps
-            .pubsub_gateway()
-            .publish(
-                self.deps.context().await?,
-                format!("channel:{}", input.channel_id),
-                input.message.as_slice(),
-            )
-            .await?;
-        Ok(PublishOutput)
+        let message = publish_message(
+            &self.deps.config(),
+            self.deps.message_repository(),
+            self.deps.pubsub_gateway(),
+            self.deps.context().await?,
+            input.channel_id,
+            input.account_id,
+            input.parent_id,
+            input.message,
+        )
+        .await?;
+        Ok(PublishOutput::new(message))
     }
 }
 
+/// filters, persists and broadcasts a new message.
+/// a reply is delivered on the parent channel with a thread marker.
+#[allow(clippy::too_many_arguments)]
+async fn publish_message<C: Transaction>(
+    cfg: &Config,
+    repo: impl MessageRepository<C>,
+    gateway: impl PubSubGateway<C>,
+    ctx: C,
+    channel_id: String,
+    account_id: String,
+    parent_id: Option<String>,
+    message: Vec<u8>,
+) -> Result<Message> {
+    let parent = match parent_id {
+        Some(parent_id) => Some(
+            internal::channel::get_thread_root(repo.clone(), ctx.clone(), &channel_id, &parent_id)
+                .await?,
+        ),
+        None => None,
+    };
+    let message = internal::filter::apply(cfg, &channel_id, message)?;
+    let Ok(body) = String::from_utf8(message) else {
+        return Err(
+            Error::bad_request(Codes::MessageRejected, "message must be utf-8 text.").into(),
+        );
+    };
+    let now = current_time();
+    let message = Message::new(
+        uuid::new_v4().to_base62(),
+        channel_id,
+        account_id,
+        body,
+        parent.as_ref().map(|parent| parent.id.clone()),
+        0,
+        now,
+        now,
+        None,
+        None,
+    )
+    .validate()?;
+
+    let tx = ctx.begin().await?;
+    let message = repo.create(tx.clone(), message).await?;
+    let thread = match parent {
+        Some(parent) => {
+            repo.increment_reply_count(tx.clone(), parent.id.clone(), 1)
+                .await?;
+            let reply_count = match repo.get(tx.clone(), parent.id.clone()).await? {
+                Some(parent) => parent.reply_count,
+                None => parent.reply_count + 1,
+            };
+            Some(ThreadMarker::new(parent.id, reply_count))
+        }
+        None => None,
+    };
+    tx.commit().await?;
+
+    internal::channel::broadcast(
+        gateway,
+        ctx,
+        &message.channel_id,
+        &ChannelEvent::MessageCreated {
+            message: message.clone(),
+            thread,
+        },
+    )
+    .await?;
+    Ok(message)
+}
+
 #[derive(Clone, new)]
 pub struct PubSubUseCase<C, Deps> {
     deps: Deps,
@@ -78,7 +206,9 @@ pub struct PubSubUseCase<C, Deps> {
 }
 
 trait_set! {
-    pub trait PubSubUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    pub trait PubSubUseCaseDeps<C: Transaction> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseMessageRepository<C>
     + UsePubSubGateway<C>
     ;
 }
@@ -86,43 +216,69 @@ trait_set! {
 #[async_trait]
 impl<C, Deps> UseCase<PubSubInput, PubSubOutput> for PubSubUseCase<C, Deps>
 where
-    C: Component,
+    C: Transaction,
     Deps: PubSubUseCaseDeps<C>,
 {
     async fn handle(&self, input: PubSubInput) -> Result<PubSubOutput> {
         let receiver = input.receiver;
         let (sender, rx) = tokio::sync::mpsc::channel(1000);
         let channel_id = input.channel_id.clone();
+        let account_id = input.account_id.clone();
         let ctx = self.deps.context().await?;
         let _ctx = ctx.clone();
         let gateway = self.deps.pubsub_gateway();
+        let repo = self.deps.message_repository();
+        let cfg = self.deps.config();
+        let rejected_sender = sender.clone();
         let mut publish_task = tokio::spawn(async move {
             let mut stream = ReceiverStream::new(receiver);
-            while let Some(message) = stream.next().await {
-                if message.is_empty() {
+            while let Some(frame) = stream.next().await {
+                if frame.is_empty() {
                     continue;
                 }
-                match gateway
-                    .publish(
-                        _ctx.clone(),
-                        format!("channel:{channel_id}"),
-                        message.as_slice(),
-                    )
-                    .await
+                let (parent_id, message) = parse_frame(frame);
+                match publish_message(
+                    &cfg,
+                    repo.clone(),
+                    gateway.clone(),
+                    _ctx.clone(),
+                    channel_id.clone(),
+                    account_id.clone(),
+                    parent_id,
+                    message,
+                )
+                .await
                 {
                     Ok(_) => (),
-                    Err(e) => {
-                        log::debug!("publish error: {}", e);
-                        break;
-                    }
+                    Err(e) => match e.downcast_ref::<Error>() {
+                        Some(Error::BadRequest(code, detail)) => {
+                            log::info!("message rejected: {}", e);
+                            let rejected = ChannelEvent::Rejected {
+                                code: code.to_string(),
+                                detail: detail.clone(),
+                            };
+                            let Ok(frame) = serde_json::to_vec(&rejected) else {
+                                continue;
+                            };
+                            if rejected_sender.send(frame).await.is_err() {
+                                break;
+                            }
+                        }
+                        _ => {
+                            log::debug!("publish error: {}", e);
+                            break;
+                        }
+                    },
                 }
             }
         });
-        let receiver = self
-            .deps
-            .pubsub_gateway()
-            .subscribe(ctx.clone(), format!("channel:{}", input.channel_id))
-            .await?;
+        let receiver = internal::channel::subscribe(
+            self.deps.pubsub_gateway(),
+            ctx.clone(),
+            &input.channel_id,
+            Some(&input.account_id),
+        )
+        .await?;
         let mut subscribe_task = tokio::spawn(async move {
             let mut stream = ReceiverStream::new(receiver);
             while let Some(msg) = stream.next().await {
@@ -157,18 +313,276 @@ where
     }
 }
 
+#[derive(Clone, new)]
+pub struct MarkReadUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait MarkReadUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseMessageRepository<C>
+    + UseChannelRepository<C>
+    + UsePubSubGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<MarkReadInput, MarkReadOutput> for MarkReadUseCase<C, Deps>
+where
+    C: Component,
+    Deps: MarkReadUseCaseDeps<C>,
+{
+    async fn handle(&self, input: MarkReadInput) -> Result<MarkReadOutput> {
+        let ctx = self.deps.context().await?;
+        let channel_repo = self.deps.channel_repository();
+        let message = internal::channel::get_message(
+            self.deps.message_repository(),
+            ctx.clone(),
+            &input.channel_id,
+            &input.message_id,
+        )
+        .await?;
+        // a read position never moves backwards, e.g. when an older device catches up late.
+        if let Some(current) = channel_repo
+            .get_read_position(
+                ctx.clone(),
+                input.channel_id.clone(),
+                input.account_id.clone(),
+            )
+            .await?
+        {
+            if current.read_time >= message.create_time {
+                return Ok(MarkReadOutput::new(current));
+            }
+        }
+        let position = ReadPosition::new(
+            input.channel_id,
+            input.account_id,
+            message.id,
+            message.create_time,
+            current_time(),
+        )
+        .validate()?;
+        channel_repo
+            .save_read_position(ctx.clone(), position.clone())
+            .await?;
+        internal::channel::notify(
+            self.deps.pubsub_gateway(),
+            ctx,
+            &position.account_id,
+            &ChannelEvent::ReadPositionUpdated {
+                channel_id: position.channel_id.clone(),
+                account_id: position.account_id.clone(),
+                message_id: position.message_id.clone(),
+            },
+        )
+        .await?;
+        Ok(MarkReadOutput::new(position))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct GetUnreadCountsUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait GetUnreadCountsUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseChannelRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<GetUnreadCountsInput, GetUnreadCountsOutput>
+    for GetUnreadCountsUseCase<C, Deps>
+where
+    C: Component,
+    Deps: GetUnreadCountsUseCaseDeps<C>,
+{
+    async fn handle(&self, input: GetUnreadCountsInput) -> Result<GetUnreadCountsOutput> {
+        let counts = self
+            .deps
+            .channel_repository()
+            .count_unread(self.deps.context().await?, input.account_id)
+            .await?;
+        Ok(GetUnreadCountsOutput::new(counts))
+    }
+}
+
+/// a frame replying in a thread, `{"body": "...", "parent_id": "..."}`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ReplyFrame {
+    body: String,
+    #[serde(default)]
+    parent_id: Option<String>,
+}
+
+/// splits a websocket frame into the parent to reply to and the message body.
+/// frames other than a reply frame are the body of a new message as they are.
+fn parse_frame(frame: Vec<u8>) -> (Option<String>, Vec<u8>) {
+    match serde_json::from_slice::<ReplyFrame>(&frame) {
+        Ok(reply) => (reply.parent_id, reply.body.into_bytes()),
+        Err(_) => (None, frame),
+    }
+}
+
+#[derive(Clone, new)]
+pub struct AddModeratorUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait AddModeratorUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseAccountRepository<C>
+    + UseChannelRepository<C>
+    + UseMessageRepository<C>
+    ;
+}
+
+/// moderators add moderators. a channel without any is claimed by its creator adding itself, who
+/// posted its first message, or by the first account adding itself when it has no messages yet.
+#[async_trait]
+impl<C, Deps> UseCase<AddModeratorInput, AddModeratorOutput> for AddModeratorUseCase<C, Deps>
+where
+    C: Component,
+    Deps: AddModeratorUseCaseDeps<C>,
+{
+    async fn handle(&self, input: AddModeratorInput) -> Result<AddModeratorOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.channel_repository();
+        let mut moderators = repo
+            .find_moderators(ctx.clone(), input.channel_id.clone())
+            .await?;
+        if moderators.is_empty() {
+            let first = self
+                .deps
+                .message_repository()
+                .find_first(ctx.clone(), input.channel_id.clone())
+                .await?;
+            let creator = match first {
+                Some(message) => message.account_id == input.account_id,
+                // a channel without messages yet is created by claiming it.
+                None => true,
+            };
+            if !creator || input.moderator_id != input.account_id {
+                bail!(Error::forbidden(
+                    Codes::NotChannelModerator,
+                    "only the creator of the channel can claim it."
+                ))
+            }
+        } else if !is_moderator(&moderators, &input.account_id) {
+            bail!(Error::forbidden(
+                Codes::NotChannelModerator,
+                "not a moderator of the channel."
+            ))
+        }
+        if is_moderator(&moderators, &input.moderator_id) {
+            return Ok(AddModeratorOutput::new(moderators));
+        }
+        let account = self
+            .deps
+            .account_repository()
+            .get(ctx.clone(), input.moderator_id.clone())
+            .await?;
+        if account.is_none() {
+            bail!(Error::not_found(
+                Codes::AccountNotFound,
+                "account not found."
+            ))
+        }
+        let moderator = ChannelModerator::new(input.channel_id, input.moderator_id, current_time());
+        repo.add_moderator(ctx, moderator.clone()).await?;
+        moderators.push(moderator);
+        Ok(AddModeratorOutput::new(moderators))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct RemoveModeratorUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait RemoveModeratorUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseChannelRepository<C>
+    ;
+}
+
+/// moderators remove moderators, themselves included, as long as one is left.
+#[async_trait]
+impl<C, Deps> UseCase<RemoveModeratorInput, RemoveModeratorOutput>
+    for RemoveModeratorUseCase<C, Deps>
+where
+    C: Component,
+    Deps: RemoveModeratorUseCaseDeps<C>,
+{
+    async fn handle(&self, input: RemoveModeratorInput) -> Result<RemoveModeratorOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.channel_repository();
+        let mut moderators = repo
+            .find_moderators(ctx.clone(), input.channel_id.clone())
+            .await?;
+        if !is_moderator(&moderators, &input.account_id) {
+            bail!(Error::forbidden(
+                Codes::NotChannelModerator,
+                "not a moderator of the channel."
+            ))
+        }
+        if !is_moderator(&moderators, &input.moderator_id) {
+            return Ok(RemoveModeratorOutput::new(moderators));
+        }
+        // a channel left without moderators could be claimed by anyone.
+        if moderators.len() == 1 {
+            bail!(Error::bad_request(
+                Codes::LastChannelModerator,
+                "the last moderator of the channel can not be removed."
+            ))
+        }
+        repo.remove_moderator(ctx, input.channel_id, input.moderator_id.clone())
+            .await?;
+        moderators.retain(|moderator| moderator.account_id != input.moderator_id);
+        Ok(RemoveModeratorOutput::new(moderators))
+    }
+}
+
+fn is_moderator(moderators: &[ChannelModerator], account_id: &str) -> bool {
+    moderators
+        .iter()
+        .any(|moderator| moderator.account_id == account_id)
+}
+
 #[derive(new)]
 pub struct PublishInput {
     pub(crate) channel_id: String,
+    pub(crate) account_id: String,
+    pub(crate) parent_id: Option<String>,
     pub(crate) message: Vec<u8>,
 }
 
 #[derive(new)]
-pub struct PublishOutput;
+pub struct PublishOutput {
+    pub message: Message,
+}
 
 #[derive(new)]
 pub struct SubscribeInput {
     pub(crate) channel_id: String,
+    /// also receives the events private to this account, e.g. its read positions.
+    pub(crate) account_id: Option<String>,
+}
+
+/// subscribes again after a disconnect, starting with the messages created since the last one
+/// the client received.
+#[derive(new)]
+pub struct ResubscribeInput {
+    pub(crate) channel_id: String,
+    pub(crate) account_id: Option<String>,
+    pub(crate) last_message_id: String,
 }
 
 #[derive(new)]
@@ -179,6 +593,7 @@ pub struct SubscribeOutput {
 #[derive(new)]
 pub struct PubSubInput {
     pub(crate) channel_id: String,
+    pub(crate) account_id: String,
     pub(crate) receiver: Receiver<Vec<u8>>,
 }
 
@@ -186,3 +601,955 @@ pub struct PubSubInput {
 pub struct PubSubOutput {
     pub rx: Receiver<Vec<u8>>,
 }
+
+#[derive(new)]
+pub struct MarkReadInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) message_id: String,
+}
+
+#[derive(new)]
+pub struct MarkReadOutput {
+    pub read_position: ReadPosition,
+}
+
+#[derive(new)]
+pub struct GetUnreadCountsInput {
+    pub(crate) account_id: String,
+}
+
+#[derive(new)]
+pub struct GetUnreadCountsOutput {
+    pub unread_counts: Vec<UnreadCount>,
+}
+
+#[derive(new)]
+pub struct AddModeratorInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) moderator_id: String,
+}
+
+#[derive(new)]
+pub struct AddModeratorOutput {
+    pub moderators: Vec<ChannelModerator>,
+}
+
+#[derive(new)]
+pub struct RemoveModeratorInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) moderator_id: String,
+}
+
+#[derive(new)]
+pub struct RemoveModeratorOutput {
+    pub moderators: Vec<ChannelModerator>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::config::{Auth, Config, ConfigBuilder, MessageFilterBuilder, System};
+    use crate::interface::gateway::pubsub::{
+        MockPubSubGateway, SubscriptionEvent, UsePubSubGateway,
+    };
+    use crate::interface::repository::account::{MockAccountRepository, UseAccountRepository};
+    use crate::interface::repository::channel::{MockChannelRepository, UseChannelRepository};
+    use crate::interface::repository::message::{MockMessageRepository, UseMessageRepository};
+    use crate::interface::{UseConfig, UseContext};
+    use crate::internal;
+    use crate::usecase::channel::{
+        AddModeratorInput, AddModeratorUseCase, MarkReadInput, MarkReadUseCase, PubSubInput,
+        PubSubUseCase, PublishInput, PublishUseCase, RemoveModeratorInput, RemoveModeratorUseCase,
+        ResubscribeInput, ResubscribeUseCase, SubscribeInput, SubscribeUseCase,
+    };
+    use crate::usecase::UseCase;
+    use async_trait::async_trait;
+    use helper::time::current_time;
+    use kernel::entity::{
+        Account, ChannelEvent, ChannelModerator, Message, MessageFilterRule, ProfanityAction,
+        ReadPosition, ThreadMarker,
+    };
+    use kernel::error::{Codes, Error};
+    use kernel::Result;
+    use mockall::predicate;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Clone)]
+    struct TestMods {
+        mock_message_repo: Arc<MockMessageRepository>,
+        mock_channel_repo: Arc<MockChannelRepository>,
+        mock_pubsub_gateway: Arc<MockPubSubGateway>,
+        mock_account_repo: Arc<MockAccountRepository>,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            let rules = vec![
+                MessageFilterRule::Profanity {
+                    words: vec!["darn".to_string()],
+                    action: ProfanityAction::Mask,
+                },
+                MessageFilterRule::Link {
+                    allowed_hosts: vec!["example.com".to_string()],
+                },
+                MessageFilterRule::Length { min: 1, max: 64 },
+            ];
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .message_filter(
+                    MessageFilterBuilder::default()
+                        .rules(HashMap::from([("channel_id".to_string(), rules)]))
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseMessageRepository<()> for TestMods {
+        type MessageRepository = Arc<MockMessageRepository>;
+
+        fn message_repository(&self) -> Self::MessageRepository {
+            self.mock_message_repo.clone()
+        }
+    }
+    impl UseChannelRepository<()> for TestMods {
+        type ChannelRepository = Arc<MockChannelRepository>;
+
+        fn channel_repository(&self) -> Self::ChannelRepository {
+            self.mock_channel_repo.clone()
+        }
+    }
+    impl UsePubSubGateway<()> for TestMods {
+        type Gateway = Arc<MockPubSubGateway>;
+
+        fn pubsub_gateway(&self) -> Self::Gateway {
+            self.mock_pubsub_gateway.clone()
+        }
+    }
+    impl UseAccountRepository<()> for TestMods {
+        type AccountRepository = Arc<MockAccountRepository>;
+
+        fn account_repository(&self) -> Self::AccountRepository {
+            self.mock_account_repo.clone()
+        }
+    }
+
+    fn mock_message_repo() -> MockMessageRepository {
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_create()
+            .returning(|_, message| Ok(message));
+        mock_message_repo
+    }
+
+    fn created_body(payload: &[u8]) -> String {
+        match serde_json::from_slice::<ChannelEvent>(payload).unwrap() {
+            ChannelEvent::MessageCreated { message, .. } => message.body,
+            event => panic!("unexpected event: {event:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_rewritten_message() {
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_publish()
+            .with(
+                predicate::eq(()),
+                predicate::eq("channel:channel_id".to_string()),
+                predicate::function(|payload: &[u8]| {
+                    created_body(payload) == "oh **** see https://www.example.com"
+                }),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let usecase = PublishUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo()),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let input = PublishInput::new(
+            "channel_id".to_string(),
+            "account_id".to_string(),
+            None,
+            b"oh Darn see https://www.example.com".to_vec(),
+        );
+        let output = usecase.handle(input).await.unwrap();
+        assert_eq!(output.message.account_id, "account_id");
+        assert_eq!(output.message.body, "oh **** see https://www.example.com");
+    }
+
+    #[tokio::test]
+    async fn publish_thread_reply() {
+        let mut mock_message_repo = mock_message_repo();
+        let root = |reply_count| {
+            let now = current_time();
+            Message::new(
+                "root_id".to_string(),
+                "channel_id".to_string(),
+                "author_id".to_string(),
+                "root".to_string(),
+                None,
+                reply_count,
+                now,
+                now,
+                None,
+                None,
+            )
+        };
+        let mut reply = root(0);
+        reply.id = "reply_id".to_string();
+        reply.parent_id = Some("root_id".to_string());
+        let mut root_calls = 0;
+        mock_message_repo
+            .expect_get()
+            .returning(move |_, id| match id.as_str() {
+                "reply_id" => Ok(Some(reply.clone())),
+                _ => {
+                    root_calls += 1;
+                    Ok(Some(root(if root_calls == 1 { 1 } else { 2 })))
+                }
+            });
+        mock_message_repo
+            .expect_increment_reply_count()
+            .with(
+                predicate::eq(()),
+                predicate::eq("root_id".to_string()),
+                predicate::eq(1),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_publish()
+            .withf(|_, channel, payload| {
+                channel == "channel:channel_id"
+                    && matches!(
+                        serde_json::from_slice::<ChannelEvent>(payload).unwrap(),
+                        ChannelEvent::MessageCreated { message, thread: Some(thread) }
+                            if message.parent_id.as_deref() == Some("root_id")
+                                && thread == ThreadMarker::new("root_id".to_string(), 2)
+                    )
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let usecase = PublishUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        // replying to a reply joins the thread of its root.
+        let input = PublishInput::new(
+            "channel_id".to_string(),
+            "account_id".to_string(),
+            Some("reply_id".to_string()),
+            b"hello".to_vec(),
+        );
+        let output = usecase.handle(input).await.unwrap();
+        assert_eq!(output.message.parent_id.as_deref(), Some("root_id"));
+    }
+
+    #[tokio::test]
+    async fn publish_rejected_message() {
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway.expect_publish().never();
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo.expect_create().never();
+        let usecase = PublishUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let input = PublishInput::new(
+            "channel_id".to_string(),
+            "account_id".to_string(),
+            None,
+            b"see http://evil.test/x".to_vec(),
+        );
+        let err = usecase.handle(input).await.err().unwrap();
+        let Some(Error::BadRequest(Codes::MessageRejected, Some(reason))) =
+            err.downcast_ref::<Error>()
+        else {
+            panic!("unexpected error: {err:?}");
+        };
+        assert_eq!(reason, "link to evil.test is not allowed.");
+    }
+
+    #[tokio::test]
+    async fn pubsub_reports_rejected_message() {
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        let (published_sender, published) = std::sync::mpsc::channel();
+        let published_sender = Mutex::new(published_sender);
+        mock_pubsub_gateway
+            .expect_publish()
+            .returning(move |_, _, message| {
+                published_sender
+                    .lock()
+                    .unwrap()
+                    .send(message.to_vec())
+                    .unwrap();
+                Ok(())
+            });
+        // the channel and the account topics, kept open by the mock for the whole test.
+        let sub_senders = Arc::new(Mutex::new(vec![]));
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .times(2)
+            .returning(move |_, _| {
+                let (sub_sender, sub_receiver) = tokio::sync::mpsc::channel(10);
+                sub_senders.lock().unwrap().push(sub_sender);
+                Ok(sub_receiver)
+            });
+        let usecase = PubSubUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo()),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let (sender, receiver) = tokio::sync::mpsc::channel(10);
+        let mut output = usecase
+            .handle(PubSubInput::new(
+                "channel_id".to_string(),
+                "account_id".to_string(),
+                receiver,
+            ))
+            .await
+            .unwrap();
+        sender.send("a".repeat(65).into_bytes()).await.unwrap();
+        sender.send(b"hello".to_vec()).await.unwrap();
+        // only the sender learns why, the rejected message is not published.
+        let rejected = serde_json::from_slice::<ChannelEvent>(&output.rx.recv().await.unwrap());
+        assert!(matches!(
+            rejected.unwrap(),
+            ChannelEvent::Rejected { code, detail: Some(detail) }
+                if code == Codes::MessageRejected.to_string()
+                    && detail == "message must be at most 64 characters."
+        ));
+        let message = tokio::task::spawn_blocking(move || {
+            published.recv_timeout(Duration::from_secs(1)).unwrap()
+        })
+        .await
+        .unwrap();
+        assert_eq!(created_body(&message), "hello");
+    }
+
+    #[tokio::test]
+    async fn pubsub_publishes_reply_frame() {
+        let mut mock_message_repo = mock_message_repo();
+        mock_message_repo.expect_get().returning(|_, id| {
+            let now = current_time();
+            Ok(Some(Message::new(
+                id,
+                "channel_id".to_string(),
+                "author_id".to_string(),
+                "root".to_string(),
+                None,
+                1,
+                now,
+                now,
+                None,
+                None,
+            )))
+        });
+        mock_message_repo
+            .expect_increment_reply_count()
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        let (published_sender, published) = std::sync::mpsc::channel();
+        let published_sender = Mutex::new(published_sender);
+        mock_pubsub_gateway
+            .expect_publish()
+            .returning(move |_, _, message| {
+                published_sender
+                    .lock()
+                    .unwrap()
+                    .send(message.to_vec())
+                    .unwrap();
+                Ok(())
+            });
+        // the channel and the account topics, kept open by the mock for the whole test.
+        let sub_senders = Arc::new(Mutex::new(vec![]));
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .times(2)
+            .returning(move |_, _| {
+                let (sub_sender, sub_receiver) = tokio::sync::mpsc::channel(10);
+                sub_senders.lock().unwrap().push(sub_sender);
+                Ok(sub_receiver)
+            });
+        let usecase = PubSubUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let (sender, receiver) = tokio::sync::mpsc::channel(10);
+        let _output = usecase
+            .handle(PubSubInput::new(
+                "channel_id".to_string(),
+                "account_id".to_string(),
+                receiver,
+            ))
+            .await
+            .unwrap();
+        sender
+            .send(br#"{"body":"hello","parent_id":"root_id"}"#.to_vec())
+            .await
+            .unwrap();
+        // anything else is a message body as it is.
+        sender.send(br#"{"text":"hi"}"#.to_vec()).await.unwrap();
+        let mut events = tokio::task::spawn_blocking(move || {
+            (0..2)
+                .map(|_| published.recv_timeout(Duration::from_secs(1)).unwrap())
+                .map(|payload| serde_json::from_slice::<ChannelEvent>(&payload).unwrap())
+                .collect::<Vec<_>>()
+        })
+        .await
+        .unwrap()
+        .into_iter();
+        assert!(matches!(
+            events.next().unwrap(),
+            ChannelEvent::MessageCreated { message, thread: Some(thread) }
+                if message.body == "hello" && thread.parent_id == "root_id"
+        ));
+        assert!(matches!(
+            events.next().unwrap(),
+            ChannelEvent::MessageCreated { message, thread: None }
+                if message.body == r#"{"text":"hi"}"#
+        ));
+    }
+
+    #[tokio::test]
+    async fn subscribe_notifies_reconnect() {
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .with(
+                predicate::eq(()),
+                predicate::eq("channel:channel_id".to_string()),
+            )
+            .return_once(|_, _| {
+                let (tx, rx) = tokio::sync::mpsc::channel(10);
+                tx.try_send(SubscriptionEvent::Message(b"before".to_vec()))
+                    .unwrap();
+                tx.try_send(SubscriptionEvent::Reconnected).unwrap();
+                tx.try_send(SubscriptionEvent::Message(b"after".to_vec()))
+                    .unwrap();
+                Ok(rx)
+            });
+        let usecase = SubscribeUseCase::new(TestMods {
+            mock_message_repo: Arc::new(MockMessageRepository::default()),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let mut output = usecase
+            .handle(SubscribeInput::new("channel_id".to_string(), None))
+            .await
+            .unwrap();
+        assert_eq!(output.rx.recv().await.unwrap(), b"before");
+        assert!(matches!(
+            serde_json::from_slice::<ChannelEvent>(&output.rx.recv().await.unwrap()).unwrap(),
+            ChannelEvent::Reconnected
+        ));
+        assert_eq!(output.rx.recv().await.unwrap(), b"after");
+    }
+
+    #[tokio::test]
+    async fn subscribe_includes_account_events() {
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .times(2)
+            .returning(|_, topic| {
+                let (tx, rx) = tokio::sync::mpsc::channel(10);
+                tx.try_send(SubscriptionEvent::Message(topic.into_bytes()))
+                    .unwrap();
+                Ok(rx)
+            });
+        let usecase = SubscribeUseCase::new(TestMods {
+            mock_message_repo: Arc::new(MockMessageRepository::default()),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let mut output = usecase
+            .handle(SubscribeInput::new(
+                "channel_id".to_string(),
+                Some("account_id".to_string()),
+            ))
+            .await
+            .unwrap();
+        let mut topics = vec![
+            output.rx.recv().await.unwrap(),
+            output.rx.recv().await.unwrap(),
+        ];
+        topics.sort();
+        assert_eq!(
+            topics,
+            vec![
+                b"account:account_id".to_vec(),
+                b"channel:channel_id".to_vec()
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn resubscribe_replays_missed_messages() {
+        let now = current_time();
+        let message = move |id: &str, parent_id: Option<&str>| {
+            Message::new(
+                id.to_string(),
+                "channel_id".to_string(),
+                "author_id".to_string(),
+                "body".to_string(),
+                parent_id.map(str::to_string),
+                0,
+                now,
+                now,
+                None,
+                None,
+            )
+        };
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_find_after()
+            .with(
+                predicate::eq(()),
+                predicate::eq("channel_id".to_string()),
+                predicate::eq("last".to_string()),
+                predicate::eq(internal::channel::MAX_REPLAY + 1),
+            )
+            .return_once(move |_, _, _, _| {
+                Ok(vec![
+                    message("missed", None),
+                    message("reply", Some("parent")),
+                ])
+            });
+        mock_message_repo
+            .expect_get()
+            .with(predicate::eq(()), predicate::eq("parent".to_string()))
+            .return_once(move |_, _| {
+                let mut parent = message("parent", None);
+                parent.reply_count = 3;
+                Ok(Some(parent))
+            });
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .return_once(move |_, _| {
+                // the reply was published after the subscription was made, so it arrives twice.
+                let (tx, rx) = tokio::sync::mpsc::channel(10);
+                let live = |id: &str| {
+                    let event = ChannelEvent::MessageCreated {
+                        message: message(id, None),
+                        thread: None,
+                    };
+                    SubscriptionEvent::Message(serde_json::to_vec(&event).unwrap())
+                };
+                tx.try_send(live("reply")).unwrap();
+                tx.try_send(live("live")).unwrap();
+                Ok(rx)
+            });
+        let usecase = ResubscribeUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let mut output = usecase
+            .handle(ResubscribeInput::new(
+                "channel_id".to_string(),
+                None,
+                "last".to_string(),
+            ))
+            .await
+            .unwrap();
+        let mut received = vec![];
+        for _ in 0..3 {
+            match serde_json::from_slice::<ChannelEvent>(&output.rx.recv().await.unwrap()).unwrap()
+            {
+                ChannelEvent::MessageCreated { message, thread } => {
+                    received.push((message.id, thread))
+                }
+                event => panic!("unexpected event: {event:?}"),
+            }
+        }
+        assert_eq!(
+            received,
+            vec![
+                ("missed".to_string(), None),
+                (
+                    "reply".to_string(),
+                    Some(ThreadMarker::new("parent".to_string(), 3))
+                ),
+                ("live".to_string(), None),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn resubscribe_too_far_behind_notifies_reconnect() {
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_find_after()
+            .return_once(move |_, _, _, limit| {
+                let now = current_time();
+                Ok((0..limit)
+                    .map(|i| {
+                        Message::new(
+                            i.to_string(),
+                            "channel_id".to_string(),
+                            "author_id".to_string(),
+                            "body".to_string(),
+                            None,
+                            0,
+                            now,
+                            now,
+                            None,
+                            None,
+                        )
+                    })
+                    .collect())
+            });
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .return_once(|_, _| Ok(tokio::sync::mpsc::channel(10).1));
+        let usecase = ResubscribeUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let mut output = usecase
+            .handle(ResubscribeInput::new(
+                "channel_id".to_string(),
+                None,
+                "last".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert!(matches!(
+            serde_json::from_slice::<ChannelEvent>(&output.rx.recv().await.unwrap()).unwrap(),
+            ChannelEvent::Reconnected
+        ));
+        assert!(output.rx.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn mark_read_moves_forward_only() {
+        let now = current_time();
+        let message = move |id: &str, create_time| {
+            Message::new(
+                id.to_string(),
+                "channel_id".to_string(),
+                "author_id".to_string(),
+                "body".to_string(),
+                None,
+                0,
+                create_time,
+                create_time,
+                None,
+                None,
+            )
+        };
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_get()
+            .returning(move |_, id| match id.as_str() {
+                "old_id" => Ok(Some(message("old_id", now - chrono::Duration::seconds(10)))),
+                _ => Ok(Some(message("new_id", now + chrono::Duration::seconds(10)))),
+            });
+        let mut mock_channel_repo = MockChannelRepository::default();
+        mock_channel_repo
+            .expect_get_read_position()
+            .returning(move |_, channel_id, account_id| {
+                Ok(Some(ReadPosition::new(
+                    channel_id,
+                    account_id,
+                    "current_id".to_string(),
+                    now,
+                    now,
+                )))
+            });
+        mock_channel_repo
+            .expect_save_read_position()
+            .withf(|_, position| position.message_id == "new_id")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_publish()
+            .withf(|_, channel, payload| {
+                channel == "account:account_id"
+                    && matches!(
+                        serde_json::from_slice::<ChannelEvent>(payload).unwrap(),
+                        ChannelEvent::ReadPositionUpdated { channel_id, account_id, message_id }
+                            if channel_id == "channel_id"
+                                && account_id == "account_id"
+                                && message_id == "new_id"
+                    )
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let usecase = MarkReadUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(mock_channel_repo),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+
+        let input = MarkReadInput::new(
+            "account_id".to_string(),
+            "channel_id".to_string(),
+            "old_id".to_string(),
+        );
+        let output = usecase.handle(input).await.unwrap();
+        assert_eq!(output.read_position.message_id, "current_id");
+
+        let input = MarkReadInput::new(
+            "account_id".to_string(),
+            "channel_id".to_string(),
+            "new_id".to_string(),
+        );
+        let output = usecase.handle(input).await.unwrap();
+        assert_eq!(output.read_position.message_id, "new_id");
+    }
+
+    fn moderator(account_id: &str) -> ChannelModerator {
+        ChannelModerator::new(
+            "channel_id".to_string(),
+            account_id.to_string(),
+            current_time(),
+        )
+    }
+
+    #[tokio::test]
+    async fn add_moderator() {
+        let mut mock_channel_repo = MockChannelRepository::default();
+        mock_channel_repo
+            .expect_find_moderators()
+            .with(predicate::eq(()), predicate::eq("channel_id".to_string()))
+            .returning(|_, _| Ok(vec![moderator("moderator_id")]));
+        mock_channel_repo
+            .expect_add_moderator()
+            .withf(|_, moderator| {
+                moderator.channel_id == "channel_id" && moderator.account_id == "account_id"
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut mock_account_repo = MockAccountRepository::default();
+        mock_account_repo.expect_get().returning(|_, id| {
+            Ok((id == "account_id")
+                .then(|| Account::new(id, "name".to_string(), "name".to_string(), current_time())))
+        });
+        let usecase = AddModeratorUseCase::new(TestMods {
+            mock_message_repo: Arc::new(MockMessageRepository::default()),
+            mock_channel_repo: Arc::new(mock_channel_repo),
+            mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+            mock_account_repo: Arc::new(mock_account_repo),
+        });
+
+        let input = AddModeratorInput::new(
+            "account_id".to_string(),
+            "channel_id".to_string(),
+            "account_id".to_string(),
+        );
+        let err = usecase.handle(input).await.err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<Error>().unwrap().codes(),
+            &Codes::NotChannelModerator
+        );
+
+        let input = AddModeratorInput::new(
+            "moderator_id".to_string(),
+            "channel_id".to_string(),
+            "unknown_id".to_string(),
+        );
+        let err = usecase.handle(input).await.err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<Error>().unwrap().codes(),
+            &Codes::AccountNotFound
+        );
+
+        let input = AddModeratorInput::new(
+            "moderator_id".to_string(),
+            "channel_id".to_string(),
+            "account_id".to_string(),
+        );
+        let output = usecase.handle(input).await.unwrap();
+        let moderators: Vec<_> = output
+            .moderators
+            .iter()
+            .map(|moderator| moderator.account_id.as_str())
+            .collect();
+        assert_eq!(moderators, vec!["moderator_id", "account_id"]);
+    }
+
+    /// the first message of "channel_id", posted by `account_id`.
+    fn first_message(account_id: &str) -> Message {
+        Message::new(
+            "first_id".to_string(),
+            "channel_id".to_string(),
+            account_id.to_string(),
+            "body".to_string(),
+            None,
+            0,
+            current_time(),
+            current_time(),
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn add_moderator_claims_unmoderated_channel() {
+        for first in [Some(first_message("account_id")), None] {
+            let mut mock_channel_repo = MockChannelRepository::default();
+            mock_channel_repo
+                .expect_find_moderators()
+                .returning(|_, _| Ok(vec![]));
+            mock_channel_repo
+                .expect_add_moderator()
+                .times(1)
+                .returning(|_, _| Ok(()));
+            let mut mock_message_repo = MockMessageRepository::default();
+            mock_message_repo
+                .expect_find_first()
+                .with(predicate::always(), predicate::eq("channel_id".to_string()))
+                .return_once(move |_, _| Ok(first));
+            let mut mock_account_repo = MockAccountRepository::default();
+            mock_account_repo.expect_get().returning(|_, id| {
+                Ok(Some(Account::new(
+                    id,
+                    "name".to_string(),
+                    "name".to_string(),
+                    current_time(),
+                )))
+            });
+            let usecase = AddModeratorUseCase::new(TestMods {
+                mock_message_repo: Arc::new(mock_message_repo),
+                mock_channel_repo: Arc::new(mock_channel_repo),
+                mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+                mock_account_repo: Arc::new(mock_account_repo),
+            });
+            let input = AddModeratorInput::new(
+                "account_id".to_string(),
+                "channel_id".to_string(),
+                "account_id".to_string(),
+            );
+            let output = usecase.handle(input).await.unwrap();
+            assert_eq!(output.moderators.len(), 1);
+            assert_eq!(output.moderators[0].account_id, "account_id");
+        }
+    }
+
+    #[tokio::test]
+    async fn add_moderator_by_non_creator_is_forbidden() {
+        let mut mock_channel_repo = MockChannelRepository::default();
+        mock_channel_repo
+            .expect_find_moderators()
+            .returning(|_, _| Ok(vec![]));
+        mock_channel_repo.expect_add_moderator().never();
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_find_first()
+            .returning(|_, _| Ok(Some(first_message("creator_id"))));
+        let usecase = AddModeratorUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(mock_channel_repo),
+            mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+        let input = AddModeratorInput::new(
+            "account_id".to_string(),
+            "channel_id".to_string(),
+            "account_id".to_string(),
+        );
+        let err = usecase.handle(input).await.err().unwrap();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::Forbidden(Codes::NotChannelModerator, _))
+        ));
+    }
+
+    #[tokio::test]
+    async fn remove_moderator() {
+        let moderators = Arc::new(Mutex::new(vec![
+            moderator("moderator_id"),
+            moderator("account_id"),
+        ]));
+        let mut mock_channel_repo = MockChannelRepository::default();
+        let found = moderators.clone();
+        mock_channel_repo
+            .expect_find_moderators()
+            .returning(move |_, _| Ok(found.lock().unwrap().clone()));
+        let removed = moderators.clone();
+        mock_channel_repo
+            .expect_remove_moderator()
+            .times(1)
+            .returning(move |_, _, account_id| {
+                removed
+                    .lock()
+                    .unwrap()
+                    .retain(|moderator| moderator.account_id != account_id);
+                Ok(())
+            });
+        let usecase = RemoveModeratorUseCase::new(TestMods {
+            mock_message_repo: Arc::new(MockMessageRepository::default()),
+            mock_channel_repo: Arc::new(mock_channel_repo),
+            mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+            mock_account_repo: Arc::new(MockAccountRepository::default()),
+        });
+
+        let input = RemoveModeratorInput::new(
+            "other_id".to_string(),
+            "channel_id".to_string(),
+            "account_id".to_string(),
+        );
+        let err = usecase.handle(input).await.err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<Error>().unwrap().codes(),
+            &Codes::NotChannelModerator
+        );
+
+        let input = RemoveModeratorInput::new(
+            "moderator_id".to_string(),
+            "channel_id".to_string(),
+            "account_id".to_string(),
+        );
+        let output = usecase.handle(input).await.unwrap();
+        assert_eq!(
+            output.moderators,
+            vec![moderators.lock().unwrap()[0].clone()]
+        );
+
+        let input = RemoveModeratorInput::new(
+            "moderator_id".to_string(),
+            "channel_id".to_string(),
+            "moderator_id".to_string(),
+        );
+        let err = usecase.handle(input).await.err().unwrap();
+        assert_eq!(
+            err.downcast_ref::<Error>().unwrap().codes(),
+            &Codes::LastChannelModerator
+        );
+    }
+}
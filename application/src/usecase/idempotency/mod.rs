@@ -0,0 +1,248 @@
+use crate::interface::repository::idempotency::{IdempotencyRepository, UseIdempotencyRepository};
+use crate::interface::{Component, UseConfig, UseContext};
+use crate::usecase::UseCase;
+use async_trait::async_trait;
+use kernel::entity::{IdempotencyRecord, IdempotentResponse};
+use kernel::Result;
+use std::marker::PhantomData;
+use trait_set::trait_set;
+
+#[derive(Clone, new)]
+pub struct BeginIdempotencyUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait BeginIdempotencyUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseIdempotencyRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<BeginIdempotencyInput, BeginIdempotencyOutput>
+    for BeginIdempotencyUseCase<C, Deps>
+where
+    C: Component,
+    Deps: BeginIdempotencyUseCaseDeps<C>,
+{
+    async fn handle(&self, input: BeginIdempotencyInput) -> Result<BeginIdempotencyOutput> {
+        let key = storage_key(&input.key, input.session_id);
+        let existing = self
+            .deps
+            .idempotency_repository()
+            .insert(
+                self.deps.context().await?,
+                key.clone(),
+                IdempotencyRecord::InProgress {
+                    fingerprint: input.fingerprint.clone(),
+                },
+                self.deps.config().idempotency.lock_ttl,
+            )
+            .await?;
+        let state = match existing {
+            None => IdempotencyState::Started,
+            Some(record) if record.fingerprint() != input.fingerprint => IdempotencyState::Mismatch,
+            Some(IdempotencyRecord::InProgress { .. }) => IdempotencyState::InProgress,
+            Some(IdempotencyRecord::Completed { response, .. }) => {
+                IdempotencyState::Replay(response)
+            }
+        };
+        Ok(BeginIdempotencyOutput::new(key, state))
+    }
+}
+
+#[derive(new)]
+pub struct BeginIdempotencyInput {
+    key: String,
+    session_id: Option<String>,
+    fingerprint: String,
+}
+
+/// `key` is what [CompleteIdempotencyInput] expects when the state is `Started`.
+#[derive(new)]
+pub struct BeginIdempotencyOutput {
+    pub key: String,
+    pub state: IdempotencyState,
+}
+
+pub enum IdempotencyState {
+    /// the key is now held by this request.
+    Started,
+    /// another request with the key has not completed yet.
+    InProgress,
+    /// the key was used for a request with another fingerprint.
+    Mismatch,
+    Replay(IdempotentResponse),
+}
+
+#[derive(Clone, new)]
+pub struct CompleteIdempotencyUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait CompleteIdempotencyUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseIdempotencyRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<CompleteIdempotencyInput, CompleteIdempotencyOutput>
+    for CompleteIdempotencyUseCase<C, Deps>
+where
+    C: Component,
+    Deps: CompleteIdempotencyUseCaseDeps<C>,
+{
+    async fn handle(&self, input: CompleteIdempotencyInput) -> Result<CompleteIdempotencyOutput> {
+        let ctx = self.deps.context().await?;
+        let repository = self.deps.idempotency_repository();
+        match input.response {
+            Some(response) => {
+                repository
+                    .set(
+                        ctx,
+                        input.key,
+                        IdempotencyRecord::Completed {
+                            fingerprint: input.fingerprint,
+                            response,
+                        },
+                        self.deps.config().idempotency.ttl,
+                    )
+                    .await?
+            }
+            None => repository.delete(ctx, input.key).await?,
+        }
+        Ok(CompleteIdempotencyOutput)
+    }
+}
+
+/// `response` is `None` when the request failed in a way worth retrying, which releases the key.
+#[derive(new)]
+pub struct CompleteIdempotencyInput {
+    key: String,
+    fingerprint: String,
+    response: Option<IdempotentResponse>,
+}
+
+pub struct CompleteIdempotencyOutput;
+
+/// keys without a session, e.g. of signup, are shared by every client without one, so that
+/// retries work whether or not the client consented to tracking. clients send random keys, and a
+/// response is only replayed to the same request as well.
+fn storage_key(key: &str, session_id: Option<String>) -> String {
+    match session_id {
+        Some(id) => format!("session:{}:{}", id, key),
+        None => format!("anonymous:{}", key),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::config::{Auth, Config, ConfigBuilder, System};
+    use crate::interface::repository::idempotency::{
+        MockIdempotencyRepository, UseIdempotencyRepository,
+    };
+    use crate::interface::{UseConfig, UseContext};
+    use crate::usecase::idempotency::{
+        BeginIdempotencyInput, BeginIdempotencyUseCase, IdempotencyState,
+    };
+    use crate::usecase::UseCase;
+    use async_trait::async_trait;
+    use kernel::entity::{IdempotencyRecord, IdempotentResponse};
+    use kernel::Result;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct TestMods {
+        mock_idempotency_repository: Arc<MockIdempotencyRepository>,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseIdempotencyRepository<()> for TestMods {
+        type IdempotencyRepository = Arc<MockIdempotencyRepository>;
+
+        fn idempotency_repository(&self) -> Self::IdempotencyRepository {
+            self.mock_idempotency_repository.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_completed_response_for_same_request() {
+        let mut mock_idempotency_repository = MockIdempotencyRepository::default();
+        mock_idempotency_repository
+            .expect_insert()
+            .with(
+                predicate::always(),
+                predicate::eq("session:sid:key".to_string()),
+                predicate::always(),
+                predicate::always(),
+            )
+            .times(2)
+            .returning(|_, _, _, _| {
+                Ok(Some(IdempotencyRecord::Completed {
+                    fingerprint: "fp".to_string(),
+                    response: IdempotentResponse::new(201, vec![], b"{}".to_vec()),
+                }))
+            });
+        let usecase = BeginIdempotencyUseCase::new(TestMods {
+            mock_idempotency_repository: Arc::new(mock_idempotency_repository),
+        });
+        let input = |fingerprint: &str| {
+            BeginIdempotencyInput::new(
+                "key".to_string(),
+                Some("sid".to_string()),
+                fingerprint.to_string(),
+            )
+        };
+        let output = usecase.handle(input("fp")).await.unwrap();
+        assert!(matches!(output.state, IdempotencyState::Replay(r) if r.status == 201));
+        let output = usecase.handle(input("other")).await.unwrap();
+        assert!(matches!(output.state, IdempotencyState::Mismatch));
+    }
+
+    #[tokio::test]
+    async fn scopes_keys_without_session_to_the_key() {
+        let mut mock_idempotency_repository = MockIdempotencyRepository::default();
+        mock_idempotency_repository
+            .expect_insert()
+            .with(
+                predicate::always(),
+                predicate::eq("anonymous:key".to_string()),
+                predicate::always(),
+                predicate::always(),
+            )
+            .return_once(|_, _, _, _| Ok(None));
+        let usecase = BeginIdempotencyUseCase::new(TestMods {
+            mock_idempotency_repository: Arc::new(mock_idempotency_repository),
+        });
+        let output = usecase
+            .handle(BeginIdempotencyInput::new(
+                "key".to_string(),
+                None,
+                "fp".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(output.key, "anonymous:key");
+        assert!(matches!(output.state, IdempotencyState::Started));
+    }
+}
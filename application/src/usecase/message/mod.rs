@@ -0,0 +1,664 @@
+use crate::interface::gateway::pubsub::UsePubSubGateway;
+use crate::interface::repository::channel::{ChannelRepository, UseChannelRepository};
+use crate::interface::repository::message::{MessageRepository, UseMessageRepository};
+use crate::interface::repository::Transaction;
+use crate::interface::{Component, UseConfig, UseContext};
+use crate::internal;
+use crate::usecase::UseCase;
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use helper::time::current_time;
+use helper::uuid;
+use helper::uuid::ToBase62;
+use helper::validation::Validation;
+use kernel::entity::{ChannelEvent, Message, MessageEdit, Reaction, ReactionCount, ThreadMarker};
+use kernel::error::{Codes, Error};
+use std::marker::PhantomData;
+use trait_set::trait_set;
+
+#[derive(Clone, new)]
+pub struct EditMessageUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait EditMessageUseCaseDeps<C: Transaction> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseMessageRepository<C>
+    + UseChannelRepository<C>
+    + UsePubSubGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<EditMessageInput, EditMessageOutput> for EditMessageUseCase<C, Deps>
+where
+    C: Transaction,
+    Deps: EditMessageUseCaseDeps<C>,
+{
+    async fn handle(&self, input: EditMessageInput) -> Result<EditMessageOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.message_repository();
+        let mut message = get_editable_message(
+            repo.clone(),
+            self.deps.channel_repository(),
+            ctx.clone(),
+            &input.account_id,
+            &input.channel_id,
+            &input.message_id,
+        )
+        .await?;
+        let body = internal::filter::apply(&self.deps.config(), &input.channel_id, input.body)?;
+        let Ok(body) = String::from_utf8(body) else {
+            return Err(
+                Error::bad_request(Codes::MessageRejected, "message must be utf-8 text.").into(),
+            );
+        };
+        let now = current_time();
+        let edit = MessageEdit::new(
+            uuid::new_v4().to_base62(),
+            message.id.clone(),
+            message.body.clone(),
+            input.account_id,
+            now,
+        )
+        .validate()?;
+        message.body = body;
+        message.update_time = now;
+        let message = message.validate()?;
+
+        let tx = ctx.begin().await?;
+        repo.create_edit(tx.clone(), edit).await?;
+        repo.update(tx.clone(), message.clone()).await?;
+        tx.commit().await?;
+
+        internal::channel::broadcast(
+            self.deps.pubsub_gateway(),
+            ctx,
+            &message.channel_id,
+            &ChannelEvent::MessageEdited {
+                message: message.clone(),
+            },
+        )
+        .await?;
+        Ok(EditMessageOutput::new(message))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct DeleteMessageUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait DeleteMessageUseCaseDeps<C: Transaction> = Component + UseContext<Context = C>
+    + UseMessageRepository<C>
+    + UseChannelRepository<C>
+    + UsePubSubGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<DeleteMessageInput, DeleteMessageOutput> for DeleteMessageUseCase<C, Deps>
+where
+    C: Transaction,
+    Deps: DeleteMessageUseCaseDeps<C>,
+{
+    async fn handle(&self, input: DeleteMessageInput) -> Result<DeleteMessageOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.message_repository();
+        let message = get_editable_message(
+            repo.clone(),
+            self.deps.channel_repository(),
+            ctx.clone(),
+            &input.account_id,
+            &input.channel_id,
+            &input.message_id,
+        )
+        .await?;
+
+        let tx = ctx.begin().await?;
+        repo.delete(
+            tx.clone(),
+            message.id.clone(),
+            input.account_id.clone(),
+            current_time(),
+        )
+        .await?;
+        let thread = match message.parent_id {
+            Some(parent_id) => {
+                repo.increment_reply_count(tx.clone(), parent_id.clone(), -1)
+                    .await?;
+                repo.get(tx.clone(), parent_id.clone())
+                    .await?
+                    .map(|parent| ThreadMarker::new(parent_id, parent.reply_count))
+            }
+            None => None,
+        };
+        tx.commit().await?;
+
+        internal::channel::broadcast(
+            self.deps.pubsub_gateway(),
+            ctx,
+            &message.channel_id,
+            &ChannelEvent::MessageDeleted {
+                message_id: message.id,
+                deleted_by: input.account_id,
+                thread,
+            },
+        )
+        .await?;
+        Ok(DeleteMessageOutput)
+    }
+}
+
+#[derive(Clone, new)]
+pub struct ListMessageEditsUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait ListMessageEditsUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseMessageRepository<C>
+    + UseChannelRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<ListMessageEditsInput, ListMessageEditsOutput>
+    for ListMessageEditsUseCase<C, Deps>
+where
+    C: Component,
+    Deps: ListMessageEditsUseCaseDeps<C>,
+{
+    async fn handle(&self, input: ListMessageEditsInput) -> Result<ListMessageEditsOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.message_repository();
+        let message = get_editable_message(
+            repo.clone(),
+            self.deps.channel_repository(),
+            ctx.clone(),
+            &input.account_id,
+            &input.channel_id,
+            &input.message_id,
+        )
+        .await?;
+        let edits = repo.find_edits(ctx, message.id).await?;
+        Ok(ListMessageEditsOutput::new(edits))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct GetThreadUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait GetThreadUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseMessageRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<GetThreadInput, GetThreadOutput> for GetThreadUseCase<C, Deps>
+where
+    C: Component,
+    Deps: GetThreadUseCaseDeps<C>,
+{
+    async fn handle(&self, input: GetThreadInput) -> Result<GetThreadOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.message_repository();
+        let parent = internal::channel::get_thread_root(
+            repo.clone(),
+            ctx.clone(),
+            &input.channel_id,
+            &input.message_id,
+        )
+        .await?;
+        let replies = repo.find_replies(ctx, parent.id.clone()).await?;
+        Ok(GetThreadOutput::new(parent, replies))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct AddReactionUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait AddReactionUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseMessageRepository<C>
+    + UsePubSubGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<AddReactionInput, AddReactionOutput> for AddReactionUseCase<C, Deps>
+where
+    C: Component,
+    Deps: AddReactionUseCaseDeps<C>,
+{
+    async fn handle(&self, input: AddReactionInput) -> Result<AddReactionOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.message_repository();
+        let message = internal::channel::get_message(
+            repo.clone(),
+            ctx.clone(),
+            &input.channel_id,
+            &input.message_id,
+        )
+        .await?;
+        let reaction = Reaction::new(
+            message.id.clone(),
+            input.account_id,
+            input.emoji,
+            current_time(),
+        )
+        .validate()?;
+        repo.add_reaction(ctx.clone(), reaction).await?;
+        let reactions = broadcast_reactions(&self.deps, ctx, &message).await?;
+        Ok(AddReactionOutput::new(reactions))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct RemoveReactionUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait RemoveReactionUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseMessageRepository<C>
+    + UsePubSubGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<RemoveReactionInput, RemoveReactionOutput> for RemoveReactionUseCase<C, Deps>
+where
+    C: Component,
+    Deps: RemoveReactionUseCaseDeps<C>,
+{
+    async fn handle(&self, input: RemoveReactionInput) -> Result<RemoveReactionOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.message_repository();
+        let message = internal::channel::get_message(
+            repo.clone(),
+            ctx.clone(),
+            &input.channel_id,
+            &input.message_id,
+        )
+        .await?;
+        repo.remove_reaction(
+            ctx.clone(),
+            message.id.clone(),
+            input.account_id,
+            input.emoji,
+        )
+        .await?;
+        let reactions = broadcast_reactions(&self.deps, ctx, &message).await?;
+        Ok(RemoveReactionOutput::new(reactions))
+    }
+}
+
+async fn broadcast_reactions<C, Deps>(
+    deps: &Deps,
+    ctx: C,
+    message: &Message,
+) -> Result<Vec<ReactionCount>>
+where
+    C: Component,
+    Deps: UseMessageRepository<C> + UsePubSubGateway<C>,
+{
+    let reactions = deps
+        .message_repository()
+        .count_reactions(ctx.clone(), message.id.clone())
+        .await?;
+    internal::channel::broadcast(
+        deps.pubsub_gateway(),
+        ctx,
+        &message.channel_id,
+        &ChannelEvent::ReactionsUpdated {
+            message_id: message.id.clone(),
+            reactions: reactions.clone(),
+        },
+    )
+    .await?;
+    Ok(reactions)
+}
+
+/// the author and the channel moderators can edit or delete a message.
+async fn get_editable_message<C: Component>(
+    repo: impl MessageRepository<C>,
+    channel_repo: impl ChannelRepository<C>,
+    ctx: C,
+    account_id: &str,
+    channel_id: &str,
+    message_id: &str,
+) -> Result<Message> {
+    let message = internal::channel::get_message(repo, ctx.clone(), channel_id, message_id).await?;
+    if message.account_id != account_id
+        && !channel_repo
+            .is_moderator(ctx, channel_id.to_string(), account_id.to_string())
+            .await?
+    {
+        bail!(Error::forbidden(
+            Codes::MessageNotEditable,
+            "not the author or a moderator."
+        ))
+    }
+    Ok(message)
+}
+
+#[derive(new)]
+pub struct EditMessageInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) message_id: String,
+    pub(crate) body: Vec<u8>,
+}
+
+#[derive(new)]
+pub struct EditMessageOutput {
+    pub message: Message,
+}
+
+#[derive(new)]
+pub struct DeleteMessageInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) message_id: String,
+}
+
+#[derive(new)]
+pub struct DeleteMessageOutput;
+
+#[derive(new)]
+pub struct ListMessageEditsInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) message_id: String,
+}
+
+#[derive(new)]
+pub struct ListMessageEditsOutput {
+    pub edits: Vec<MessageEdit>,
+}
+
+#[derive(new)]
+pub struct GetThreadInput {
+    pub(crate) channel_id: String,
+    pub(crate) message_id: String,
+}
+
+#[derive(new)]
+pub struct GetThreadOutput {
+    pub parent: Message,
+    pub replies: Vec<Message>,
+}
+
+#[derive(new)]
+pub struct AddReactionInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) message_id: String,
+    pub(crate) emoji: String,
+}
+
+#[derive(new)]
+pub struct AddReactionOutput {
+    pub reactions: Vec<ReactionCount>,
+}
+
+#[derive(new)]
+pub struct RemoveReactionInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) message_id: String,
+    pub(crate) emoji: String,
+}
+
+#[derive(new)]
+pub struct RemoveReactionOutput {
+    pub reactions: Vec<ReactionCount>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::config::{Auth, Config, ConfigBuilder, System};
+    use crate::interface::gateway::pubsub::{MockPubSubGateway, UsePubSubGateway};
+    use crate::interface::repository::channel::{MockChannelRepository, UseChannelRepository};
+    use crate::interface::repository::message::{MockMessageRepository, UseMessageRepository};
+    use crate::interface::{UseConfig, UseContext};
+    use crate::usecase::message::{
+        DeleteMessageInput, DeleteMessageUseCase, EditMessageInput, EditMessageUseCase,
+    };
+    use crate::usecase::UseCase;
+    use async_trait::async_trait;
+    use helper::time::current_time;
+    use kernel::entity::{ChannelEvent, Message};
+    use kernel::error::Error;
+    use kernel::Result;
+    use mockall::predicate;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct TestMods {
+        mock_message_repo: Arc<MockMessageRepository>,
+        mock_channel_repo: Arc<MockChannelRepository>,
+        mock_pubsub_gateway: Arc<MockPubSubGateway>,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseMessageRepository<()> for TestMods {
+        type MessageRepository = Arc<MockMessageRepository>;
+
+        fn message_repository(&self) -> Self::MessageRepository {
+            self.mock_message_repo.clone()
+        }
+    }
+    impl UseChannelRepository<()> for TestMods {
+        type ChannelRepository = Arc<MockChannelRepository>;
+
+        fn channel_repository(&self) -> Self::ChannelRepository {
+            self.mock_channel_repo.clone()
+        }
+    }
+    impl UsePubSubGateway<()> for TestMods {
+        type Gateway = Arc<MockPubSubGateway>;
+
+        fn pubsub_gateway(&self) -> Self::Gateway {
+            self.mock_pubsub_gateway.clone()
+        }
+    }
+
+    fn message() -> Message {
+        let now = current_time();
+        Message::new(
+            "message_id".to_string(),
+            "channel_id".to_string(),
+            "author_id".to_string(),
+            "before".to_string(),
+            None,
+            0,
+            now,
+            now,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn edit_message_by_author() {
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_get()
+            .with(predicate::eq(()), predicate::eq("message_id".to_string()))
+            .returning(|_, _| Ok(Some(message())));
+        mock_message_repo
+            .expect_create_edit()
+            .withf(|_, edit| edit.body == "before" && edit.edited_by == "author_id")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_message_repo
+            .expect_update()
+            .withf(|_, message| message.body == "after")
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut mock_channel_repo = MockChannelRepository::default();
+        mock_channel_repo.expect_is_moderator().never();
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_publish()
+            .withf(|_, channel, payload| {
+                channel == "channel:channel_id"
+                    && matches!(
+                        serde_json::from_slice::<ChannelEvent>(payload).unwrap(),
+                        ChannelEvent::MessageEdited { message } if message.body == "after"
+                    )
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let usecase = EditMessageUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(mock_channel_repo),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+        });
+        let input = EditMessageInput::new(
+            "author_id".to_string(),
+            "channel_id".to_string(),
+            "message_id".to_string(),
+            b"after".to_vec(),
+        );
+        let output = usecase.handle(input).await.unwrap();
+        assert_eq!(output.message.body, "after");
+    }
+
+    #[tokio::test]
+    async fn delete_message_requires_author_or_moderator() {
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_get()
+            .returning(|_, _| Ok(Some(message())));
+        mock_message_repo
+            .expect_delete()
+            .with(
+                predicate::eq(()),
+                predicate::eq("message_id".to_string()),
+                predicate::eq("moderator_id".to_string()),
+                predicate::always(),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        let mut mock_channel_repo = MockChannelRepository::default();
+        mock_channel_repo
+            .expect_is_moderator()
+            .returning(|_, _, account_id| Ok(account_id == "moderator_id"));
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_publish()
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let usecase = DeleteMessageUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(mock_channel_repo),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+        });
+
+        let input = DeleteMessageInput::new(
+            "other_id".to_string(),
+            "channel_id".to_string(),
+            "message_id".to_string(),
+        );
+        let err = usecase.handle(input).await.err().unwrap();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::Forbidden(..))
+        ));
+
+        let input = DeleteMessageInput::new(
+            "moderator_id".to_string(),
+            "channel_id".to_string(),
+            "message_id".to_string(),
+        );
+        assert!(usecase.handle(input).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete_reply_updates_thread() {
+        let mut mock_message_repo = MockMessageRepository::default();
+        mock_message_repo
+            .expect_get()
+            .with(predicate::eq(()), predicate::eq("message_id".to_string()))
+            .returning(|_, _| {
+                let mut reply = message();
+                reply.parent_id = Some("parent_id".to_string());
+                Ok(Some(reply))
+            });
+        mock_message_repo
+            .expect_delete()
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+        mock_message_repo
+            .expect_increment_reply_count()
+            .with(
+                predicate::eq(()),
+                predicate::eq("parent_id".to_string()),
+                predicate::eq(-1),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        mock_message_repo
+            .expect_get()
+            .with(predicate::eq(()), predicate::eq("parent_id".to_string()))
+            .returning(|_, _| {
+                let mut parent = message();
+                parent.id = "parent_id".to_string();
+                parent.reply_count = 1;
+                Ok(Some(parent))
+            });
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_pubsub_gateway
+            .expect_publish()
+            .withf(|_, _, payload| {
+                matches!(
+                    serde_json::from_slice::<ChannelEvent>(payload).unwrap(),
+                    ChannelEvent::MessageDeleted { thread: Some(thread), .. }
+                        if thread.parent_id == "parent_id" && thread.reply_count == 1
+                )
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let usecase = DeleteMessageUseCase::new(TestMods {
+            mock_message_repo: Arc::new(mock_message_repo),
+            mock_channel_repo: Arc::new(MockChannelRepository::default()),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+        });
+        let input = DeleteMessageInput::new(
+            "author_id".to_string(),
+            "channel_id".to_string(),
+            "message_id".to_string(),
+        );
+        assert!(usecase.handle(input).await.is_ok());
+    }
+}
@@ -4,10 +4,15 @@ use blanket::blanket;
 use kernel::Result;
 
 pub mod account;
+pub mod analytics;
 pub mod auth;
 pub mod channel;
+pub mod idempotency;
+pub mod message;
+pub mod rate_limit;
 pub mod session;
 pub mod status;
+pub mod webhook;
 
 #[async_trait]
 #[blanket(derive(Arc))]
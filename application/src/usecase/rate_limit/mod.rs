@@ -0,0 +1,200 @@
+use crate::interface::gateway::rate_limit::{Bucket, RateLimitGateway, UseRateLimitGateway};
+use crate::interface::{Component, UseConfig, UseContext};
+use crate::usecase::UseCase;
+use async_trait::async_trait;
+use kernel::entity::RateLimitKey;
+use kernel::Result;
+use std::marker::PhantomData;
+use trait_set::trait_set;
+
+#[derive(Clone, new)]
+pub struct RateLimitUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait RateLimitUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseRateLimitGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<RateLimitInput, RateLimitOutput> for RateLimitUseCase<C, Deps>
+where
+    C: Component,
+    Deps: RateLimitUseCaseDeps<C>,
+{
+    async fn handle(&self, input: RateLimitInput) -> Result<RateLimitOutput> {
+        let cfg = self.deps.config();
+        if cfg.rate_limit.exempt_paths.contains(&input.path) {
+            return Ok(RateLimitOutput::new(None));
+        }
+        let Some(rule) = cfg
+            .rate_limit
+            .rules
+            .iter()
+            .find(|rule| rule.matches(&input.method, &input.path))
+        else {
+            return Ok(RateLimitOutput::new(None));
+        };
+        let (kind, id) = match rule.key {
+            RateLimitKey::Session if input.session_id.is_some() => {
+                (RateLimitKey::Session, input.session_id)
+            }
+            RateLimitKey::Session | RateLimitKey::Tracking if input.tracking_id.is_some() => {
+                (RateLimitKey::Tracking, input.tracking_id)
+            }
+            _ => (RateLimitKey::Ip, input.ip),
+        };
+        let Some(id) = id else {
+            return Ok(RateLimitOutput::new(None));
+        };
+        let bucket = self
+            .deps
+            .rate_limit_gateway()
+            .acquire(
+                self.deps.context().await?,
+                format!("{}:{}:{}", rule.path, kind, id),
+                rule.capacity,
+                rule.refill_per_second,
+            )
+            .await?;
+        Ok(RateLimitOutput::new(Some(RateLimitDecision::new(
+            rule.capacity,
+            bucket,
+        ))))
+    }
+}
+
+#[derive(new)]
+pub struct RateLimitInput {
+    method: String,
+    path: String,
+    ip: Option<String>,
+    tracking_id: Option<String>,
+    /// only a session known to exist, so that a made up one does not get a fresh bucket.
+    session_id: Option<String>,
+}
+
+/// `None` when no rule applies to the request.
+#[derive(new)]
+pub struct RateLimitOutput {
+    pub decision: Option<RateLimitDecision>,
+}
+
+#[derive(new)]
+pub struct RateLimitDecision {
+    pub limit: u32,
+    pub bucket: Bucket,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::config::{Auth, Config, ConfigBuilder, RateLimitBuilder, System};
+    use crate::interface::gateway::rate_limit::{
+        Bucket, MockRateLimitGateway, UseRateLimitGateway,
+    };
+    use crate::interface::{UseConfig, UseContext};
+    use crate::usecase::rate_limit::{RateLimitInput, RateLimitUseCase};
+    use crate::usecase::UseCase;
+    use async_trait::async_trait;
+    use kernel::entity::{RateLimitKey, RateLimitRule};
+    use kernel::Result;
+    use mockall::predicate;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[derive(Clone)]
+    struct TestMods {
+        mock_rate_limit_gateway: Arc<MockRateLimitGateway>,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .rate_limit(
+                    RateLimitBuilder::default()
+                        .rules(vec![RateLimitRule {
+                            path: "/api/v1/channel/:channel_id/*".to_string(),
+                            methods: vec!["POST".to_string()],
+                            key: RateLimitKey::Session,
+                            capacity: 10,
+                            refill_per_second: 1.0,
+                        }])
+                        .exempt_paths(vec!["/api/v1/channel/exempt/messages".to_string()])
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseRateLimitGateway<()> for TestMods {
+        type Gateway = Arc<MockRateLimitGateway>;
+
+        fn rate_limit_gateway(&self) -> Self::Gateway {
+            self.mock_rate_limit_gateway.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn session_bucket_falls_back_to_tracking() {
+        let mut mock_rate_limit_gateway = MockRateLimitGateway::default();
+        mock_rate_limit_gateway
+            .expect_acquire()
+            .with(
+                predicate::always(),
+                predicate::eq("/api/v1/channel/:channel_id/*:tracking:tid".to_string()),
+                predicate::eq(10),
+                predicate::eq(1.0),
+            )
+            .times(1)
+            .returning(|_, _, _, _| {
+                Ok(Bucket::new(
+                    false,
+                    0,
+                    Duration::from_secs(1),
+                    Duration::from_secs(10),
+                ))
+            });
+        let usecase = RateLimitUseCase::new(TestMods {
+            mock_rate_limit_gateway: Arc::new(mock_rate_limit_gateway),
+        });
+        let input = |method: &str, path: &str| {
+            RateLimitInput::new(
+                method.to_string(),
+                path.to_string(),
+                Some("127.0.0.1".to_string()),
+                Some("tid".to_string()),
+                None,
+            )
+        };
+        let output = usecase
+            .handle(input("GET", "/api/v1/channel/c1/messages"))
+            .await
+            .unwrap();
+        assert!(output.decision.is_none());
+        let output = usecase
+            .handle(input("POST", "/api/v1/channel/exempt/messages"))
+            .await
+            .unwrap();
+        assert!(output.decision.is_none());
+        let output = usecase
+            .handle(input("POST", "/api/v1/channel/c1/messages"))
+            .await
+            .unwrap();
+        let decision = output.decision.unwrap();
+        assert_eq!(decision.limit, 10);
+        assert!(!decision.bucket.allowed);
+    }
+}
@@ -1,11 +1,13 @@
+use crate::interface::gateway::health::{Dependency, HealthGateway, UseHealthGateway};
 use crate::interface::Component;
-use crate::interface::UseContext;
+use crate::interface::{UseConfig, UseContext};
 
 use kernel::Result;
 
 use crate::usecase::UseCase;
 use async_trait::async_trait;
 use std::marker::PhantomData;
+use std::time::{Duration, Instant};
 use trait_set::trait_set;
 
 #[derive(Clone, new)]
@@ -39,3 +41,185 @@ pub struct StatusOutput {
     pub version: Option<String>,
     pub build_timestamp: String,
 }
+
+#[derive(Clone, new)]
+pub struct LivenessUseCase<C, Deps> {
+    _deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait LivenessUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<LivenessInput, LivenessOutput> for LivenessUseCase<C, Deps>
+where
+    C: Component,
+    Deps: LivenessUseCaseDeps<C>,
+{
+    /// the process answers, nothing else is checked so that a dependency outage does not
+    /// restart every node.
+    async fn handle(&self, _: LivenessInput) -> Result<LivenessOutput> {
+        Ok(LivenessOutput)
+    }
+}
+
+pub struct LivenessInput;
+
+pub struct LivenessOutput;
+
+#[derive(Clone, new)]
+pub struct ReadinessUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait ReadinessUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseHealthGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<ReadinessInput, ReadinessOutput> for ReadinessUseCase<C, Deps>
+where
+    C: Component,
+    Deps: ReadinessUseCaseDeps<C>,
+{
+    async fn handle(&self, _: ReadinessInput) -> Result<ReadinessOutput> {
+        let timeout = self.deps.config().health.timeout;
+        let ctx = self.deps.context().await?;
+        let (database, redis_primary, redis_reader) = tokio::join!(
+            self.check(ctx.clone(), Dependency::Database, timeout),
+            self.check(ctx.clone(), Dependency::RedisPrimary, timeout),
+            self.check(ctx, Dependency::RedisReader, timeout),
+        );
+        Ok(ReadinessOutput::new(vec![
+            database,
+            redis_primary,
+            redis_reader,
+        ]))
+    }
+}
+
+impl<C, Deps> ReadinessUseCase<C, Deps>
+where
+    C: Component,
+    Deps: ReadinessUseCaseDeps<C>,
+{
+    async fn check(&self, ctx: C, dependency: Dependency, timeout: Duration) -> DependencyCheck {
+        let start = Instant::now();
+        let gateway = self.deps.health_gateway();
+        let name = gateway.name(&ctx, dependency);
+        let ping = gateway.ping(ctx, dependency);
+        let error = match tokio::time::timeout(timeout, ping).await {
+            Ok(Ok(())) => None,
+            Ok(Err(e)) => Some(e.to_string()),
+            Err(_) => Some(format!("timed out after {timeout:?}")),
+        };
+        DependencyCheck::new(dependency, name, start.elapsed(), error)
+    }
+}
+
+pub struct ReadinessInput;
+
+#[derive(new)]
+pub struct ReadinessOutput {
+    pub checks: Vec<DependencyCheck>,
+}
+
+impl ReadinessOutput {
+    #[must_use]
+    pub fn ready(&self) -> bool {
+        self.checks.iter().all(|check| check.error.is_none())
+    }
+}
+
+#[derive(new)]
+pub struct DependencyCheck {
+    pub dependency: Dependency,
+    pub name: String,
+    pub latency: Duration,
+    /// `None` when the dependency answered in time.
+    pub error: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::config::{Auth, Config, ConfigBuilder, System};
+    use crate::interface::gateway::health::{Dependency, MockHealthGateway, UseHealthGateway};
+    use crate::interface::{UseConfig, UseContext};
+    use crate::usecase::status::{ReadinessInput, ReadinessUseCase};
+    use crate::usecase::UseCase;
+    use async_trait::async_trait;
+    use kernel::{unexpected, Result};
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct TestMods {
+        mock_health_gateway: Arc<MockHealthGateway>,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseHealthGateway<()> for TestMods {
+        type Gateway = Arc<MockHealthGateway>;
+
+        fn health_gateway(&self) -> Self::Gateway {
+            self.mock_health_gateway.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn readiness_reports_each_dependency() {
+        let mut mock_health_gateway = MockHealthGateway::default();
+        mock_health_gateway
+            .expect_ping()
+            .times(3)
+            .returning(|_, dependency| match dependency {
+                Dependency::RedisReader => Err(unexpected!("connection refused").into()),
+                _ => Ok(()),
+            });
+        mock_health_gateway
+            .expect_name()
+            .times(3)
+            .returning(|_, dependency| match dependency {
+                Dependency::Database => "sqlite".to_string(),
+                dependency => dependency.to_string(),
+            });
+        let usecase = ReadinessUseCase::new(TestMods {
+            mock_health_gateway: Arc::new(mock_health_gateway),
+        });
+        let output = usecase.handle(ReadinessInput).await.unwrap();
+        assert!(!output.ready());
+        let failed: Vec<_> = output
+            .checks
+            .iter()
+            .filter(|check| check.error.is_some())
+            .map(|check| check.dependency)
+            .collect();
+        assert_eq!(failed, vec![Dependency::RedisReader]);
+        let names: Vec<_> = output
+            .checks
+            .iter()
+            .map(|check| check.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["sqlite", "redis_primary", "redis_reader"]);
+    }
+}
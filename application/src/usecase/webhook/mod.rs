@@ -0,0 +1,992 @@
+use crate::interface::config::Config;
+use crate::interface::gateway::pubsub::{PubSubGateway, SubscriptionEvent, UsePubSubGateway};
+use crate::interface::gateway::webhook::UseWebhookGateway;
+use crate::interface::repository::webhook::{UseWebhookRepository, WebhookRepository};
+use crate::interface::{Component, UseConfig, UseContext};
+use crate::internal;
+use crate::usecase::UseCase;
+use anyhow::{bail, Result};
+use async_trait::async_trait;
+use helper::time::current_time;
+use helper::uuid;
+use helper::uuid::ToBase62;
+use helper::validation::Validation;
+use kernel::entity::{Webhook, WebhookDelivery};
+use kernel::error::{Codes, Error};
+use log;
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc::{Receiver, Sender};
+use tokio::sync::Semaphore;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::StreamExt;
+use trait_set::trait_set;
+
+#[derive(Clone, new)]
+pub struct CreateWebhookUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait CreateWebhookUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseWebhookRepository<C>
+    + UseWebhookGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<CreateWebhookInput, CreateWebhookOutput> for CreateWebhookUseCase<C, Deps>
+where
+    C: Component,
+    Deps: CreateWebhookUseCaseDeps<C>,
+{
+    async fn handle(&self, input: CreateWebhookInput) -> Result<CreateWebhookOutput> {
+        let ctx = self.deps.context().await?;
+        internal::webhook::resolve_url(&self.deps.webhook_gateway(), ctx.clone(), &input.url)
+            .await?;
+        let webhook = Webhook::new(
+            uuid::new_v4().to_base62(),
+            input.channel_id,
+            input.account_id,
+            input.url,
+            uuid::new_v4().to_base62(),
+            current_time(),
+        )
+        .validate()?;
+        let webhook = self.deps.webhook_repository().create(ctx, webhook).await?;
+        Ok(CreateWebhookOutput::new(webhook))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct ListWebhooksUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait ListWebhooksUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseWebhookRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<ListWebhooksInput, ListWebhooksOutput> for ListWebhooksUseCase<C, Deps>
+where
+    C: Component,
+    Deps: ListWebhooksUseCaseDeps<C>,
+{
+    async fn handle(&self, input: ListWebhooksInput) -> Result<ListWebhooksOutput> {
+        let webhooks = self
+            .deps
+            .webhook_repository()
+            .find_by_channel(self.deps.context().await?, input.channel_id)
+            .await?
+            .into_iter()
+            .filter(|webhook| webhook.account_id == input.account_id)
+            .collect();
+        Ok(ListWebhooksOutput::new(webhooks))
+    }
+}
+
+#[derive(Clone, new)]
+pub struct DeleteWebhookUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait DeleteWebhookUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseWebhookRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<DeleteWebhookInput, DeleteWebhookOutput> for DeleteWebhookUseCase<C, Deps>
+where
+    C: Component,
+    Deps: DeleteWebhookUseCaseDeps<C>,
+{
+    async fn handle(&self, input: DeleteWebhookInput) -> Result<DeleteWebhookOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.webhook_repository();
+        let webhook = get_own_webhook(
+            repo.clone(),
+            ctx.clone(),
+            input.account_id,
+            input.channel_id,
+            input.webhook_id,
+        )
+        .await?;
+        repo.delete(ctx, webhook.id).await?;
+        Ok(DeleteWebhookOutput)
+    }
+}
+
+#[derive(Clone, new)]
+pub struct ListWebhookDeliveriesUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait ListWebhookDeliveriesUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseWebhookRepository<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<ListWebhookDeliveriesInput, ListWebhookDeliveriesOutput>
+    for ListWebhookDeliveriesUseCase<C, Deps>
+where
+    C: Component,
+    Deps: ListWebhookDeliveriesUseCaseDeps<C>,
+{
+    async fn handle(
+        &self,
+        input: ListWebhookDeliveriesInput,
+    ) -> Result<ListWebhookDeliveriesOutput> {
+        let ctx = self.deps.context().await?;
+        let repo = self.deps.webhook_repository();
+        let webhook = get_own_webhook(
+            repo.clone(),
+            ctx.clone(),
+            input.account_id,
+            input.channel_id,
+            input.webhook_id,
+        )
+        .await?;
+        let deliveries = repo.find_deliveries(ctx, webhook.id).await?;
+        Ok(ListWebhookDeliveriesOutput::new(deliveries))
+    }
+}
+
+async fn get_own_webhook<C>(
+    repo: impl WebhookRepository<C>,
+    ctx: C,
+    account_id: String,
+    channel_id: String,
+    webhook_id: String,
+) -> Result<Webhook> {
+    let Some(webhook) = repo.get(ctx, webhook_id).await? else {
+        bail!(Error::not_found(
+            Codes::WebhookNotFound,
+            "webhook not found."
+        ))
+    };
+    if webhook.channel_id != channel_id {
+        bail!(Error::not_found(
+            Codes::WebhookNotFound,
+            "webhook not found."
+        ))
+    }
+    if webhook.account_id != account_id {
+        bail!(Error::forbidden(
+            Codes::NotWebhookOwner,
+            "not an owner of the webhook."
+        ))
+    }
+    Ok(webhook)
+}
+
+#[derive(Clone, new)]
+pub struct WebhookWorkerUseCase<C, Deps> {
+    deps: Deps,
+    _c: PhantomData<C>,
+}
+
+trait_set! {
+    pub trait WebhookWorkerUseCaseDeps<C: Component> = Component + UseContext<Context = C>
+    + UseConfig
+    + UseWebhookRepository<C>
+    + UseWebhookGateway<C>
+    + UsePubSubGateway<C>
+    ;
+}
+
+#[async_trait]
+impl<C, Deps> UseCase<WebhookWorkerInput, WebhookWorkerOutput> for WebhookWorkerUseCase<C, Deps>
+where
+    C: Component,
+    Deps: WebhookWorkerUseCaseDeps<C>,
+{
+    async fn handle(&self, input: WebhookWorkerInput) -> Result<WebhookWorkerOutput> {
+        let (sender, rx) = tokio::sync::mpsc::channel(1000);
+        let deps = self.deps.clone();
+        let queues = WebhookQueues::new(&deps.config(), sender);
+        tokio::spawn(async move {
+            let subscribed = Arc::new(Mutex::new(HashSet::<String>::new()));
+            let mut interval = tokio::time::interval(input.rescan_interval);
+            while !queues.sender.is_closed() {
+                interval.tick().await;
+                if let Err(e) = subscribe_channels(&deps, &subscribed, &queues).await {
+                    log::warn!("webhook channel scan error: {:?}", e);
+                }
+            }
+        });
+        Ok(WebhookWorkerOutput::new(rx))
+    }
+}
+
+/// subscribes every channel that has a webhook and is not yet being delivered.
+async fn subscribe_channels<C: Component, Deps: WebhookWorkerUseCaseDeps<C>>(
+    deps: &Deps,
+    subscribed: &Arc<Mutex<HashSet<String>>>,
+    queues: &WebhookQueues,
+) -> Result<()> {
+    let ctx = deps.context().await?;
+    let channel_ids = deps
+        .webhook_repository()
+        .find_channel_ids(ctx.clone())
+        .await?;
+    for channel_id in channel_ids {
+        if !subscribed.lock().unwrap().insert(channel_id.clone()) {
+            continue;
+        }
+        let receiver = match deps
+            .pubsub_gateway()
+            .subscribe(ctx.clone(), internal::channel::topic(&channel_id))
+            .await
+        {
+            Ok(receiver) => receiver,
+            Err(e) => {
+                subscribed.lock().unwrap().remove(&channel_id);
+                return Err(e);
+            }
+        };
+        let deps = deps.clone();
+        let subscribed = subscribed.clone();
+        let queues = queues.clone();
+        tokio::spawn(async move {
+            let mut stream = ReceiverStream::new(receiver);
+            while let Some(event) = stream.next().await {
+                let message = match event {
+                    SubscriptionEvent::Message(message) => message,
+                    SubscriptionEvent::Reconnected => {
+                        log::warn!("resubscribed {}, messages may have been missed", channel_id);
+                        continue;
+                    }
+                };
+                if let Err(e) = deliver_message(&deps, &queues, &channel_id, message).await {
+                    log::warn!("webhook delivery error: {:?}", e);
+                }
+            }
+            // resubscribed at the next scan.
+            subscribed.lock().unwrap().remove(&channel_id);
+        });
+    }
+    Ok(())
+}
+
+async fn deliver_message<C: Component, Deps: WebhookWorkerUseCaseDeps<C>>(
+    deps: &Deps,
+    queues: &WebhookQueues,
+    channel_id: &str,
+    message: Vec<u8>,
+) -> Result<()> {
+    let ctx = deps.context().await?;
+    let webhooks = deps
+        .webhook_repository()
+        .find_by_channel(ctx, channel_id.to_string())
+        .await?;
+    for queue in queues.assign(deps, channel_id, webhooks) {
+        // waits while the webhook is behind, so a slow receiver holds the channel back rather
+        // than losing its messages.
+        if queue.send(message.clone()).await.is_err() {
+            log::warn!("webhook queue of {} is closed", channel_id);
+        }
+    }
+    Ok(())
+}
+
+/// a queue per webhook, drained in order by a worker of its own. the workers share the
+/// permits, which bound the deliveries in progress at once.
+#[derive(Clone)]
+struct WebhookQueues {
+    queues: Arc<Mutex<HashMap<String, WebhookQueue>>>,
+    permits: Arc<Semaphore>,
+    queue_size: usize,
+    sender: Sender<WebhookDelivery>,
+}
+
+struct WebhookQueue {
+    channel_id: String,
+    tx: Sender<Vec<u8>>,
+}
+
+impl WebhookQueues {
+    fn new(cfg: &Config, sender: Sender<WebhookDelivery>) -> Self {
+        Self {
+            queues: Arc::new(Mutex::new(HashMap::new())),
+            permits: Arc::new(Semaphore::new(cfg.webhook.max_concurrency.max(1))),
+            queue_size: cfg.webhook.queue_size.max(1),
+            sender,
+        }
+    }
+
+    /// the queues of the webhooks of the channel, starting the workers of new ones.
+    fn assign<C: Component, Deps: WebhookWorkerUseCaseDeps<C>>(
+        &self,
+        deps: &Deps,
+        channel_id: &str,
+        webhooks: Vec<Webhook>,
+    ) -> Vec<Sender<Vec<u8>>> {
+        let mut queues = self.queues.lock().unwrap();
+        // the worker of a deleted webhook ends once its queue is drained.
+        queues.retain(|id, queue| {
+            queue.channel_id != channel_id || webhooks.iter().any(|webhook| &webhook.id == id)
+        });
+        webhooks
+            .into_iter()
+            .map(|webhook| {
+                queues
+                    .entry(webhook.id.clone())
+                    .or_insert_with(|| self.spawn_worker(deps.clone(), webhook))
+                    .tx
+                    .clone()
+            })
+            .collect()
+    }
+
+    fn spawn_worker<C: Component, Deps: WebhookWorkerUseCaseDeps<C>>(
+        &self,
+        deps: Deps,
+        webhook: Webhook,
+    ) -> WebhookQueue {
+        let (tx, mut rx) = tokio::sync::mpsc::channel::<Vec<u8>>(self.queue_size);
+        let channel_id = webhook.channel_id.clone();
+        let permits = self.permits.clone();
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            while let Some(message) = rx.recv().await {
+                // held through the retries, which are part of the delivery.
+                let _permit = match permits.acquire().await {
+                    Ok(permit) => permit,
+                    Err(_) => break,
+                };
+                let delivery = match deps.context().await {
+                    Ok(ctx) => {
+                        internal::webhook::deliver(
+                            &deps.config(),
+                            deps.webhook_gateway(),
+                            deps.webhook_repository(),
+                            ctx,
+                            webhook.clone(),
+                            message,
+                        )
+                        .await
+                    }
+                    Err(e) => Err(e),
+                };
+                match delivery {
+                    Ok(delivery) => {
+                        let _ = sender.send(delivery).await;
+                    }
+                    Err(e) => log::warn!("webhook delivery error: {:?}", e),
+                }
+            }
+        });
+        WebhookQueue { channel_id, tx }
+    }
+}
+
+#[derive(new)]
+pub struct CreateWebhookInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) url: String,
+}
+
+#[derive(new)]
+pub struct CreateWebhookOutput {
+    pub webhook: Webhook,
+}
+
+#[derive(new)]
+pub struct ListWebhooksInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+}
+
+#[derive(new)]
+pub struct ListWebhooksOutput {
+    pub webhooks: Vec<Webhook>,
+}
+
+#[derive(new)]
+pub struct DeleteWebhookInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) webhook_id: String,
+}
+
+#[derive(new, Debug, PartialEq)]
+pub struct DeleteWebhookOutput;
+
+#[derive(new)]
+pub struct ListWebhookDeliveriesInput {
+    pub(crate) account_id: String,
+    pub(crate) channel_id: String,
+    pub(crate) webhook_id: String,
+}
+
+#[derive(new)]
+pub struct ListWebhookDeliveriesOutput {
+    pub deliveries: Vec<WebhookDelivery>,
+}
+
+#[derive(new)]
+pub struct WebhookWorkerInput {
+    pub(crate) rescan_interval: Duration,
+}
+
+#[derive(new)]
+pub struct WebhookWorkerOutput {
+    pub rx: Receiver<WebhookDelivery>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interface::config::{Auth, Config, ConfigBuilder, System, WebhookBuilder};
+    use crate::interface::gateway::pubsub::{
+        MockPubSubGateway, SubscriptionEvent, UsePubSubGateway,
+    };
+    use crate::interface::gateway::webhook::{
+        MockWebhookGateway, UseWebhookGateway, WebhookGateway, WebhookRequest, WebhookResponse,
+    };
+    use crate::interface::repository::webhook::{MockWebhookRepository, UseWebhookRepository};
+    use crate::interface::{UseConfig, UseContext};
+    use crate::internal;
+    use crate::usecase::webhook::{
+        CreateWebhookInput, CreateWebhookUseCase, DeleteWebhookInput, DeleteWebhookUseCase,
+        WebhookWorkerInput, WebhookWorkerUseCase,
+    };
+    use crate::usecase::UseCase;
+    use async_trait::async_trait;
+    use helper::time::current_time;
+    use kernel::entity::{Webhook, WebhookDeliveryStatus};
+    use kernel::error::{Codes, Error};
+    use kernel::{unexpected, Result};
+    use mockall::predicate;
+    use std::net::SocketAddr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Clone)]
+    struct TestMods {
+        mock_webhook_repo: Arc<MockWebhookRepository>,
+        mock_webhook_gateway: Arc<MockWebhookGateway>,
+        mock_pubsub_gateway: Arc<MockPubSubGateway>,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .webhook(
+                    WebhookBuilder::default()
+                        .max_attempts(3)
+                        .initial_backoff(Duration::ZERO)
+                        .max_backoff(Duration::ZERO)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseWebhookRepository<()> for TestMods {
+        type WebhookRepository = Arc<MockWebhookRepository>;
+
+        fn webhook_repository(&self) -> Self::WebhookRepository {
+            self.mock_webhook_repo.clone()
+        }
+    }
+    impl UseWebhookGateway<()> for TestMods {
+        type Gateway = Arc<MockWebhookGateway>;
+
+        fn webhook_gateway(&self) -> Self::Gateway {
+            self.mock_webhook_gateway.clone()
+        }
+    }
+    impl UsePubSubGateway<()> for TestMods {
+        type Gateway = Arc<MockPubSubGateway>;
+
+        fn pubsub_gateway(&self) -> Self::Gateway {
+            self.mock_pubsub_gateway.clone()
+        }
+    }
+
+    /// runs the worker against `SlowGateway`, which shows the order and overlap of the posts.
+    #[derive(Clone)]
+    struct QueueMods {
+        mock_webhook_repo: Arc<MockWebhookRepository>,
+        mock_pubsub_gateway: Arc<MockPubSubGateway>,
+        gateway: SlowGateway,
+        max_concurrency: usize,
+    }
+    #[async_trait]
+    impl UseContext for QueueMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for QueueMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .webhook(
+                    WebhookBuilder::default()
+                        .max_attempts(1)
+                        .initial_backoff(Duration::ZERO)
+                        .max_backoff(Duration::ZERO)
+                        .max_concurrency(self.max_concurrency)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseWebhookRepository<()> for QueueMods {
+        type WebhookRepository = Arc<MockWebhookRepository>;
+
+        fn webhook_repository(&self) -> Self::WebhookRepository {
+            self.mock_webhook_repo.clone()
+        }
+    }
+    impl UseWebhookGateway<()> for QueueMods {
+        type Gateway = SlowGateway;
+
+        fn webhook_gateway(&self) -> Self::Gateway {
+            self.gateway.clone()
+        }
+    }
+    impl UsePubSubGateway<()> for QueueMods {
+        type Gateway = Arc<MockPubSubGateway>;
+
+        fn pubsub_gateway(&self) -> Self::Gateway {
+            self.mock_pubsub_gateway.clone()
+        }
+    }
+
+    /// records the posts as they complete. the first message takes the longest, so a
+    /// delivery overtaking it would be recorded before it.
+    #[derive(Clone, Default)]
+    struct SlowGateway {
+        posted: Arc<Mutex<Vec<WebhookRequest>>>,
+        in_flight: Arc<AtomicUsize>,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+    #[async_trait]
+    impl WebhookGateway<()> for SlowGateway {
+        async fn resolve(&self, _: (), _: String, port: u16) -> Result<Vec<SocketAddr>> {
+            Ok(public_addrs(port))
+        }
+        async fn post(&self, _: (), request: WebhookRequest) -> Result<WebhookResponse> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            let millis = if request.body == b"0" { 50 } else { 10 };
+            tokio::time::sleep(Duration::from_millis(millis)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            self.posted.lock().unwrap().push(request);
+            Ok(WebhookResponse::new(200))
+        }
+    }
+
+    /// a worker whose channel has `count` webhooks and is sent the messages "0", "1", ...
+    fn queue_mods(count: usize, messages: usize, max_concurrency: usize) -> QueueMods {
+        let mut mock_webhook_repo = MockWebhookRepository::default();
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_webhook_repo
+            .expect_find_channel_ids()
+            .returning(|_| Ok(vec!["channel_id".to_string()]));
+        mock_webhook_repo
+            .expect_find_by_channel()
+            .returning(move |_, _| {
+                Ok((0..count)
+                    .map(|i| {
+                        let mut webhook = webhook();
+                        webhook.id = format!("webhook_id_{i}");
+                        webhook.url = format!("https://example.com/hook/{i}");
+                        webhook
+                    })
+                    .collect())
+            });
+        mock_webhook_repo
+            .expect_create_delivery()
+            .returning(|_, _| Ok(()));
+        mock_webhook_repo
+            .expect_update_delivery()
+            .returning(|_, _| Ok(()));
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .return_once(move |_, _| {
+                let (tx, rx) = tokio::sync::mpsc::channel(messages);
+                tokio::spawn(async move {
+                    for i in 0..messages {
+                        tx.send(SubscriptionEvent::Message(i.to_string().into_bytes()))
+                            .await
+                            .unwrap();
+                    }
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                });
+                Ok(rx)
+            });
+        QueueMods {
+            mock_webhook_repo: Arc::new(mock_webhook_repo),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+            gateway: SlowGateway::default(),
+            max_concurrency,
+        }
+    }
+
+    fn webhook() -> Webhook {
+        Webhook::new(
+            "webhook_id".to_string(),
+            "channel_id".to_string(),
+            "account_id".to_string(),
+            "https://example.com/hook".to_string(),
+            "secret".to_string(),
+            current_time(),
+        )
+    }
+
+    fn public_addrs(port: u16) -> Vec<SocketAddr> {
+        vec![SocketAddr::from(([93, 184, 216, 34], port))]
+    }
+
+    #[tokio::test]
+    async fn deliver_signed_message() {
+        let mut mock_webhook_repo = MockWebhookRepository::default();
+        let mut mock_webhook_gateway = MockWebhookGateway::default();
+        let mut mock_pubsub_gateway = MockPubSubGateway::default();
+        mock_webhook_repo
+            .expect_find_channel_ids()
+            .returning(|_| Ok(vec!["channel_id".to_string()]));
+        mock_webhook_repo
+            .expect_find_by_channel()
+            .with(predicate::eq(()), predicate::eq("channel_id".to_string()))
+            .returning(|_, _| Ok(vec![webhook()]));
+        mock_webhook_repo
+            .expect_create_delivery()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_webhook_repo
+            .expect_update_delivery()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_pubsub_gateway
+            .expect_subscribe()
+            .with(
+                predicate::eq(()),
+                predicate::eq("channel:channel_id".to_string()),
+            )
+            .return_once(|_, _| {
+                let (tx, rx) = tokio::sync::mpsc::channel(1);
+                tokio::spawn(async move {
+                    tx.send(SubscriptionEvent::Message(b"hello".to_vec()))
+                        .await
+                        .unwrap();
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                });
+                Ok(rx)
+            });
+        mock_webhook_gateway
+            .expect_resolve()
+            .with(
+                predicate::eq(()),
+                predicate::eq("example.com".to_string()),
+                predicate::eq(443),
+            )
+            .returning(|_, _, port| Ok(public_addrs(port)));
+        mock_webhook_gateway
+            .expect_post()
+            .times(1)
+            .returning(|_, request| {
+                let timestamp = request
+                    .headers
+                    .iter()
+                    .find(|(k, _)| k == internal::webhook::TIMESTAMP_HEADER)
+                    .unwrap()
+                    .1
+                    .parse::<i64>()
+                    .unwrap();
+                let signature = &request
+                    .headers
+                    .iter()
+                    .find(|(k, _)| k == internal::webhook::SIGNATURE_HEADER)
+                    .unwrap()
+                    .1;
+                assert_eq!(
+                    &internal::webhook::sign("secret", timestamp, b"hello").unwrap(),
+                    signature
+                );
+                assert_eq!("https://example.com/hook", request.url);
+                assert_eq!(public_addrs(443), request.addrs);
+                assert_eq!(b"hello".to_vec(), request.body);
+                Ok(WebhookResponse::new(200))
+            });
+        let mods = TestMods {
+            mock_webhook_repo: Arc::new(mock_webhook_repo),
+            mock_webhook_gateway: Arc::new(mock_webhook_gateway),
+            mock_pubsub_gateway: Arc::new(mock_pubsub_gateway),
+        };
+        let interactor = WebhookWorkerUseCase::new(mods);
+        let mut output = interactor
+            .handle(WebhookWorkerInput::new(Duration::from_secs(60)))
+            .await
+            .unwrap();
+        let delivery = output.rx.recv().await.unwrap();
+        assert_eq!(delivery.status, WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.attempts, 1);
+        assert_eq!(delivery.response_status, Some(200));
+    }
+
+    #[tokio::test]
+    async fn deliver_messages_to_webhook_in_order() {
+        let mods = queue_mods(1, 5, 16);
+        let gateway = mods.gateway.clone();
+        let mut output = WebhookWorkerUseCase::new(mods)
+            .handle(WebhookWorkerInput::new(Duration::from_secs(60)))
+            .await
+            .unwrap();
+        let mut payloads = vec![];
+        for _ in 0..5 {
+            payloads.push(output.rx.recv().await.unwrap().payload);
+        }
+        let expected = (0..5)
+            .map(|i| i.to_string().into_bytes())
+            .collect::<Vec<_>>();
+        assert_eq!(expected, payloads);
+        let posted = gateway
+            .posted
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.body.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(expected, posted);
+        assert_eq!(1, gateway.max_in_flight.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn deliveries_in_progress_are_bounded() {
+        let mods = queue_mods(4, 3, 2);
+        let gateway = mods.gateway.clone();
+        let mut output = WebhookWorkerUseCase::new(mods)
+            .handle(WebhookWorkerInput::new(Duration::from_secs(60)))
+            .await
+            .unwrap();
+        for _ in 0..12 {
+            output.rx.recv().await.unwrap();
+        }
+        assert_eq!(2, gateway.max_in_flight.load(Ordering::SeqCst));
+        let posted = gateway.posted.lock().unwrap().clone();
+        for i in 0..4 {
+            let url = format!("https://example.com/hook/{i}");
+            let bodies = posted
+                .iter()
+                .filter(|request| request.url == url)
+                .map(|request| request.body.clone())
+                .collect::<Vec<_>>();
+            assert_eq!(vec![b"0".to_vec(), b"1".to_vec(), b"2".to_vec()], bodies);
+        }
+    }
+
+    #[tokio::test]
+    async fn dead_letter_after_max_attempts() {
+        let mut mock_webhook_repo = MockWebhookRepository::default();
+        let mut mock_webhook_gateway = MockWebhookGateway::default();
+        mock_webhook_repo
+            .expect_create_delivery()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_webhook_repo
+            .expect_update_delivery()
+            .times(3)
+            .returning(|_, _| Ok(()));
+        mock_webhook_gateway
+            .expect_resolve()
+            .times(3)
+            .returning(|_, _, port| Ok(public_addrs(port)));
+        let mut attempts = 0;
+        mock_webhook_gateway
+            .expect_post()
+            .times(3)
+            .returning(move |_, _| {
+                attempts += 1;
+                if attempts == 1 {
+                    Ok(WebhookResponse::new(500))
+                } else {
+                    Err(unexpected!("connection refused").into())
+                }
+            });
+        let mods = TestMods {
+            mock_webhook_repo: Arc::new(mock_webhook_repo),
+            mock_webhook_gateway: Arc::new(mock_webhook_gateway),
+            mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+        };
+        let delivery = internal::webhook::deliver(
+            &mods.config(),
+            mods.webhook_gateway(),
+            mods.webhook_repository(),
+            (),
+            webhook(),
+            b"hello".to_vec(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(delivery.status, WebhookDeliveryStatus::DeadLetter);
+        assert_eq!(delivery.attempts, 3);
+        assert_eq!(delivery.response_status, None);
+    }
+
+    #[tokio::test]
+    async fn deliver_refuses_host_pointed_at_private_address() {
+        let mut mock_webhook_repo = MockWebhookRepository::default();
+        let mut mock_webhook_gateway = MockWebhookGateway::default();
+        mock_webhook_repo
+            .expect_create_delivery()
+            .returning(|_, _| Ok(()));
+        mock_webhook_repo
+            .expect_update_delivery()
+            .times(3)
+            .returning(|_, _| Ok(()));
+        mock_webhook_gateway
+            .expect_resolve()
+            .returning(|_, _, port| Ok(vec![SocketAddr::from(([10, 0, 0, 1], port))]));
+        mock_webhook_gateway.expect_post().never();
+        let mods = TestMods {
+            mock_webhook_repo: Arc::new(mock_webhook_repo),
+            mock_webhook_gateway: Arc::new(mock_webhook_gateway),
+            mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+        };
+        let delivery = internal::webhook::deliver(
+            &mods.config(),
+            mods.webhook_gateway(),
+            mods.webhook_repository(),
+            (),
+            webhook(),
+            b"hello".to_vec(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(delivery.status, WebhookDeliveryStatus::DeadLetter);
+        assert!(delivery.last_error.is_some());
+    }
+
+    #[tokio::test]
+    async fn create_webhook_rejects_non_public_url() {
+        let urls = [
+            "http://example.com/hook",
+            "https://127.0.0.1/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]:8443/hook",
+            "https://[::ffff:10.0.0.1]/hook",
+            "https://internal.example.com/hook",
+            "not a url",
+        ];
+        for url in urls {
+            let mut mock_webhook_repo = MockWebhookRepository::default();
+            let mut mock_webhook_gateway = MockWebhookGateway::default();
+            mock_webhook_repo.expect_create().never();
+            mock_webhook_gateway
+                .expect_resolve()
+                .with(
+                    predicate::eq(()),
+                    predicate::eq("internal.example.com".to_string()),
+                    predicate::eq(443),
+                )
+                .returning(|_, _, port| Ok(vec![SocketAddr::from(([192, 168, 1, 10], port))]));
+            let mods = TestMods {
+                mock_webhook_repo: Arc::new(mock_webhook_repo),
+                mock_webhook_gateway: Arc::new(mock_webhook_gateway),
+                mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+            };
+            let input = CreateWebhookInput::new(
+                "account_id".to_string(),
+                "channel_id".to_string(),
+                url.to_string(),
+            );
+            let err = CreateWebhookUseCase::new(mods)
+                .handle(input)
+                .await
+                .err()
+                .unwrap();
+            assert!(
+                matches!(
+                    err.downcast_ref::<Error>(),
+                    Some(Error::BadRequest(Codes::InvalidWebhookUrl, _))
+                ),
+                "{url}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn create_webhook_with_public_url() {
+        let mut mock_webhook_repo = MockWebhookRepository::default();
+        let mut mock_webhook_gateway = MockWebhookGateway::default();
+        mock_webhook_gateway
+            .expect_resolve()
+            .returning(|_, _, port| Ok(public_addrs(port)));
+        mock_webhook_repo
+            .expect_create()
+            .times(1)
+            .returning(|_, webhook| Ok(webhook));
+        let mods = TestMods {
+            mock_webhook_repo: Arc::new(mock_webhook_repo),
+            mock_webhook_gateway: Arc::new(mock_webhook_gateway),
+            mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+        };
+        let input = CreateWebhookInput::new(
+            "account_id".to_string(),
+            "channel_id".to_string(),
+            "https://example.com/hook".to_string(),
+        );
+        let output = CreateWebhookUseCase::new(mods).handle(input).await.unwrap();
+        assert_eq!(output.webhook.url, "https://example.com/hook");
+    }
+
+    #[tokio::test]
+    async fn delete_foreign_webhook_is_forbidden() {
+        let mut mock_webhook_repo = MockWebhookRepository::default();
+        mock_webhook_repo
+            .expect_get()
+            .with(predicate::always(), predicate::eq("webhook_id".to_string()))
+            .returning(|_, _| Ok(Some(webhook())));
+        mock_webhook_repo.expect_delete().never();
+        let mods = TestMods {
+            mock_webhook_repo: Arc::new(mock_webhook_repo),
+            mock_webhook_gateway: Arc::new(MockWebhookGateway::default()),
+            mock_pubsub_gateway: Arc::new(MockPubSubGateway::default()),
+        };
+        let input = DeleteWebhookInput::new(
+            "other_account_id".to_string(),
+            "channel_id".to_string(),
+            "webhook_id".to_string(),
+        );
+        let err = DeleteWebhookUseCase::new(mods)
+            .handle(input)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::Forbidden(Codes::NotWebhookOwner, _))
+        ));
+    }
+}
@@ -1,38 +1,121 @@
-use driver::http::server::api::config::Config as APIConfig;
-use driver::mysql::config::Config as MySQLConfig;
+use anyhow::bail;
+use driver::grpc::config::Config as GrpcConfig;
+use driver::http::server::api::config::{Config as APIConfig, Cors, Csrf, Tls, Tracking};
+use driver::rdb::config::Config as RDBConfig;
 use driver::redis::config::Config as RedisConfig;
-use helper::env::get_var;
-use kernel::Result;
+use helper::env::{get_var, get_var_opt, get_var_or, get_var_or_else};
+use kernel::{unexpected, Result};
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
 use std::time::Duration;
 
 #[derive(Clone)]
 pub(crate) struct Config {
     pub(crate) api_config: APIConfig,
-    pub(crate) mysql_config: MySQLConfig,
+    /// the grpc listener is not started when `None`.
+    pub(crate) grpc_config: Option<GrpcConfig>,
+    pub(crate) db_config: RDBConfig,
     pub(crate) redis_config: RedisConfig,
+    /// bounds the connection draining and the closing of the pools each.
+    pub(crate) shutdown_timeout: Duration,
 }
 
 impl Config {
     pub fn new() -> Result<Config> {
+        let shutdown_timeout = Duration::from_secs(get_var_or("SHUTDOWN_TIMEOUT", 30));
         let config = Config {
             api_config: APIConfig::new(
                 format!("0.0.0.0:{}", get_var::<u16>("LISTEN_PORT")?).parse()?,
+                get_var_opt::<u16>("METRICS_LISTEN_PORT")
+                    .map(|port| format!("0.0.0.0:{port}").parse())
+                    .transpose()?,
+                shutdown_timeout,
+                Csrf::new(
+                    list(get_var::<String>("CSRF_ALLOW_SITE_HOSTS")?),
+                    list(get_var::<String>("CSRF_ALLOW_X_FROM")?),
+                    list(get_var_or_else("CSRF_EXEMPT_PATHS", || {
+                        Csrf::DEFAULT_EXEMPT_PATHS.to_string()
+                    })),
+                    get_var_or("CSRF_DOUBLE_SUBMIT", false),
+                ),
+                Tracking::new(
+                    get_var_or_else("TRACKING_COOKIE_NAME", || "tid".to_string()),
+                    Duration::from_secs(get_var_or("TRACKING_COOKIE_MAX_AGE", 86400 * 365)),
+                    get_var_opt("TRACKING_COOKIE_DOMAIN"),
+                    get_var_or("TRACKING_REQUIRE_CONSENT", true),
+                ),
+                cors()?,
+                tls()?,
             ),
-            mysql_config: MySQLConfig::new(
+            grpc_config: grpc()?,
+            db_config: RDBConfig::new(
                 get_var("DATABASE_URL")?,
                 get_var("DATABASE_MIN_CONNECTIONS")?,
                 get_var("DATABASE_MAX_CONNECTIONS")?,
                 Duration::from_secs(get_var("DATABASE_CONNECT_TIMEOUT")?),
                 Duration::from_secs(get_var("DATABASE_IDLE_TIMEOUT")?),
                 Duration::from_secs(get_var("DATABASE_MAX_LIFETIME")?),
-            ),
+            )?,
             redis_config: RedisConfig::new(
                 get_var("REDIS_PRIMARY_URL")?,
                 get_var("REDIS_READER_URL")?,
                 Some(get_var("REDIS_MIN_IDLE")?),
                 get_var("REDIS_MAX_SIZE")?,
             ),
+            shutdown_timeout,
         };
         Ok(config)
     }
 }
+
+/// enabled by CORS_ALLOW_ORIGINS.
+fn cors() -> Result<Option<Cors>> {
+    let Some(allow_origins) = get_var_opt::<String>("CORS_ALLOW_ORIGINS") else {
+        return Ok(None);
+    };
+    let cors = Cors::new(
+        list(allow_origins),
+        list(get_var_or_else("CORS_ALLOW_METHODS", || {
+            "GET,POST,PUT,PATCH,DELETE".to_string()
+        })),
+        list(get_var_or_else("CORS_ALLOW_HEADERS", || {
+            "content-type,x-from,x-csrf-token".to_string()
+        })),
+        get_var_or("CORS_ALLOW_CREDENTIALS", true),
+        Duration::from_secs(get_var_or("CORS_MAX_AGE", 600)),
+    )?;
+    Ok(Some(cors))
+}
+
+/// enabled by GRPC_LISTEN_PORT, on GRPC_LISTEN_HOST or all interfaces.
+/// served over mTLS only, with the certificates of the http server.
+fn grpc() -> Result<Option<GrpcConfig>> {
+    let Some(port) = get_var_opt::<u16>("GRPC_LISTEN_PORT") else {
+        return Ok(None);
+    };
+    let Some(tls) = tls()? else {
+        bail!(unexpected!(
+            "grpc requires TLS_CERT_PATH, TLS_KEY_PATH and TLS_CLIENT_CA_PATH"
+        ));
+    };
+    let host = get_var_or("GRPC_LISTEN_HOST", IpAddr::from([0, 0, 0, 0]));
+    Ok(Some(GrpcConfig::new(SocketAddr::new(host, port), tls)?))
+}
+
+/// enabled by TLS_CERT_PATH and TLS_KEY_PATH, with mTLS when TLS_CLIENT_CA_PATH is set.
+fn tls() -> Result<Option<Tls>> {
+    let Some(cert_path) = get_var_opt::<PathBuf>("TLS_CERT_PATH") else {
+        return Ok(None);
+    };
+    Ok(Some(Tls::new(
+        cert_path,
+        get_var("TLS_KEY_PATH")?,
+        get_var_opt("TLS_CLIENT_CA_PATH"),
+        Duration::from_secs(get_var_or("TLS_RELOAD_INTERVAL", 10)),
+    )))
+}
+
+/// comma separated values.
+pub(crate) fn list(value: String) -> Vec<String> {
+    value.split(',').map(ToString::to_string).collect()
+}
@@ -1,10 +1,16 @@
 use crate::modules::Modules;
-use application::interface::config::{AuthBuilder, ConfigBuilder, SystemBuilder};
+use application::interface::config::{
+    AnalyticsBuilder, AuthBuilder, ConfigBuilder, HealthBuilder, IdempotencyBuilder,
+    MessageFilterBuilder, RateLimit, RateLimitBuilder, SystemBuilder,
+};
 use driver::aws::ssm::load_from_ssm;
-use driver::mysql::DB;
+use driver::rdb::RDB;
 use driver::redis::Redis;
-use helper::env::get_var;
+use helper::env::{get_var, get_var_or, get_var_or_else};
+use kernel::entity::{MessageFilterRule, RateLimitRule};
 use kernel::Result;
+use std::collections::HashMap;
+use std::time::Duration;
 mod config;
 mod modules;
 
@@ -14,10 +20,15 @@ async fn main() -> Result<()> {
     if let Ok(ssm_envs_path) = get_var("SSM_ENVS_PATH") {
         load_from_ssm(ssm_envs_path).await?;
     }
-    log::init();
+    log::init()?;
     let config = config::Config::new()?;
-    let db = DB::new(config.mysql_config).await?;
+    let db = RDB::new(config.db_config).await?;
+    // off by default, the schema is expected to be migrated by the `migrate` command on deploy.
+    if get_var_or("DATABASE_AUTO_MIGRATE", false) {
+        db.migrate_up().await?;
+    }
     let redis = Redis::new(config.redis_config).await?;
+    driver::metrics::register_pools(&db, &redis)?;
     let cfg = ConfigBuilder::default()
         .auth(
             AuthBuilder::default()
@@ -32,19 +43,100 @@ async fn main() -> Result<()> {
                 .build()
                 .unwrap(),
         )
+        .message_filter(
+            MessageFilterBuilder::default()
+                .rules(load_message_filter_rules()?)
+                .build()?,
+        )
+        .rate_limit(
+            RateLimitBuilder::default()
+                .rules(load_rate_limit_rules()?)
+                .exempt_paths(config::list(get_var_or_else(
+                    "RATE_LIMIT_EXEMPT_PATHS",
+                    || RateLimit::DEFAULT_EXEMPT_PATHS.to_string(),
+                )))
+                .build()?,
+        )
+        .health(
+            HealthBuilder::default()
+                .timeout(Duration::from_millis(get_var_or(
+                    "HEALTH_CHECK_TIMEOUT_MS",
+                    1000,
+                )))
+                .build()?,
+        )
+        .idempotency(
+            IdempotencyBuilder::default()
+                .ttl(Duration::from_secs(get_var_or("IDEMPOTENCY_TTL", 86400)))
+                .lock_ttl(Duration::from_secs(get_var_or("IDEMPOTENCY_LOCK_TTL", 60)))
+                .build()?,
+        )
+        .analytics(
+            AnalyticsBuilder::default()
+                .max_events(get_var_or("ANALYTICS_MAX_EVENTS", 1000usize))
+                .retention(Duration::from_secs(get_var_or(
+                    "ANALYTICS_RETENTION",
+                    86400 * 90,
+                )))
+                .build()?,
+        )
         .build()
         .unwrap();
-    driver::http::server::api::start(config.api_config, Modules::new(cfg, db, redis)).await?;
+    let modules = Modules::new(cfg, db.clone(), redis.clone());
+    let grpc = async {
+        match config.grpc_config {
+            Some(grpc_config) => driver::grpc::start(grpc_config, modules.clone()).await,
+            None => Ok(()),
+        }
+    };
+    tokio::try_join!(
+        driver::http::server::api::start(config.api_config, modules.clone()),
+        grpc
+    )?;
+    redis.close();
+    // connections still in use after the timeout are left to the process exit.
+    let _ = tokio::time::timeout(config.shutdown_timeout, db.close()).await;
+    log::shutdown();
     Ok(())
 }
 
+/// loads `{"<channel_id or *>": [rule, ...]}` from MESSAGE_FILTER_PATH.
+fn load_message_filter_rules() -> Result<HashMap<String, Vec<MessageFilterRule>>> {
+    let Ok(path) = get_var::<String>("MESSAGE_FILTER_PATH") else {
+        return Ok(HashMap::new());
+    };
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+/// loads `[rule, ...]` from RATE_LIMIT_PATH, the first rule matching a request applies.
+fn load_rate_limit_rules() -> Result<Vec<RateLimitRule>> {
+    let Ok(path) = get_var::<String>("RATE_LIMIT_PATH") else {
+        return Ok(vec![]);
+    };
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
 mod log {
-    use helper::env::{get_var_or_else, var_is};
+    use helper::env::{get_var_opt, get_var_or_else, var_is};
+    use kernel::Result;
+    use opentelemetry::sdk::propagation::TraceContextPropagator;
+    use opentelemetry::sdk::trace::{self, Tracer};
+    use opentelemetry::sdk::Resource;
+    use opentelemetry::{global, runtime, KeyValue};
+    use opentelemetry_otlp::WithExportConfig;
     use tracing_subscriber::filter::LevelFilter;
     use tracing_subscriber::prelude::*;
     use tracing_subscriber::{filter, registry};
 
-    pub fn init() {
+    /// exports spans to OTEL_EXPORTER_OTLP_ENDPOINT as well, if set.
+    pub fn init() -> Result<()> {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let telemetry = match get_var_opt::<String>("OTEL_EXPORTER_OTLP_ENDPOINT") {
+            Some(endpoint) => {
+                Some(tracing_opentelemetry::layer().with_tracer(otlp_tracer(endpoint)?))
+            }
+            None => None,
+        };
         let log_format = tracing_subscriber::fmt::Layer::default()
             .with_line_number(true)
             .with_file(true)
@@ -53,9 +145,40 @@ mod log {
             filter::Targets::new().with_default(LevelFilter::INFO)
         });
         if var_is::<String>("LOG_FORMAT", "json".to_string()) {
-            registry().with(filter).with(log_format.json()).init();
+            registry()
+                .with(filter)
+                .with(telemetry)
+                .with(log_format.json())
+                .init();
         } else {
-            registry().with(filter).with(log_format).init();
+            registry()
+                .with(filter)
+                .with(telemetry)
+                .with(log_format)
+                .init();
         }
+        Ok(())
+    }
+
+    fn otlp_tracer(endpoint: String) -> Result<Tracer> {
+        let resource = Resource::new(vec![KeyValue::new(
+            "service.name",
+            get_var_or_else("OTEL_SERVICE_NAME", || "api".to_string()),
+        )]);
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(
+                opentelemetry_otlp::new_exporter()
+                    .tonic()
+                    .with_endpoint(endpoint),
+            )
+            .with_trace_config(trace::config().with_resource(resource))
+            .install_batch(runtime::Tokio)?;
+        Ok(tracer)
+    }
+
+    /// flushes the spans not exported yet.
+    pub fn shutdown() {
+        global::shutdown_tracer_provider();
     }
 }
@@ -1,13 +1,27 @@
 use application::interface::config::Config;
+use application::interface::gateway::health::UseHealthGateway;
 use application::interface::gateway::mail::UseMailGateway;
 use application::interface::gateway::pubsub::UsePubSubGateway;
+use application::interface::gateway::rate_limit::UseRateLimitGateway;
+use application::interface::gateway::webhook::UseWebhookGateway;
 use application::interface::repository::account::UseAccountRepository;
+use application::interface::repository::analytics::UseAnalyticsRepository;
 use application::interface::repository::authentication::UseAuthenticationRepository;
+use application::interface::repository::channel::UseChannelRepository;
 use application::interface::repository::comment::UseCommentRepository;
+use application::interface::repository::idempotency::UseIdempotencyRepository;
+use application::interface::repository::message::UseMessageRepository;
 use application::interface::repository::session::UseSessionRepository;
+use application::interface::repository::webhook::UseWebhookRepository;
 use application::interface::repository::Transaction;
 use application::interface::{UseConfig, UseContext};
-use application::usecase::account::{GetAccountInput, GetAccountOutput, GetAccountUseCase};
+use application::usecase::account::{
+    GetAccountInput, GetAccountOutput, GetAccountUseCase, UpdateLocaleInput, UpdateLocaleOutput,
+    UpdateLocaleUseCase,
+};
+use application::usecase::analytics::{
+    RecordAnalyticsEventInput, RecordAnalyticsEventOutput, RecordAnalyticsEventUseCase,
+};
 use application::usecase::auth::{
     ForgetPasswordInput, ForgetPasswordOutput, ForgetPasswordUseCase, GetAuthStatusInput,
     GetAuthStatusOutput, GetAuthStatusUseCase, ResetPasswordInput, ResetPasswordOutput,
@@ -16,22 +30,55 @@ use application::usecase::auth::{
     SignUpOutput, SignUpUseCase,
 };
 use application::usecase::channel::{
+    AddModeratorInput, AddModeratorOutput, AddModeratorUseCase, GetUnreadCountsInput,
+    GetUnreadCountsOutput, GetUnreadCountsUseCase, MarkReadInput, MarkReadOutput, MarkReadUseCase,
     PubSubInput, PubSubOutput, PubSubUseCase, PublishInput, PublishOutput, PublishUseCase,
-    SubscribeInput, SubscribeOutput, SubscribeUseCase,
+    RemoveModeratorInput, RemoveModeratorOutput, RemoveModeratorUseCase, ResubscribeInput,
+    ResubscribeUseCase, SubscribeInput, SubscribeOutput, SubscribeUseCase,
+};
+use application::usecase::idempotency::{
+    BeginIdempotencyInput, BeginIdempotencyOutput, BeginIdempotencyUseCase,
+    CompleteIdempotencyInput, CompleteIdempotencyOutput, CompleteIdempotencyUseCase,
+};
+use application::usecase::message::{
+    AddReactionInput, AddReactionOutput, AddReactionUseCase, DeleteMessageInput,
+    DeleteMessageOutput, DeleteMessageUseCase, EditMessageInput, EditMessageOutput,
+    EditMessageUseCase, GetThreadInput, GetThreadOutput, GetThreadUseCase, ListMessageEditsInput,
+    ListMessageEditsOutput, ListMessageEditsUseCase, RemoveReactionInput, RemoveReactionOutput,
+    RemoveReactionUseCase,
 };
+use application::usecase::rate_limit::{RateLimitInput, RateLimitOutput, RateLimitUseCase};
 use application::usecase::session::{GetSessionInput, GetSessionOutput, GetSessionUseCase};
-use application::usecase::status::{StatusInput, StatusOutput, StatusUseCase};
+use application::usecase::status::{
+    LivenessInput, LivenessOutput, LivenessUseCase, ReadinessInput, ReadinessOutput,
+    ReadinessUseCase, StatusInput, StatusOutput, StatusUseCase,
+};
+use application::usecase::webhook::{
+    CreateWebhookInput, CreateWebhookOutput, CreateWebhookUseCase, DeleteWebhookInput,
+    DeleteWebhookOutput, DeleteWebhookUseCase, ListWebhookDeliveriesInput,
+    ListWebhookDeliveriesOutput, ListWebhookDeliveriesUseCase, ListWebhooksInput,
+    ListWebhooksOutput, ListWebhooksUseCase,
+};
 use application::usecase::UseUseCase;
 use async_trait::async_trait;
 use derive_new::new;
+use driver::adapter::gateway::health::HealthGatewayImpl;
 use driver::adapter::gateway::mail::{SesContext, SesMailGateway};
 use driver::adapter::gateway::pubsub::PubSubGatewayImpl;
+use driver::adapter::gateway::rate_limit::RateLimitGatewayImpl;
+use driver::adapter::gateway::webhook::{HttpClientContext, HttpWebhookGateway};
 use driver::adapter::repository::account::AccountRepositoryImpl;
+use driver::adapter::repository::analytics::AnalyticsRepositoryImpl;
 use driver::adapter::repository::authentication::AuthenticationRepositoryImpl;
+use driver::adapter::repository::channel::ChannelRepositoryImpl;
 use driver::adapter::repository::comment::CommentRepositoryImpl;
+use driver::adapter::repository::idempotency::IdempotencyRepositoryImpl;
+use driver::adapter::repository::message::MessageRepositoryImpl;
 use driver::adapter::repository::session::SessionRepositoryImpl;
+use driver::adapter::repository::webhook::WebhookRepositoryImpl;
 use driver::http::server::api::presenter::openapi::OpenAPIServerPresenter;
-use driver::mysql::{MySQLContext, MySql, PoolConnection, DB};
+use driver::mysql::PoolConnection;
+use driver::rdb::{Backend, DBContext, Dialect, RDBContext, RDB};
 use driver::redis::{
     PooledRedisConnection, Redis, RedisConnection, RedisConnectionManager, RedisPrimaryContext,
     RedisReaderContext,
@@ -45,13 +92,13 @@ use tokio::sync::Mutex;
 #[derive(Clone, new)]
 pub(crate) struct Modules {
     cfg: Config,
-    db: DB,
+    db: RDB,
     redis: Redis,
 }
 
 #[derive(Clone)]
 pub(crate) struct Context {
-    db: Arc<DB>,
+    db: Arc<RDB>,
     redis: Redis,
 }
 
@@ -73,9 +120,15 @@ impl Transaction for Context {
 }
 
 #[async_trait]
-impl MySQLContext for Context {
-    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<MySql>>>> {
-        self.db.acquire().await
+impl<D: Dialect> DBContext<D> for Context {
+    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<D>>>> {
+        DBContext::<D>::acquire(&*self.db).await
+    }
+}
+
+impl RDBContext for Context {
+    fn backend(&self) -> Backend {
+        self.db.backend()
     }
 }
 
@@ -100,6 +153,8 @@ impl RedisReaderContext for Context {
 #[async_trait]
 impl SesContext for Context {}
 
+impl HttpClientContext for Context {}
+
 impl UseConfig for Modules {
     fn config(&self) -> Config {
         self.cfg.clone()
@@ -133,6 +188,22 @@ impl UseSessionRepository<Context> for Modules {
     }
 }
 
+impl UseIdempotencyRepository<Context> for Modules {
+    type IdempotencyRepository = IdempotencyRepositoryImpl;
+
+    fn idempotency_repository(&self) -> Self::IdempotencyRepository {
+        IdempotencyRepositoryImpl
+    }
+}
+
+impl UseAnalyticsRepository<Context> for Modules {
+    type AnalyticsRepository = AnalyticsRepositoryImpl;
+
+    fn analytics_repository(&self) -> Self::AnalyticsRepository {
+        AnalyticsRepositoryImpl
+    }
+}
+
 impl UseAuthenticationRepository<Context> for Modules {
     type AuthenticationRepository = AuthenticationRepositoryImpl;
 
@@ -149,6 +220,46 @@ impl UseCommentRepository<Context> for Modules {
     }
 }
 
+impl UseMessageRepository<Context> for Modules {
+    type MessageRepository = MessageRepositoryImpl;
+
+    fn message_repository(&self) -> Self::MessageRepository {
+        MessageRepositoryImpl
+    }
+}
+
+impl UseChannelRepository<Context> for Modules {
+    type ChannelRepository = ChannelRepositoryImpl;
+
+    fn channel_repository(&self) -> Self::ChannelRepository {
+        ChannelRepositoryImpl
+    }
+}
+
+impl UseWebhookGateway<Context> for Modules {
+    type Gateway = HttpWebhookGateway;
+
+    fn webhook_gateway(&self) -> Self::Gateway {
+        HttpWebhookGateway
+    }
+}
+
+impl UseWebhookRepository<Context> for Modules {
+    type WebhookRepository = WebhookRepositoryImpl;
+
+    fn webhook_repository(&self) -> Self::WebhookRepository {
+        WebhookRepositoryImpl
+    }
+}
+
+impl UseHealthGateway<Context> for Modules {
+    type Gateway = HealthGatewayImpl;
+
+    fn health_gateway(&self) -> Self::Gateway {
+        HealthGatewayImpl
+    }
+}
+
 impl UseMailGateway<Context> for Modules {
     type Gateway = SesMailGateway;
 
@@ -165,6 +276,14 @@ impl UsePubSubGateway<Context> for Modules {
     }
 }
 
+impl UseRateLimitGateway<Context> for Modules {
+    type Gateway = RateLimitGatewayImpl;
+
+    fn rate_limit_gateway(&self) -> Self::Gateway {
+        RateLimitGatewayImpl
+    }
+}
+
 impl UsePresenter for Modules {
     type Presenter = OpenAPIServerPresenter;
 
@@ -179,12 +298,30 @@ impl UseUseCase<StatusInput, StatusOutput> for Modules {
         StatusUseCase::new(self.clone())
     }
 }
+impl UseUseCase<LivenessInput, LivenessOutput> for Modules {
+    type UseCase = LivenessUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        LivenessUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<ReadinessInput, ReadinessOutput> for Modules {
+    type UseCase = ReadinessUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        ReadinessUseCase::new(self.clone())
+    }
+}
 impl UseUseCase<GetAccountInput, GetAccountOutput> for Modules {
     type UseCase = GetAccountUseCase<Context, Modules>;
     fn usecase(&self) -> Self::UseCase {
         GetAccountUseCase::new(self.clone())
     }
 }
+impl UseUseCase<UpdateLocaleInput, UpdateLocaleOutput> for Modules {
+    type UseCase = UpdateLocaleUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        UpdateLocaleUseCase::new(self.clone())
+    }
+}
 impl UseUseCase<GetAuthStatusInput, GetAuthStatusOutput> for Modules {
     type UseCase = GetAuthStatusUseCase<Context, Modules>;
     fn usecase(&self) -> Self::UseCase {
@@ -239,15 +376,129 @@ impl UseUseCase<SubscribeInput, SubscribeOutput> for Modules {
         SubscribeUseCase::new(self.clone())
     }
 }
+impl UseUseCase<ResubscribeInput, SubscribeOutput> for Modules {
+    type UseCase = ResubscribeUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        ResubscribeUseCase::new(self.clone())
+    }
+}
 impl UseUseCase<PubSubInput, PubSubOutput> for Modules {
     type UseCase = PubSubUseCase<Context, Modules>;
     fn usecase(&self) -> Self::UseCase {
         PubSubUseCase::new(self.clone())
     }
 }
+impl UseUseCase<MarkReadInput, MarkReadOutput> for Modules {
+    type UseCase = MarkReadUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        MarkReadUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<GetUnreadCountsInput, GetUnreadCountsOutput> for Modules {
+    type UseCase = GetUnreadCountsUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        GetUnreadCountsUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<AddModeratorInput, AddModeratorOutput> for Modules {
+    type UseCase = AddModeratorUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        AddModeratorUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<RemoveModeratorInput, RemoveModeratorOutput> for Modules {
+    type UseCase = RemoveModeratorUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        RemoveModeratorUseCase::new(self.clone())
+    }
+}
 impl UseUseCase<GetSessionInput, GetSessionOutput> for Modules {
     type UseCase = GetSessionUseCase<Context, Modules>;
     fn usecase(&self) -> Self::UseCase {
         GetSessionUseCase::new(self.clone())
     }
 }
+impl UseUseCase<RateLimitInput, RateLimitOutput> for Modules {
+    type UseCase = RateLimitUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        RateLimitUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<BeginIdempotencyInput, BeginIdempotencyOutput> for Modules {
+    type UseCase = BeginIdempotencyUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        BeginIdempotencyUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<CompleteIdempotencyInput, CompleteIdempotencyOutput> for Modules {
+    type UseCase = CompleteIdempotencyUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        CompleteIdempotencyUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<CreateWebhookInput, CreateWebhookOutput> for Modules {
+    type UseCase = CreateWebhookUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        CreateWebhookUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<ListWebhooksInput, ListWebhooksOutput> for Modules {
+    type UseCase = ListWebhooksUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        ListWebhooksUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<DeleteWebhookInput, DeleteWebhookOutput> for Modules {
+    type UseCase = DeleteWebhookUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        DeleteWebhookUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<ListWebhookDeliveriesInput, ListWebhookDeliveriesOutput> for Modules {
+    type UseCase = ListWebhookDeliveriesUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        ListWebhookDeliveriesUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<RecordAnalyticsEventInput, RecordAnalyticsEventOutput> for Modules {
+    type UseCase = RecordAnalyticsEventUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        RecordAnalyticsEventUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<EditMessageInput, EditMessageOutput> for Modules {
+    type UseCase = EditMessageUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        EditMessageUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<DeleteMessageInput, DeleteMessageOutput> for Modules {
+    type UseCase = DeleteMessageUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        DeleteMessageUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<ListMessageEditsInput, ListMessageEditsOutput> for Modules {
+    type UseCase = ListMessageEditsUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        ListMessageEditsUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<AddReactionInput, AddReactionOutput> for Modules {
+    type UseCase = AddReactionUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        AddReactionUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<RemoveReactionInput, RemoveReactionOutput> for Modules {
+    type UseCase = RemoveReactionUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        RemoveReactionUseCase::new(self.clone())
+    }
+}
+impl UseUseCase<GetThreadInput, GetThreadOutput> for Modules {
+    type UseCase = GetThreadUseCase<Context, Modules>;
+    fn usecase(&self) -> Self::UseCase {
+        GetThreadUseCase::new(self.clone())
+    }
+}
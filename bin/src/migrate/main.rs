@@ -0,0 +1,77 @@
+use clap::{Parser, Subcommand};
+use driver::aws::ssm::load_from_ssm;
+use driver::rdb::config::Config as RDBConfig;
+use driver::rdb::RDB;
+use helper::env::{get_var, get_var_or};
+use kernel::Result;
+use std::time::Duration;
+
+#[derive(Parser, Debug)]
+#[command()]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// applies the pending migrations.
+    Up,
+    /// reverts the latest migration, or every migration newer than `--target`.
+    Down {
+        #[arg(long)]
+        target: Option<i64>,
+    },
+    /// lists the migrations and whether they are applied.
+    Status,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    if let Ok(ssm_envs_path) = get_var("SSM_ENVS_PATH") {
+        load_from_ssm(ssm_envs_path).await?;
+    }
+    log::init();
+    let args = Args::parse();
+    // a single connection is enough, the migrations run one by one.
+    let db = RDB::new(RDBConfig::new(
+        get_var("DATABASE_URL")?,
+        0,
+        1,
+        Duration::from_secs(get_var_or("DATABASE_CONNECT_TIMEOUT", 30)),
+        Duration::from_secs(get_var_or("DATABASE_IDLE_TIMEOUT", 600)),
+        Duration::from_secs(get_var_or("DATABASE_MAX_LIFETIME", 1800)),
+    )?)
+    .await?;
+    match args.command {
+        Command::Up => db.migrate_up().await?,
+        Command::Down { target } => db.migrate_down(target).await?,
+        Command::Status => {
+            for status in db.migration_status().await? {
+                let state = if status.applied { "applied" } else { "pending" };
+                println!("{}\t{}\t{}", status.version, state, status.description);
+            }
+        }
+    }
+    db.close().await;
+    Ok(())
+}
+
+mod log {
+    use helper::env::get_var_or;
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::{filter, fmt, registry};
+
+    pub fn init() {
+        registry()
+            .with(filter::LevelFilter::INFO)
+            .with(
+                fmt::Layer::default()
+                    .with_line_number(true)
+                    .with_file(true)
+                    .with_ansi(get_var_or("LOG_COLOR", true)),
+            )
+            .init();
+    }
+}
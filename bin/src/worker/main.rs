@@ -0,0 +1,221 @@
+use application::interface::config::{
+    Auth, Config as AppConfig, ConfigBuilder, System, WebhookBuilder,
+};
+use application::interface::gateway::pubsub::UsePubSubGateway;
+use application::interface::gateway::webhook::UseWebhookGateway;
+use application::interface::repository::webhook::UseWebhookRepository;
+use application::interface::{UseConfig, UseContext};
+use application::usecase::webhook::{
+    WebhookWorkerInput, WebhookWorkerOutput, WebhookWorkerUseCase,
+};
+use application::usecase::UseUseCase;
+use async_trait::async_trait;
+use derive_new::new;
+use driver::adapter::gateway::pubsub::PubSubGatewayImpl;
+use driver::adapter::gateway::webhook::{HttpClientContext, HttpWebhookGateway};
+use driver::adapter::repository::webhook::WebhookRepositoryImpl;
+use driver::aws::ssm::load_from_ssm;
+use driver::cli::presenter::logging::LoggingPresenter;
+use driver::mysql::PoolConnection;
+use driver::rdb::config::Config as RDBConfig;
+use driver::rdb::{Backend, DBContext, Dialect, RDBContext, RDB};
+use driver::redis::config::Config as RedisConfig;
+use driver::redis::{
+    PooledRedisConnection, Redis, RedisConnection, RedisConnectionManager, RedisPrimaryContext,
+    RedisReaderContext,
+};
+use driver::UsePresenter;
+use helper::env::{get_var, get_var_or};
+use kernel::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenv::dotenv().ok();
+    if let Ok(ssm_envs_path) = get_var("SSM_ENVS_PATH") {
+        load_from_ssm(ssm_envs_path).await?;
+    }
+    log::init();
+    let config = Config::new()?;
+    let db = RDB::new(config.db_config).await?;
+    let redis = Redis::new(config.redis_config).await?;
+    let cfg = ConfigBuilder::default()
+        .auth(Auth::default())
+        .system(System::default())
+        .webhook(
+            WebhookBuilder::default()
+                .max_attempts(get_var_or::<i32>("WEBHOOK_MAX_ATTEMPTS", 8))
+                .initial_backoff(Duration::from_millis(get_var_or(
+                    "WEBHOOK_INITIAL_BACKOFF_MILLIS",
+                    500,
+                )))
+                .max_backoff(Duration::from_millis(get_var_or(
+                    "WEBHOOK_MAX_BACKOFF_MILLIS",
+                    60000,
+                )))
+                .max_concurrency(get_var_or::<usize>("WEBHOOK_MAX_CONCURRENCY", 16))
+                .queue_size(get_var_or::<usize>("WEBHOOK_QUEUE_SIZE", 100))
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    driver::worker::start_webhook_worker(
+        Duration::from_secs(get_var_or("WEBHOOK_RESCAN_INTERVAL", 30)),
+        Modules::new(cfg, db, redis),
+    )
+    .await?;
+    Ok(())
+}
+
+#[derive(Clone, new)]
+struct Modules {
+    cfg: AppConfig,
+    db: RDB,
+    redis: Redis,
+}
+
+impl UsePresenter for Modules {
+    type Presenter = LoggingPresenter;
+
+    fn presenter(&self) -> Self::Presenter {
+        LoggingPresenter
+    }
+}
+
+impl UseConfig for Modules {
+    fn config(&self) -> AppConfig {
+        self.cfg.clone()
+    }
+}
+
+#[async_trait]
+impl UseContext for Modules {
+    type Context = Context;
+
+    async fn context(&self) -> Result<Self::Context> {
+        Ok(Context {
+            db: Arc::new(self.db.clone()),
+            redis: self.redis.clone(),
+        })
+    }
+}
+
+impl UseUseCase<WebhookWorkerInput, WebhookWorkerOutput> for Modules {
+    type UseCase = WebhookWorkerUseCase<Context, Modules>;
+
+    fn usecase(&self) -> Self::UseCase {
+        WebhookWorkerUseCase::new(self.clone())
+    }
+}
+
+impl UseWebhookRepository<Context> for Modules {
+    type WebhookRepository = WebhookRepositoryImpl;
+
+    fn webhook_repository(&self) -> Self::WebhookRepository {
+        WebhookRepositoryImpl
+    }
+}
+
+impl UseWebhookGateway<Context> for Modules {
+    type Gateway = HttpWebhookGateway;
+
+    fn webhook_gateway(&self) -> Self::Gateway {
+        HttpWebhookGateway
+    }
+}
+
+impl UsePubSubGateway<Context> for Modules {
+    type Gateway = PubSubGatewayImpl;
+
+    fn pubsub_gateway(&self) -> Self::Gateway {
+        PubSubGatewayImpl
+    }
+}
+
+#[derive(Clone)]
+pub struct Context {
+    db: Arc<RDB>,
+    redis: Redis,
+}
+
+#[async_trait]
+impl<D: Dialect> DBContext<D> for Context {
+    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<D>>>> {
+        DBContext::<D>::acquire(&*self.db).await
+    }
+}
+
+impl RDBContext for Context {
+    fn backend(&self) -> Backend {
+        self.db.backend()
+    }
+}
+
+#[async_trait]
+impl RedisPrimaryContext for Context {
+    async fn primary(&self) -> Result<PooledRedisConnection<RedisConnectionManager>> {
+        self.redis.primary().await
+    }
+}
+
+#[async_trait]
+impl RedisReaderContext for Context {
+    async fn reader(&self) -> Result<PooledRedisConnection<RedisConnectionManager>> {
+        self.redis.reader().await
+    }
+
+    async fn subscribe_connection(&self) -> Result<RedisConnection> {
+        self.redis.subscribe_connection().await
+    }
+}
+
+impl HttpClientContext for Context {}
+
+mod log {
+    use helper::env::get_var_or;
+    use tracing_subscriber::prelude::*;
+    use tracing_subscriber::{filter, fmt, registry};
+
+    pub fn init() {
+        registry()
+            .with(filter::LevelFilter::INFO)
+            .with(
+                fmt::Layer::default()
+                    .with_line_number(true)
+                    .with_file(true)
+                    .with_ansi(get_var_or("LOG_COLOR", true)),
+            )
+            .init();
+    }
+}
+
+#[derive(Clone)]
+struct Config {
+    pub db_config: RDBConfig,
+    pub redis_config: RedisConfig,
+}
+
+impl Config {
+    pub fn new() -> Result<Config> {
+        let config = Config {
+            db_config: RDBConfig::new(
+                get_var("DATABASE_URL")?,
+                get_var("DATABASE_MIN_CONNECTIONS")?,
+                get_var("DATABASE_MAX_CONNECTIONS")?,
+                Duration::from_secs(get_var("DATABASE_CONNECT_TIMEOUT")?),
+                Duration::from_secs(get_var("DATABASE_IDLE_TIMEOUT")?),
+                Duration::from_secs(get_var("DATABASE_MAX_LIFETIME")?),
+            )?,
+            redis_config: RedisConfig::new(
+                get_var("REDIS_PRIMARY_URL")?,
+                get_var("REDIS_READER_URL")?,
+                Some(get_var("REDIS_MIN_IDLE")?),
+                get_var("REDIS_MAX_SIZE")?,
+            ),
+        };
+        Ok(config)
+    }
+}
@@ -0,0 +1,8 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // no protoc has to be installed to build.
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    tonic_build::compile_protos("proto/api.proto")?;
+    // the migrations are embedded by `sqlx::migrate!`, which cargo does not track.
+    println!("cargo:rerun-if-changed=migrations");
+    Ok(())
+}
@@ -0,0 +1,24 @@
+CREATE TABLE account (
+    id VARCHAR(64) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    display_name VARCHAR(100) NOT NULL,
+    create_time DATETIME(6) NOT NULL,
+    PRIMARY KEY (id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
+
+CREATE TABLE authentication (
+    account_id VARCHAR(64) NOT NULL,
+    mail VARCHAR(255) NOT NULL,
+    salt VARCHAR(64) NOT NULL,
+    password CHAR(128) NOT NULL,
+    PRIMARY KEY (account_id, mail),
+    UNIQUE KEY authentication_mail (mail),
+    CONSTRAINT authentication_account_id FOREIGN KEY (account_id) REFERENCES account (id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
+
+CREATE TABLE comment (
+    id VARCHAR(64) NOT NULL,
+    body TEXT NOT NULL,
+    create_time DATETIME(6) NOT NULL,
+    PRIMARY KEY (id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
@@ -0,0 +1,35 @@
+CREATE TABLE message (
+    id VARCHAR(64) NOT NULL,
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    body TEXT NOT NULL,
+    parent_id VARCHAR(64) NULL,
+    reply_count BIGINT NOT NULL DEFAULT 0,
+    create_time DATETIME(6) NOT NULL,
+    update_time DATETIME(6) NOT NULL,
+    delete_time DATETIME(6) NULL,
+    deleted_by VARCHAR(64) NULL,
+    PRIMARY KEY (id),
+    KEY message_channel_id_create_time (channel_id, create_time),
+    KEY message_parent_id_create_time (parent_id, create_time)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
+
+CREATE TABLE message_edit (
+    id VARCHAR(64) NOT NULL,
+    message_id VARCHAR(64) NOT NULL,
+    body TEXT NOT NULL,
+    edited_by VARCHAR(64) NOT NULL,
+    create_time DATETIME(6) NOT NULL,
+    PRIMARY KEY (id),
+    KEY message_edit_message_id_create_time (message_id, create_time),
+    CONSTRAINT message_edit_message_id FOREIGN KEY (message_id) REFERENCES message (id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
+
+CREATE TABLE message_reaction (
+    message_id VARCHAR(64) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    emoji VARCHAR(32) NOT NULL,
+    create_time DATETIME(6) NOT NULL,
+    PRIMARY KEY (message_id, account_id, emoji),
+    CONSTRAINT message_reaction_message_id FOREIGN KEY (message_id) REFERENCES message (id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
@@ -0,0 +1,2 @@
+DROP TABLE channel_read_position;
+DROP TABLE channel_moderator;
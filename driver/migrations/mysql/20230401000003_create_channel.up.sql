@@ -0,0 +1,16 @@
+CREATE TABLE channel_moderator (
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    create_time DATETIME(6) NOT NULL DEFAULT CURRENT_TIMESTAMP(6),
+    PRIMARY KEY (channel_id, account_id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
+
+CREATE TABLE channel_read_position (
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    message_id VARCHAR(64) NOT NULL,
+    read_time DATETIME(6) NOT NULL,
+    update_time DATETIME(6) NOT NULL,
+    PRIMARY KEY (channel_id, account_id),
+    KEY channel_read_position_account_id (account_id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
@@ -0,0 +1,25 @@
+CREATE TABLE webhook (
+    id VARCHAR(64) NOT NULL,
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    create_time DATETIME(6) NOT NULL,
+    PRIMARY KEY (id),
+    KEY webhook_channel_id_create_time (channel_id, create_time)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
+
+CREATE TABLE webhook_delivery (
+    id VARCHAR(64) NOT NULL,
+    webhook_id VARCHAR(64) NOT NULL,
+    channel_id VARCHAR(255) NOT NULL,
+    payload MEDIUMBLOB NOT NULL,
+    status VARCHAR(16) NOT NULL,
+    attempts INT NOT NULL,
+    response_status INT NULL,
+    last_error TEXT NULL,
+    create_time DATETIME(6) NOT NULL,
+    update_time DATETIME(6) NOT NULL,
+    PRIMARY KEY (id),
+    KEY webhook_delivery_webhook_id_create_time (webhook_id, create_time)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_bin;
@@ -0,0 +1 @@
+ALTER TABLE account ADD COLUMN locale VARCHAR(16) NULL;
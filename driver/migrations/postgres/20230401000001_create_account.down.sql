@@ -0,0 +1,3 @@
+DROP TABLE comment;
+DROP TABLE authentication;
+DROP TABLE account;
@@ -0,0 +1,23 @@
+CREATE TABLE account (
+    id VARCHAR(64) NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    display_name VARCHAR(100) NOT NULL,
+    create_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE authentication (
+    account_id VARCHAR(64) NOT NULL REFERENCES account (id),
+    mail VARCHAR(255) NOT NULL,
+    salt VARCHAR(64) NOT NULL,
+    password CHAR(128) NOT NULL,
+    PRIMARY KEY (account_id, mail),
+    CONSTRAINT authentication_mail UNIQUE (mail)
+);
+
+CREATE TABLE comment (
+    id VARCHAR(64) NOT NULL,
+    body TEXT NOT NULL,
+    create_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (id)
+);
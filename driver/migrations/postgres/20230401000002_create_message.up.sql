@@ -0,0 +1,33 @@
+CREATE TABLE message (
+    id VARCHAR(64) NOT NULL,
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    body TEXT NOT NULL,
+    parent_id VARCHAR(64) NULL,
+    reply_count BIGINT NOT NULL DEFAULT 0,
+    create_time TIMESTAMPTZ NOT NULL,
+    update_time TIMESTAMPTZ NOT NULL,
+    delete_time TIMESTAMPTZ NULL,
+    deleted_by VARCHAR(64) NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX message_channel_id_create_time ON message (channel_id, create_time);
+CREATE INDEX message_parent_id_create_time ON message (parent_id, create_time);
+
+CREATE TABLE message_edit (
+    id VARCHAR(64) NOT NULL,
+    message_id VARCHAR(64) NOT NULL REFERENCES message (id),
+    body TEXT NOT NULL,
+    edited_by VARCHAR(64) NOT NULL,
+    create_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX message_edit_message_id_create_time ON message_edit (message_id, create_time);
+
+CREATE TABLE message_reaction (
+    message_id VARCHAR(64) NOT NULL REFERENCES message (id),
+    account_id VARCHAR(64) NOT NULL,
+    emoji VARCHAR(32) NOT NULL,
+    create_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (message_id, account_id, emoji)
+);
@@ -0,0 +1,16 @@
+CREATE TABLE channel_moderator (
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    create_time TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (channel_id, account_id)
+);
+
+CREATE TABLE channel_read_position (
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    message_id VARCHAR(64) NOT NULL,
+    read_time TIMESTAMPTZ NOT NULL,
+    update_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (channel_id, account_id)
+);
+CREATE INDEX channel_read_position_account_id ON channel_read_position (account_id);
@@ -0,0 +1,25 @@
+CREATE TABLE webhook (
+    id VARCHAR(64) NOT NULL,
+    channel_id VARCHAR(255) NOT NULL,
+    account_id VARCHAR(64) NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    secret VARCHAR(255) NOT NULL,
+    create_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX webhook_channel_id_create_time ON webhook (channel_id, create_time);
+
+CREATE TABLE webhook_delivery (
+    id VARCHAR(64) NOT NULL,
+    webhook_id VARCHAR(64) NOT NULL,
+    channel_id VARCHAR(255) NOT NULL,
+    payload BYTEA NOT NULL,
+    status VARCHAR(16) NOT NULL,
+    attempts INTEGER NOT NULL,
+    response_status INTEGER NULL,
+    last_error TEXT NULL,
+    create_time TIMESTAMPTZ NOT NULL,
+    update_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX webhook_delivery_webhook_id_create_time ON webhook_delivery (webhook_id, create_time);
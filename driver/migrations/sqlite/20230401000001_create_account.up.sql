@@ -0,0 +1,23 @@
+CREATE TABLE account (
+    id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    display_name TEXT NOT NULL,
+    create_time TEXT NOT NULL,
+    PRIMARY KEY (id)
+);
+
+CREATE TABLE authentication (
+    account_id TEXT NOT NULL REFERENCES account (id),
+    mail TEXT NOT NULL,
+    salt TEXT NOT NULL,
+    password TEXT NOT NULL,
+    PRIMARY KEY (account_id, mail),
+    CONSTRAINT authentication_mail UNIQUE (mail)
+);
+
+CREATE TABLE comment (
+    id TEXT NOT NULL,
+    body TEXT NOT NULL,
+    create_time TEXT NOT NULL,
+    PRIMARY KEY (id)
+);
@@ -0,0 +1,3 @@
+DROP TABLE message_reaction;
+DROP TABLE message_edit;
+DROP TABLE message;
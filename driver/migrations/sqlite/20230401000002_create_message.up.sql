@@ -0,0 +1,33 @@
+CREATE TABLE message (
+    id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    body TEXT NOT NULL,
+    parent_id TEXT NULL,
+    reply_count INTEGER NOT NULL DEFAULT 0,
+    create_time TEXT NOT NULL,
+    update_time TEXT NOT NULL,
+    delete_time TEXT NULL,
+    deleted_by TEXT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX message_channel_id_create_time ON message (channel_id, create_time);
+CREATE INDEX message_parent_id_create_time ON message (parent_id, create_time);
+
+CREATE TABLE message_edit (
+    id TEXT NOT NULL,
+    message_id TEXT NOT NULL REFERENCES message (id),
+    body TEXT NOT NULL,
+    edited_by TEXT NOT NULL,
+    create_time TEXT NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX message_edit_message_id_create_time ON message_edit (message_id, create_time);
+
+CREATE TABLE message_reaction (
+    message_id TEXT NOT NULL REFERENCES message (id),
+    account_id TEXT NOT NULL,
+    emoji TEXT NOT NULL,
+    create_time TEXT NOT NULL,
+    PRIMARY KEY (message_id, account_id, emoji)
+);
@@ -0,0 +1,16 @@
+CREATE TABLE channel_moderator (
+    channel_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    create_time TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (channel_id, account_id)
+);
+
+CREATE TABLE channel_read_position (
+    channel_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    message_id TEXT NOT NULL,
+    read_time TEXT NOT NULL,
+    update_time TEXT NOT NULL,
+    PRIMARY KEY (channel_id, account_id)
+);
+CREATE INDEX channel_read_position_account_id ON channel_read_position (account_id);
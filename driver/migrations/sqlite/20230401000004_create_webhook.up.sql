@@ -0,0 +1,25 @@
+CREATE TABLE webhook (
+    id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    account_id TEXT NOT NULL,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    create_time TEXT NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX webhook_channel_id_create_time ON webhook (channel_id, create_time);
+
+CREATE TABLE webhook_delivery (
+    id TEXT NOT NULL,
+    webhook_id TEXT NOT NULL,
+    channel_id TEXT NOT NULL,
+    payload BLOB NOT NULL,
+    status TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    response_status INTEGER NULL,
+    last_error TEXT NULL,
+    create_time TEXT NOT NULL,
+    update_time TEXT NOT NULL,
+    PRIMARY KEY (id)
+);
+CREATE INDEX webhook_delivery_webhook_id_create_time ON webhook_delivery (webhook_id, create_time);
@@ -0,0 +1 @@
+ALTER TABLE account ADD COLUMN locale TEXT NULL;
@@ -0,0 +1,48 @@
+use crate::mysql::MySql;
+use crate::postgres::Postgres;
+use crate::rdb::{Backend, RDBContext};
+use crate::redis::RedisContext;
+use crate::sqlite::Sqlite;
+use application::interface::gateway::health::{Dependency, HealthGateway};
+use async_trait::async_trait;
+use derive_new::new;
+use kernel::Result;
+
+#[derive(Clone, Debug, new)]
+pub struct HealthGatewayImpl;
+
+#[async_trait]
+impl<Context: RDBContext + RedisContext> HealthGateway<Context> for HealthGatewayImpl {
+    async fn ping(&self, ctx: Context, dependency: Dependency) -> Result<()> {
+        match dependency {
+            Dependency::Database => match ctx.backend() {
+                Backend::MySQL => {
+                    crate::traced!("mysql", rdb::gateway, health::ping::<MySql>(ctx))
+                }
+                Backend::Postgres => {
+                    crate::traced!("postgres", rdb::gateway, health::ping::<Postgres>(ctx))
+                }
+                Backend::Sqlite => {
+                    crate::traced!("sqlite", rdb::gateway, health::ping::<Sqlite>(ctx))
+                }
+            },
+            Dependency::RedisPrimary => {
+                crate::traced!("redis", redis::gateway, health::ping_primary(ctx))
+            }
+            Dependency::RedisReader => {
+                crate::traced!("redis", redis::gateway, health::ping_reader(ctx))
+            }
+        }
+    }
+
+    fn name(&self, ctx: &Context, dependency: Dependency) -> String {
+        match dependency {
+            Dependency::Database => match ctx.backend() {
+                Backend::MySQL => "mysql".to_string(),
+                Backend::Postgres => "postgres".to_string(),
+                Backend::Sqlite => "sqlite".to_string(),
+            },
+            dependency => dependency.to_string(),
+        }
+    }
+}
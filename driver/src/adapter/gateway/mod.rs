@@ -1,2 +1,5 @@
+pub mod health;
 pub mod mail;
 pub mod pubsub;
+pub mod rate_limit;
+pub mod webhook;
@@ -1,6 +1,6 @@
 use crate::redis::RedisContext;
 
-use application::interface::gateway::pubsub::PubSubGateway;
+use application::interface::gateway::pubsub::{PubSubGateway, SubscriptionEvent};
 
 use async_trait::async_trait;
 use derive_new::new;
@@ -15,10 +15,18 @@ pub struct PubSubGatewayImpl;
 #[async_trait]
 impl<Context: RedisContext> PubSubGateway<Context> for PubSubGatewayImpl {
     async fn publish(&self, ctx: Context, channel: String, message: &[u8]) -> Result<()> {
-        crate::redis::gateway::pubsub::publish(ctx, channel, message).await
+        crate::traced!(
+            "redis",
+            redis::gateway,
+            pubsub::publish(ctx, channel, message)
+        )
     }
 
-    async fn subscribe(&self, ctx: Context, channel: String) -> Result<Receiver<Vec<u8>>> {
-        crate::redis::gateway::pubsub::subscribe(ctx, channel).await
+    async fn subscribe(
+        &self,
+        ctx: Context,
+        channel: String,
+    ) -> Result<Receiver<SubscriptionEvent>> {
+        crate::traced!("redis", redis::gateway, pubsub::subscribe(ctx, channel))
     }
 }
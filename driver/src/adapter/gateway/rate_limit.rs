@@ -0,0 +1,25 @@
+use crate::redis::RedisPrimaryContext;
+use application::interface::gateway::rate_limit::{Bucket, RateLimitGateway};
+use async_trait::async_trait;
+use derive_new::new;
+use kernel::Result;
+
+#[derive(Clone, Debug, new)]
+pub struct RateLimitGatewayImpl;
+
+#[async_trait]
+impl<Context: RedisPrimaryContext> RateLimitGateway<Context> for RateLimitGatewayImpl {
+    async fn acquire(
+        &self,
+        ctx: Context,
+        key: String,
+        capacity: u32,
+        refill_per_second: f64,
+    ) -> Result<Bucket> {
+        crate::traced!(
+            "redis",
+            redis::gateway,
+            rate_limit::acquire(ctx, key, capacity, refill_per_second)
+        )
+    }
+}
@@ -0,0 +1,25 @@
+use application::interface::gateway::webhook::{WebhookGateway, WebhookRequest, WebhookResponse};
+use application::interface::Component;
+use async_trait::async_trait;
+use derive_new::new;
+use kernel::Result;
+use std::net::SocketAddr;
+
+#[derive(Clone, Debug, new)]
+pub struct HttpWebhookGateway;
+
+pub trait HttpClientContext: Component {}
+
+#[async_trait]
+impl<Context: HttpClientContext> WebhookGateway<Context> for HttpWebhookGateway {
+    async fn resolve(&self, _ctx: Context, host: String, port: u16) -> Result<Vec<SocketAddr>> {
+        crate::http::client::resolve(&host, port).await
+    }
+
+    async fn post(&self, _ctx: Context, request: WebhookRequest) -> Result<WebhookResponse> {
+        let status =
+            crate::http::client::post(&request.url, &request.addrs, request.headers, request.body)
+                .await?;
+        Ok(WebhookResponse::new(status))
+    }
+}
@@ -1,10 +1,11 @@
 use std::fmt::Debug;
 
-use crate::mysql::MySQLContext;
+use crate::rdb::{self, RDBContext};
 use application::interface::repository::account::AccountRepository;
 use async_trait::async_trait;
 use helper::validation::Validation;
 use kernel::entity;
+use kernel::i18n::Locale;
 use kernel::Result;
 
 #[derive(Clone, Debug)]
@@ -13,13 +14,17 @@ pub struct AccountRepositoryImpl;
 #[async_trait]
 impl<Context> AccountRepository<Context> for AccountRepositoryImpl
 where
-    Context: MySQLContext,
+    Context: RDBContext,
 {
     async fn get(&self, ctx: Context, id: String) -> Result<Option<entity::Account>> {
-        crate::mysql::repository::account::get(ctx, id).await
+        rdb::repository!(ctx, account::get(id))
     }
 
     async fn create(&self, ctx: Context, new_account: entity::Account) -> Result<entity::Account> {
-        crate::mysql::repository::account::create(ctx, new_account.validate()?).await
+        rdb::repository!(ctx, account::create(new_account.validate()?))
+    }
+
+    async fn update_locale(&self, ctx: Context, id: String, locale: Locale) -> Result<()> {
+        rdb::repository!(ctx, account::update_locale(id, locale))
     }
 }
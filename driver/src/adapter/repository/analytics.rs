@@ -0,0 +1,30 @@
+use crate::redis::RedisContext;
+use application::interface::repository::analytics::AnalyticsRepository;
+use async_trait::async_trait;
+use derive_new::new;
+use kernel::entity::AnalyticsEvent;
+use kernel::Result;
+use std::time::Duration;
+
+#[derive(Clone, Debug, new)]
+pub struct AnalyticsRepositoryImpl;
+
+#[async_trait]
+impl<Context> AnalyticsRepository<Context> for AnalyticsRepositoryImpl
+where
+    Context: RedisContext,
+{
+    async fn record(
+        &self,
+        ctx: Context,
+        event: AnalyticsEvent,
+        max_events: usize,
+        retention: Duration,
+    ) -> Result<()> {
+        crate::traced!(
+            "redis",
+            redis::repository,
+            analytics::record(ctx, event, max_events, retention)
+        )
+    }
+}
@@ -0,0 +1,56 @@
+use crate::rdb::{self, RDBContext};
+use application::interface::repository::channel::ChannelRepository;
+use async_trait::async_trait;
+use helper::validation::Validation;
+use kernel::entity::{ChannelModerator, ReadPosition, UnreadCount};
+use kernel::Result;
+
+#[derive(Clone, Debug)]
+pub struct ChannelRepositoryImpl;
+
+#[async_trait]
+impl<Context> ChannelRepository<Context> for ChannelRepositoryImpl
+where
+    Context: RDBContext,
+{
+    async fn is_moderator(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<bool> {
+        rdb::repository!(ctx, channel::is_moderator(channel_id, account_id))
+    }
+    async fn find_moderators(
+        &self,
+        ctx: Context,
+        channel_id: String,
+    ) -> Result<Vec<ChannelModerator>> {
+        rdb::repository!(ctx, channel::find_moderators(channel_id))
+    }
+    async fn add_moderator(&self, ctx: Context, moderator: ChannelModerator) -> Result<()> {
+        rdb::repository!(ctx, channel::add_moderator(moderator))
+    }
+    async fn remove_moderator(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<()> {
+        rdb::repository!(ctx, channel::remove_moderator(channel_id, account_id))
+    }
+    async fn get_read_position(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<Option<ReadPosition>> {
+        rdb::repository!(ctx, channel::get_read_position(channel_id, account_id))
+    }
+    async fn save_read_position(&self, ctx: Context, position: ReadPosition) -> Result<()> {
+        rdb::repository!(ctx, channel::save_read_position(position.validate()?))
+    }
+    async fn count_unread(&self, ctx: Context, account_id: String) -> Result<Vec<UnreadCount>> {
+        rdb::repository!(ctx, channel::count_unread(account_id))
+    }
+}
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use crate::mysql::MySQLContext;
+use crate::rdb::{self, RDBContext};
 use application::interface::repository::comment::CommentRepository;
 use async_trait::async_trait;
 use kernel::entity;
@@ -12,13 +12,13 @@ pub struct CommentRepositoryImpl;
 #[async_trait]
 impl<Context> CommentRepository<Context> for CommentRepositoryImpl
 where
-    Context: MySQLContext,
+    Context: RDBContext,
 {
     async fn get(&self, ctx: Context, id: String) -> Result<Option<entity::Comment>> {
-        crate::mysql::repository::comment::get(ctx, id).await
+        rdb::repository!(ctx, comment::get(id))
     }
 
     async fn put(&self, ctx: Context, id: String, body: String) -> Result<Option<entity::Comment>> {
-        crate::mysql::repository::comment::put(ctx, id, body).await
+        rdb::repository!(ctx, comment::put(id, body))
     }
 }
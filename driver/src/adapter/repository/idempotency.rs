@@ -0,0 +1,46 @@
+use crate::redis::RedisContext;
+use application::interface::repository::idempotency::IdempotencyRepository;
+use async_trait::async_trait;
+use derive_new::new;
+use kernel::entity::IdempotencyRecord;
+use kernel::Result;
+use std::time::Duration;
+
+#[derive(Clone, Debug, new)]
+pub struct IdempotencyRepositoryImpl;
+
+#[async_trait]
+impl<Context> IdempotencyRepository<Context> for IdempotencyRepositoryImpl
+where
+    Context: RedisContext,
+{
+    async fn insert(
+        &self,
+        ctx: Context,
+        key: String,
+        record: IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<Option<IdempotencyRecord>> {
+        crate::traced!(
+            "redis",
+            redis::repository,
+            idempotency::insert(ctx, key, record, ttl)
+        )
+    }
+    async fn set(
+        &self,
+        ctx: Context,
+        key: String,
+        record: IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<()> {
+        crate::traced!(
+            "redis",
+            redis::repository,
+            idempotency::set(ctx, key, record, ttl)
+        )
+    }
+    async fn delete(&self, ctx: Context, key: String) -> Result<()> {
+        crate::traced!("redis", redis::repository, idempotency::delete(ctx, key))
+    }
+}
@@ -0,0 +1,78 @@
+use crate::rdb::{self, RDBContext};
+use application::interface::repository::message::MessageRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use helper::validation::Validation;
+use kernel::entity::{Message, MessageEdit, Reaction, ReactionCount};
+use kernel::Result;
+
+#[derive(Clone, Debug)]
+pub struct MessageRepositoryImpl;
+
+#[async_trait]
+impl<Context> MessageRepository<Context> for MessageRepositoryImpl
+where
+    Context: RDBContext,
+{
+    async fn get(&self, ctx: Context, id: String) -> Result<Option<Message>> {
+        rdb::repository!(ctx, message::get(id))
+    }
+    async fn create(&self, ctx: Context, message: Message) -> Result<Message> {
+        rdb::repository!(ctx, message::create(message.validate()?))
+    }
+    async fn update(&self, ctx: Context, message: Message) -> Result<()> {
+        rdb::repository!(ctx, message::update(message.validate()?))
+    }
+    async fn find_replies(&self, ctx: Context, parent_id: String) -> Result<Vec<Message>> {
+        rdb::repository!(ctx, message::find_replies(parent_id))
+    }
+    async fn find_after(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        message_id: String,
+        limit: u64,
+    ) -> Result<Vec<Message>> {
+        rdb::repository!(ctx, message::find_after(channel_id, message_id, limit))
+    }
+    async fn find_first(&self, ctx: Context, channel_id: String) -> Result<Option<Message>> {
+        rdb::repository!(ctx, message::find_first(channel_id))
+    }
+    async fn increment_reply_count(&self, ctx: Context, id: String, delta: i64) -> Result<()> {
+        rdb::repository!(ctx, message::increment_reply_count(id, delta))
+    }
+    async fn delete(
+        &self,
+        ctx: Context,
+        id: String,
+        deleted_by: String,
+        delete_time: DateTime<Utc>,
+    ) -> Result<()> {
+        rdb::repository!(ctx, message::delete(id, deleted_by, delete_time))
+    }
+    async fn create_edit(&self, ctx: Context, edit: MessageEdit) -> Result<()> {
+        rdb::repository!(ctx, message::create_edit(edit.validate()?))
+    }
+    async fn find_edits(&self, ctx: Context, message_id: String) -> Result<Vec<MessageEdit>> {
+        rdb::repository!(ctx, message::find_edits(message_id))
+    }
+    async fn add_reaction(&self, ctx: Context, reaction: Reaction) -> Result<()> {
+        rdb::repository!(ctx, message::add_reaction(reaction.validate()?))
+    }
+    async fn remove_reaction(
+        &self,
+        ctx: Context,
+        message_id: String,
+        account_id: String,
+        emoji: String,
+    ) -> Result<()> {
+        rdb::repository!(ctx, message::remove_reaction(message_id, account_id, emoji))
+    }
+    async fn count_reactions(
+        &self,
+        ctx: Context,
+        message_id: String,
+    ) -> Result<Vec<ReactionCount>> {
+        rdb::repository!(ctx, message::count_reactions(message_id))
+    }
+}
@@ -1,4 +1,9 @@
 pub mod account;
+pub mod analytics;
 pub mod authentication;
+pub mod channel;
 pub mod comment;
+pub mod idempotency;
+pub mod message;
 pub mod session;
+pub mod webhook;
@@ -19,23 +19,35 @@ where
         ctx: Context,
         session: ProvisionalSession,
     ) -> Result<()> {
-        crate::redis::repository::session::set_provisional_session(ctx, session.validate()?).await
+        crate::traced!(
+            "redis",
+            redis::repository,
+            session::set_provisional_session(ctx, session.validate()?)
+        )
     }
     async fn get_provisional_session(
         &self,
         ctx: Context,
         id: String,
     ) -> Result<Option<ProvisionalSession>> {
-        crate::redis::repository::session::get_provisional_session(ctx, id).await
+        crate::traced!(
+            "redis",
+            redis::repository,
+            session::get_provisional_session(ctx, id)
+        )
     }
     async fn set(&self, ctx: Context, session: Session) -> Result<()> {
-        crate::redis::repository::session::set(ctx, session.validate()?).await
+        crate::traced!(
+            "redis",
+            redis::repository,
+            session::set(ctx, session.validate()?)
+        )
     }
 
     async fn get(&self, ctx: Context, id: String) -> Result<Option<Session>> {
-        crate::redis::repository::session::get(ctx, id).await
+        crate::traced!("redis", redis::repository, session::get(ctx, id))
     }
     async fn delete(&self, ctx: Context, id: String) -> Result<()> {
-        crate::redis::repository::session::delete(ctx, id).await
+        crate::traced!("redis", redis::repository, session::delete(ctx, id))
     }
 }
@@ -0,0 +1,46 @@
+use std::fmt::Debug;
+
+use crate::rdb::{self, RDBContext};
+use application::interface::repository::webhook::WebhookRepository;
+use async_trait::async_trait;
+use helper::validation::Validation;
+use kernel::entity::{Webhook, WebhookDelivery};
+use kernel::Result;
+
+#[derive(Clone, Debug)]
+pub struct WebhookRepositoryImpl;
+
+#[async_trait]
+impl<Context> WebhookRepository<Context> for WebhookRepositoryImpl
+where
+    Context: RDBContext,
+{
+    async fn get(&self, ctx: Context, id: String) -> Result<Option<Webhook>> {
+        rdb::repository!(ctx, webhook::get(id))
+    }
+    async fn find_by_channel(&self, ctx: Context, channel_id: String) -> Result<Vec<Webhook>> {
+        rdb::repository!(ctx, webhook::find_by_channel(channel_id))
+    }
+    async fn find_channel_ids(&self, ctx: Context) -> Result<Vec<String>> {
+        rdb::repository!(ctx, webhook::find_channel_ids())
+    }
+    async fn create(&self, ctx: Context, webhook: Webhook) -> Result<Webhook> {
+        rdb::repository!(ctx, webhook::create(webhook.validate()?))
+    }
+    async fn delete(&self, ctx: Context, id: String) -> Result<()> {
+        rdb::repository!(ctx, webhook::delete(id))
+    }
+    async fn create_delivery(&self, ctx: Context, delivery: WebhookDelivery) -> Result<()> {
+        rdb::repository!(ctx, webhook::create_delivery(delivery))
+    }
+    async fn update_delivery(&self, ctx: Context, delivery: WebhookDelivery) -> Result<()> {
+        rdb::repository!(ctx, webhook::update_delivery(delivery))
+    }
+    async fn find_deliveries(
+        &self,
+        ctx: Context,
+        webhook_id: String,
+    ) -> Result<Vec<WebhookDelivery>> {
+        rdb::repository!(ctx, webhook::find_deliveries(webhook_id))
+    }
+}
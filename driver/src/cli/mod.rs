@@ -17,7 +17,7 @@ struct Args {
 }
 pub async fn listen_pubsub<M: Mods<P>, P: Presenter>(mods: M) -> Result<()> {
     let args = Args::parse();
-    let _ = dispatch(SubscribeInput::new(args.channel), mods).await;
+    let _ = dispatch(SubscribeInput::new(args.channel, None), mods).await;
     Ok(())
 }
 
@@ -1,5 +1,6 @@
 use crate::Present;
 use application::usecase::channel::SubscribeOutput;
+use application::usecase::webhook::WebhookWorkerOutput;
 use async_trait::async_trait;
 use futures::StreamExt;
 use kernel::Result;
@@ -21,6 +22,31 @@ impl Present<Result<SubscribeOutput>> for LoggingPresenter {
     }
 }
 
+#[async_trait]
+impl Present<Result<WebhookWorkerOutput>> for LoggingPresenter {
+    type Output = ();
+
+    async fn present(&self, data: Result<WebhookWorkerOutput>, _: ()) -> Self::Output {
+        let output = match data {
+            Ok(output) => output,
+            Err(e) => {
+                log::error!("webhook worker error: {:?}", e);
+                return;
+            }
+        };
+        let mut stream = ReceiverStream::new(output.rx);
+        while let Some(delivery) = stream.next().await {
+            log::info!(
+                "webhook delivery: id={}, webhook_id={}, status={}, attempts={}",
+                delivery.id,
+                delivery.webhook_id,
+                delivery.status,
+                delivery.attempts
+            );
+        }
+    }
+}
+
 async fn logging_receiver(rx: Receiver<Vec<u8>>) {
     let _ = tokio::spawn(async move {
         let mut stream = ReceiverStream::new(rx);
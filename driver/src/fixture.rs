@@ -0,0 +1,65 @@
+//! use cases answering fixed outputs, for testing the servers without the databases behind them.
+use application::usecase::UseCase;
+use async_trait::async_trait;
+use chrono::Utc;
+use kernel::entity::{Account, Message};
+use kernel::Result;
+
+/// answers every input with the same output.
+pub(crate) struct Fixture<O>(pub(crate) fn() -> Result<O>);
+
+impl<O> Clone for Fixture<O> {
+    fn clone(&self) -> Self {
+        Fixture(self.0)
+    }
+}
+
+#[async_trait]
+impl<I, O> UseCase<I, O> for Fixture<O>
+where
+    I: Send + 'static,
+    O: Send + 'static,
+{
+    async fn handle(&self, _: I) -> Result<O> {
+        (self.0)()
+    }
+}
+
+/// implements `UseUseCase` for `$mods` with a [Fixture] per input.
+macro_rules! fixtures {
+    ($mods:ty { $($input:ty => $output:ty = $fixture:expr;)* }) => {
+        $(
+            impl ::application::usecase::UseUseCase<$input, $output> for $mods {
+                type UseCase = $crate::fixture::Fixture<$output>;
+                fn usecase(&self) -> Self::UseCase {
+                    $crate::fixture::Fixture($fixture)
+                }
+            }
+        )*
+    };
+}
+pub(crate) use fixtures;
+
+pub(crate) fn account() -> Account {
+    Account::new(
+        "account".to_string(),
+        "name".to_string(),
+        "display name".to_string(),
+        Utc::now(),
+    )
+}
+
+pub(crate) fn message() -> Message {
+    Message::new(
+        "message".to_string(),
+        "channel".to_string(),
+        "account".to_string(),
+        "body".to_string(),
+        None,
+        0,
+        Utc::now(),
+        Utc::now(),
+        None,
+        None,
+    )
+}
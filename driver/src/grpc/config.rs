@@ -0,0 +1,23 @@
+use crate::http::server::api::config::Tls;
+use anyhow::bail;
+use kernel::{unexpected, Result};
+use std::net::SocketAddr;
+
+/// the rpcs act for the account named in each request, so only clients presenting a certificate
+/// signed by the client CA of `tls` are served.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub(crate) bind_address: SocketAddr,
+    pub(crate) tls: Tls,
+}
+
+impl Config {
+    pub fn new(bind_address: SocketAddr, tls: Tls) -> Result<Config> {
+        if tls.client_ca_path.is_none() {
+            bail!(unexpected!(
+                "grpc requires a client CA to verify its clients"
+            ));
+        }
+        Ok(Config { bind_address, tls })
+    }
+}
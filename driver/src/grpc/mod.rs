@@ -0,0 +1,240 @@
+use crate::grpc::config::Config;
+use crate::grpc::pb::api_server::ApiServer;
+use crate::grpc::service::ApiService;
+use crate::http::server::api::config::Tls;
+use crate::http::server::shutdown;
+use anyhow::Context as _;
+use application::interface::Component;
+use application::usecase::account::{GetAccountInput, GetAccountOutput};
+use application::usecase::channel::{
+    GetUnreadCountsInput, GetUnreadCountsOutput, MarkReadInput, MarkReadOutput, PublishInput,
+    PublishOutput, SubscribeInput, SubscribeOutput,
+};
+use application::usecase::message::{GetThreadInput, GetThreadOutput};
+use application::usecase::status::{StatusInput, StatusOutput};
+use application::usecase::UseUseCase;
+use kernel::{unexpected, Result};
+use std::path::Path;
+use tonic::transport::{Certificate, Identity, Server, ServerTlsConfig};
+use trait_set::trait_set;
+
+pub mod config;
+pub mod presenter;
+mod service;
+
+pub mod pb {
+    tonic::include_proto!("api.v1");
+}
+
+/// serves the same use cases as the http api over mTLS until its shutdown starts.
+/// the certificates are read once, unlike the http server a renewal needs a restart.
+pub async fn start<M: Mods>(config: Config, mods: M) -> Result<()> {
+    Server::builder()
+        .tls_config(tls_config(&config.tls)?)
+        .with_context(|| unexpected!("grpc tls config error"))?
+        .add_service(ApiServer::new(ApiService::new(mods)))
+        .serve_with_shutdown(config.bind_address, shutdown::started())
+        .await
+        .with_context(|| unexpected!("grpc server error"))
+}
+
+fn tls_config(tls: &Tls) -> Result<ServerTlsConfig> {
+    let identity = Identity::from_pem(read(&tls.cert_path)?, read(&tls.key_path)?);
+    let config = ServerTlsConfig::new().identity(identity);
+    match &tls.client_ca_path {
+        Some(path) => Ok(config.client_ca_root(Certificate::from_pem(read(path)?))),
+        None => Ok(config),
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+    std::fs::read(path).with_context(|| unexpected!("read pem error: {}", path.display()))
+}
+
+trait_set! {
+    pub trait Mods = Component
+    + UseUseCase<StatusInput, StatusOutput>
+    + UseUseCase<GetAccountInput, GetAccountOutput>
+    + UseUseCase<PublishInput, PublishOutput>
+    + UseUseCase<SubscribeInput, SubscribeOutput>
+    + UseUseCase<GetThreadInput, GetThreadOutput>
+    + UseUseCase<MarkReadInput, MarkReadOutput>
+    + UseUseCase<GetUnreadCountsInput, GetUnreadCountsOutput>
+    ;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::config::Config;
+    use super::pb::api_client::ApiClient;
+    use super::pb::{GetAccountRequest, GetStatusRequest, PublishRequest};
+    use super::start;
+    use crate::fixture::{fixtures, message};
+    use crate::http::server::api::config::Tls;
+    use application::usecase::account::{GetAccountInput, GetAccountOutput};
+    use application::usecase::channel::{
+        GetUnreadCountsInput, GetUnreadCountsOutput, MarkReadInput, MarkReadOutput, PublishInput,
+        PublishOutput, SubscribeInput, SubscribeOutput,
+    };
+    use application::usecase::message::{GetThreadInput, GetThreadOutput};
+    use application::usecase::status::{StatusInput, StatusOutput};
+    use chrono::Utc;
+    use kernel::entity::ReadPosition;
+    use kernel::Result;
+    use rcgen::{BasicConstraints, Certificate as Cert, CertificateParams, IsCa};
+    use std::path::{Path, PathBuf};
+    use std::time::Duration;
+    use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
+    use tonic::Code;
+
+    #[derive(Clone)]
+    struct TestMods;
+
+    fixtures! {
+        TestMods {
+            StatusInput => StatusOutput = || {
+                Ok(StatusOutput::new(Some("1.0.0".to_string()), "-".to_string()))
+            };
+            GetAccountInput => GetAccountOutput = || Ok(GetAccountOutput::new(None));
+            PublishInput => PublishOutput = || Ok(PublishOutput::new(message()));
+            SubscribeInput => SubscribeOutput = || {
+                Ok(SubscribeOutput::new(tokio::sync::mpsc::channel(1).1))
+            };
+            GetThreadInput => GetThreadOutput = || Ok(GetThreadOutput::new(message(), vec![]));
+            MarkReadInput => MarkReadOutput = || {
+                Ok(MarkReadOutput::new(ReadPosition::new(
+                    "channel".to_string(),
+                    "account".to_string(),
+                    "message".to_string(),
+                    Utc::now(),
+                    Utc::now(),
+                )))
+            };
+            GetUnreadCountsInput => GetUnreadCountsOutput = || Ok(GetUnreadCountsOutput::new(vec![]));
+        }
+    }
+
+    /// a CA, a server certificate for localhost and a client certificate signed by it.
+    struct Pki {
+        dir: PathBuf,
+        ca: String,
+        client: Identity,
+    }
+
+    impl Pki {
+        fn new(test: &str) -> Pki {
+            let dir = std::env::temp_dir().join(format!("{test}-{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let mut params = CertificateParams::new(vec![]);
+            params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            let ca = Cert::from_params(params).unwrap();
+            let issue = |name: &str| {
+                let cert = rcgen::generate_simple_self_signed(vec![name.to_string()]).unwrap();
+                (
+                    cert.serialize_pem_with_signer(&ca).unwrap(),
+                    cert.serialize_private_key_pem(),
+                )
+            };
+            let (server_cert, server_key) = issue("localhost");
+            let (client_cert, client_key) = issue("client");
+            let ca = ca.serialize_pem().unwrap();
+            std::fs::write(dir.join("ca.pem"), &ca).unwrap();
+            std::fs::write(dir.join("cert.pem"), server_cert).unwrap();
+            std::fs::write(dir.join("key.pem"), server_key).unwrap();
+            Pki {
+                dir,
+                ca,
+                client: Identity::from_pem(client_cert, client_key),
+            }
+        }
+
+        fn path(&self, name: &str) -> PathBuf {
+            self.dir.join(name)
+        }
+    }
+
+    impl Drop for Pki {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn tls(pki: &Pki, client_ca_path: Option<&Path>) -> Tls {
+        Tls::new(
+            pki.path("cert.pem"),
+            pki.path("key.pem"),
+            client_ca_path.map(Path::to_path_buf),
+            Duration::from_secs(1),
+        )
+    }
+
+    async fn connect(port: u16, tls: ClientTlsConfig) -> Result<ApiClient<Channel>> {
+        let endpoint =
+            Channel::from_shared(format!("https://localhost:{port}"))?.tls_config(tls)?;
+        // the server may not be listening yet.
+        for _ in 0..50 {
+            if let Ok(channel) = endpoint.connect().await {
+                return Ok(ApiClient::new(channel));
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        Ok(ApiClient::new(endpoint.connect().await?))
+    }
+
+    #[test]
+    fn config_requires_client_ca() {
+        let pki = Pki::new("grpc-config-test");
+        let address = "127.0.0.1:0".parse().unwrap();
+        assert!(Config::new(address, tls(&pki, None)).is_err());
+        assert!(Config::new(address, tls(&pki, Some(&pki.path("ca.pem")))).is_ok());
+    }
+
+    #[tokio::test]
+    async fn serves_only_clients_with_certificates() {
+        let pki = Pki::new("grpc-mtls-test");
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let config = Config::new(
+            ([127, 0, 0, 1], port).into(),
+            tls(&pki, Some(&pki.path("ca.pem"))),
+        )
+        .unwrap();
+        let server = tokio::spawn(start(config, TestMods));
+        let client_tls = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(&pki.ca))
+            .domain_name("localhost");
+
+        let mut client = connect(port, client_tls.clone().identity(pki.client.clone()))
+            .await
+            .unwrap();
+        let status = client.get_status(GetStatusRequest {}).await.unwrap();
+        assert_eq!(status.into_inner().version, "1.0.0");
+        let published = client
+            .publish(PublishRequest {
+                channel_id: "channel".to_string(),
+                account_id: "account".to_string(),
+                parent_id: None,
+                body: b"body".to_vec(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(published.into_inner().id, "message");
+        let missing = client
+            .get_account(GetAccountRequest {
+                account_id: "account".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code(), Code::NotFound);
+
+        let anonymous = match connect(port, client_tls).await {
+            Ok(mut client) => client.get_status(GetStatusRequest {}).await.is_err(),
+            Err(_) => true,
+        };
+        assert!(anonymous);
+        server.abort();
+    }
+}
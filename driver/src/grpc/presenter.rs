@@ -0,0 +1,213 @@
+use crate::grpc::pb;
+use crate::http::server::shutdown;
+use crate::metrics::{GaugeGuard, GRPC_STREAMS};
+use crate::Present;
+use application::usecase::account::GetAccountOutput;
+use application::usecase::channel::{
+    GetUnreadCountsOutput, MarkReadOutput, PublishOutput, SubscribeOutput,
+};
+use application::usecase::message::GetThreadOutput;
+use application::usecase::status::StatusOutput;
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use kernel::error::Codes;
+use kernel::Result;
+use std::pin::Pin;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::metadata::MetadataValue;
+use tonic::{Code, Response, Status};
+use validator::ValidationErrors;
+
+pub type SubscribeStream = Pin<Box<dyn Stream<Item = Result<pb::ChannelEvent, Status>> + Send>>;
+
+/// carries the error code of the http api's problem details.
+static ERROR_CODE: &str = "error-code";
+
+#[derive(Clone, Default)]
+pub struct GrpcPresenter;
+
+#[async_trait]
+impl Present<Result<StatusOutput>> for GrpcPresenter {
+    type Output = Result<Response<pb::StatusResponse>, Status>;
+
+    async fn present(&self, data: Result<StatusOutput>, _: ()) -> Self::Output {
+        let output = data.map_err(|e| to_status(&e))?;
+        Ok(Response::new(pb::StatusResponse {
+            version: output.version.unwrap_or_else(|| "-".to_string()),
+            build_timestamp: output.build_timestamp,
+        }))
+    }
+}
+#[async_trait]
+impl Present<Result<GetAccountOutput>> for GrpcPresenter {
+    type Output = Result<Response<pb::Account>, Status>;
+
+    async fn present(&self, data: Result<GetAccountOutput>, _: ()) -> Self::Output {
+        let Some(account) = data.map_err(|e| to_status(&e))?.account else {
+            return Err(status(Code::NotFound, &Codes::AccountNotFound, None));
+        };
+        Ok(Response::new(pb::Account {
+            id: account.id,
+            name: account.name,
+            display_name: account.display_name,
+        }))
+    }
+}
+#[async_trait]
+impl Present<Result<PublishOutput>> for GrpcPresenter {
+    type Output = Result<Response<pb::Message>, Status>;
+
+    async fn present(&self, data: Result<PublishOutput>, _: ()) -> Self::Output {
+        let output = data.map_err(|e| to_status(&e))?;
+        Ok(Response::new(convert_message(output.message)))
+    }
+}
+#[async_trait]
+impl Present<Result<SubscribeOutput>> for GrpcPresenter {
+    type Output = Result<Response<SubscribeStream>, Status>;
+
+    #[allow(clippy::result_large_err)]
+    async fn present(&self, data: Result<SubscribeOutput>, _: ()) -> Self::Output {
+        let output = data.map_err(|e| to_status(&e))?;
+        let connection = GaugeGuard::new(&GRPC_STREAMS);
+        let stream = ReceiverStream::new(output.rx)
+            .map(move |data| {
+                // the gauge is decremented as the stream is dropped.
+                let _ = &connection;
+                Ok(pb::ChannelEvent { data })
+            })
+            .take_until(shutdown::started());
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+#[async_trait]
+impl Present<Result<GetThreadOutput>> for GrpcPresenter {
+    type Output = Result<Response<pb::Thread>, Status>;
+
+    async fn present(&self, data: Result<GetThreadOutput>, _: ()) -> Self::Output {
+        let output = data.map_err(|e| to_status(&e))?;
+        Ok(Response::new(pb::Thread {
+            parent: Some(convert_message(output.parent)),
+            replies: output.replies.into_iter().map(convert_message).collect(),
+        }))
+    }
+}
+#[async_trait]
+impl Present<Result<MarkReadOutput>> for GrpcPresenter {
+    type Output = Result<Response<pb::ReadPosition>, Status>;
+
+    async fn present(&self, data: Result<MarkReadOutput>, _: ()) -> Self::Output {
+        let position = data.map_err(|e| to_status(&e))?.read_position;
+        Ok(Response::new(pb::ReadPosition {
+            channel_id: position.channel_id,
+            account_id: position.account_id,
+            message_id: position.message_id,
+            update_time: position.update_time.to_rfc3339(),
+        }))
+    }
+}
+#[async_trait]
+impl Present<Result<GetUnreadCountsOutput>> for GrpcPresenter {
+    type Output = Result<Response<pb::UnreadCounts>, Status>;
+
+    async fn present(&self, data: Result<GetUnreadCountsOutput>, _: ()) -> Self::Output {
+        let output = data.map_err(|e| to_status(&e))?;
+        Ok(Response::new(pb::UnreadCounts {
+            unread_counts: output
+                .unread_counts
+                .into_iter()
+                .map(|c| pb::UnreadCount {
+                    channel_id: c.channel_id,
+                    message_id: c.message_id,
+                    count: c.count,
+                })
+                .collect(),
+        }))
+    }
+}
+
+fn convert_message(message: kernel::entity::Message) -> pb::Message {
+    pb::Message {
+        id: message.id,
+        channel_id: message.channel_id,
+        account_id: message.account_id,
+        body: message.body,
+        parent_id: message.parent_id,
+        reply_count: message.reply_count,
+        create_time: message.create_time.to_rfc3339(),
+        update_time: message.update_time.to_rfc3339(),
+    }
+}
+
+/// maps the errors as the http api maps them to status codes.
+pub(crate) fn to_status(err: &anyhow::Error) -> Status {
+    if let Some(usecase_err) = err.downcast_ref::<kernel::Error>() {
+        match usecase_err {
+            kernel::Error::BadRequest(type_code, message) => {
+                log::warn!("{}", err);
+                status(Code::InvalidArgument, type_code, message.clone())
+            }
+            kernel::Error::Unauthorized(type_code, message) => {
+                log::warn!("{}", err);
+                status(Code::Unauthenticated, type_code, message.clone())
+            }
+            kernel::Error::Forbidden(type_code, message) => {
+                log::warn!("{}", err);
+                status(Code::PermissionDenied, type_code, message.clone())
+            }
+            kernel::Error::NotFound(type_code, message) => {
+                log::warn!("{}", err);
+                status(Code::NotFound, type_code, message.clone())
+            }
+            kernel::Error::Unexpected(type_code, message) => {
+                log::error!("{:?}", err);
+                status(Code::Internal, type_code, message.clone())
+            }
+        }
+    } else if let Some(errors) = err.downcast_ref::<ValidationErrors>() {
+        log::warn!("{}", err);
+        status(
+            Code::InvalidArgument,
+            &Codes::ValidationFailed,
+            Some(errors.to_string()),
+        )
+    } else {
+        log::error!("{:?}", err);
+        status(Code::Internal, &Codes::CommonUnexpected, None)
+    }
+}
+
+fn status(code: Code, type_code: &Codes, message: Option<String>) -> Status {
+    let mut status = Status::new(
+        code,
+        message.unwrap_or_else(|| type_code.title().to_string()),
+    );
+    if let Ok(value) = MetadataValue::try_from(type_code.to_string()) {
+        status.metadata_mut().insert(ERROR_CODE, value);
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grpc::presenter::{to_status, ERROR_CODE};
+    use kernel::error::Codes;
+    use tonic::Code;
+
+    #[test]
+    fn maps_errors_to_status_codes() {
+        let err = anyhow::Error::new(kernel::Error::not_found(
+            Codes::AccountNotFound,
+            "account not found",
+        ));
+        let status = to_status(&err);
+        assert_eq!(status.code(), Code::NotFound);
+        assert_eq!(status.message(), "account not found");
+        assert_eq!(
+            status.metadata().get(ERROR_CODE).unwrap(),
+            Codes::AccountNotFound.to_string().as_str()
+        );
+        let status = to_status(&anyhow::anyhow!("connection reset"));
+        assert_eq!(status.code(), Code::Internal);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::dispatch_to;
+use crate::grpc::pb::api_server::Api;
+use crate::grpc::pb::{
+    Account, GetAccountRequest, GetStatusRequest, GetThreadRequest, GetUnreadCountsRequest,
+    MarkReadRequest, Message, PublishRequest, ReadPosition, StatusResponse, SubscribeRequest,
+    Thread, UnreadCounts,
+};
+use crate::grpc::presenter::{GrpcPresenter, SubscribeStream};
+use crate::grpc::Mods;
+use application::usecase::account::GetAccountInput;
+use application::usecase::channel::{
+    GetUnreadCountsInput, MarkReadInput, PublishInput, SubscribeInput,
+};
+use application::usecase::message::GetThreadInput;
+use application::usecase::status::StatusInput;
+use derive_new::new;
+use tonic::{Request, Response, Status};
+
+#[derive(new)]
+pub(crate) struct ApiService<M> {
+    mods: M,
+    #[new(default)]
+    presenter: GrpcPresenter,
+}
+
+#[tonic::async_trait]
+impl<M: Mods> Api for ApiService<M> {
+    async fn get_status(
+        &self,
+        _: Request<GetStatusRequest>,
+    ) -> Result<Response<StatusResponse>, Status> {
+        dispatch_to(StatusInput, (), self.mods.clone(), &self.presenter).await
+    }
+
+    async fn get_account(
+        &self,
+        request: Request<GetAccountRequest>,
+    ) -> Result<Response<Account>, Status> {
+        let GetAccountRequest { account_id } = request.into_inner();
+        let input = GetAccountInput::new(account_id, None);
+        dispatch_to(input, (), self.mods.clone(), &self.presenter).await
+    }
+
+    async fn publish(&self, request: Request<PublishRequest>) -> Result<Response<Message>, Status> {
+        let PublishRequest {
+            channel_id,
+            account_id,
+            parent_id,
+            body,
+        } = request.into_inner();
+        let input = PublishInput::new(channel_id, account_id, parent_id, body);
+        dispatch_to(input, (), self.mods.clone(), &self.presenter).await
+    }
+
+    type SubscribeStream = SubscribeStream;
+
+    async fn subscribe(
+        &self,
+        request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let SubscribeRequest { channel_id } = request.into_inner();
+        let input = SubscribeInput::new(channel_id, None);
+        dispatch_to(input, (), self.mods.clone(), &self.presenter).await
+    }
+
+    async fn get_thread(
+        &self,
+        request: Request<GetThreadRequest>,
+    ) -> Result<Response<Thread>, Status> {
+        let GetThreadRequest {
+            channel_id,
+            message_id,
+        } = request.into_inner();
+        let input = GetThreadInput::new(channel_id, message_id);
+        dispatch_to(input, (), self.mods.clone(), &self.presenter).await
+    }
+
+    async fn mark_read(
+        &self,
+        request: Request<MarkReadRequest>,
+    ) -> Result<Response<ReadPosition>, Status> {
+        let MarkReadRequest {
+            channel_id,
+            account_id,
+            message_id,
+        } = request.into_inner();
+        let input = MarkReadInput::new(account_id, channel_id, message_id);
+        dispatch_to(input, (), self.mods.clone(), &self.presenter).await
+    }
+
+    async fn get_unread_counts(
+        &self,
+        request: Request<GetUnreadCountsRequest>,
+    ) -> Result<Response<UnreadCounts>, Status> {
+        let GetUnreadCountsRequest { account_id } = request.into_inner();
+        let input = GetUnreadCountsInput::new(account_id);
+        dispatch_to(input, (), self.mods.clone(), &self.presenter).await
+    }
+}
@@ -0,0 +1,131 @@
+use anyhow::Context as _;
+use helper::env::get_var_or;
+use kernel::{unexpected, Result};
+use once_cell::sync::Lazy;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Client, ClientBuilder, Url};
+use std::net::SocketAddr;
+use std::time::Duration;
+
+pub async fn resolve(host: &str, port: u16) -> Result<Vec<SocketAddr>> {
+    Ok(tokio::net::lookup_host((host, port))
+        .await
+        .with_context(|| unexpected!("resolve error: {}", host))?
+        .collect())
+}
+
+/// posts `body` to `url`, connecting to `addrs` instead of resolving the host when given.
+pub async fn post(
+    url: &str,
+    addrs: &[SocketAddr],
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+) -> Result<u16> {
+    let host = Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string));
+    let client = match host {
+        Some(host) if !addrs.is_empty() => client_builder()
+            .resolve_to_addrs(&host, addrs)
+            .build()
+            .with_context(|| unexpected!("http client build error"))?,
+        _ => HTTP_CLIENT.clone(),
+    };
+    let mut request = client
+        .post(url)
+        .header(CONTENT_TYPE, mime::APPLICATION_OCTET_STREAM.as_ref())
+        .body(body);
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    let response = request
+        .send()
+        .await
+        .with_context(|| unexpected!("http post error"))?;
+    Ok(response.status().as_u16())
+}
+
+static HTTP_CLIENT: Lazy<Client> = Lazy::new(|| {
+    client_builder()
+        .build()
+        .expect("failed to build http client")
+});
+
+fn client_builder() -> ClientBuilder {
+    Client::builder()
+        .timeout(Duration::from_secs(get_var_or("HTTP_CLIENT_TIMEOUT", 10)))
+        .redirect(reqwest::redirect::Policy::none())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::{Router, Server};
+    use std::net::{SocketAddr, TcpListener};
+    use std::sync::{Arc, Mutex};
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, Vec<u8>)>>>;
+
+    /// a local stand-in for a partner endpoint. records every request it receives.
+    fn stand_in(status: StatusCode) -> (SocketAddr, Received) {
+        let received: Received = Arc::default();
+        let cloned = received.clone();
+        let app = Router::new().route(
+            "/hook",
+            post(
+                move |headers: HeaderMap, body: axum::body::Bytes| async move {
+                    cloned.lock().unwrap().push((headers, body.to_vec()));
+                    status
+                },
+            ),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            Server::from_tcp(listener)
+                .unwrap()
+                .serve(app.into_make_service()),
+        );
+        (addr, received)
+    }
+
+    #[tokio::test]
+    async fn post_to_stand_in() {
+        let (addr, received) = stand_in(StatusCode::NO_CONTENT);
+        let status = super::post(
+            &format!("http://{addr}/hook"),
+            &[],
+            vec![("x-webhook-signature".to_string(), "sha256=abc".to_string())],
+            b"hello".to_vec(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, 204);
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].0["x-webhook-signature"], "sha256=abc");
+        assert_eq!(received[0].1, b"hello".to_vec());
+    }
+
+    #[tokio::test]
+    async fn post_returns_error_status() {
+        let (addr, _) = stand_in(StatusCode::SERVICE_UNAVAILABLE);
+        let status = super::post(&format!("http://{addr}/hook"), &[], vec![], vec![])
+            .await
+            .unwrap();
+        assert_eq!(status, 503);
+    }
+
+    #[tokio::test]
+    async fn post_to_pinned_address() {
+        let (addr, received) = stand_in(StatusCode::OK);
+        // the name does not resolve, the request can only reach the stand-in through `addrs`.
+        let url = format!("http://partner.invalid:{}/hook", addr.port());
+        let status = super::post(&url, &[addr], vec![], b"pinned".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(received.lock().unwrap()[0].1, b"pinned".to_vec());
+    }
+}
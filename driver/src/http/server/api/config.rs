@@ -1,7 +1,154 @@
+use crate::http::server::response::constants;
+use anyhow::bail;
+use axum::http::{HeaderName, HeaderValue, Method};
 use derive_new::new;
+use kernel::{unexpected, Result};
+use std::collections::HashSet;
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Clone, new)]
 pub struct Config {
     pub(crate) bind_address: SocketAddr,
+    /// `/metrics` is served on this listener only, so that it is kept off the public one.
+    /// it is not served at all when `None`.
+    pub(crate) metrics_bind_address: Option<SocketAddr>,
+    /// how long to wait for connections to drain after a shutdown signal.
+    pub(crate) shutdown_timeout: Duration,
+    pub(crate) csrf: Csrf,
+    pub(crate) tracking: Tracking,
+    /// no cors headers are sent when `None`.
+    pub(crate) cors: Option<Cors>,
+    /// plain http is served when `None`.
+    pub(crate) tls: Option<Tls>,
+}
+
+/// allow-lists for the csrf protection, matched exactly.
+#[derive(Clone, Debug)]
+pub struct Csrf {
+    pub(crate) allow_hosts: HashSet<String>,
+    pub(crate) allow_origins: HashSet<String>,
+    /// whole request paths, with the `/api/v1` prefix the api is mounted at.
+    pub(crate) exempt_paths: HashSet<String>,
+    /// accepts requests without `x-from` when they echo the csrf cookie in a header or form field.
+    pub(crate) double_submit: bool,
+}
+
+impl Csrf {
+    /// the status probed by monitoring, which sends no `x-from`.
+    pub const DEFAULT_EXEMPT_PATHS: &'static str = "/api/v1/status";
+
+    pub fn new(
+        allow_hosts: impl IntoIterator<Item = String>,
+        allow_origins: impl IntoIterator<Item = String>,
+        exempt_paths: impl IntoIterator<Item = String>,
+        double_submit: bool,
+    ) -> Csrf {
+        Csrf {
+            allow_hosts: allow_hosts
+                .into_iter()
+                .map(|host| host.trim().to_ascii_lowercase())
+                .filter(|host| !host.is_empty())
+                .collect(),
+            allow_origins: allow_origins
+                .into_iter()
+                .map(|origin| normalize_origin(&origin))
+                .filter(|origin| !origin.is_empty())
+                .collect(),
+            exempt_paths: exempt_paths
+                .into_iter()
+                .map(|path| path.trim().to_string())
+                .filter(|path| !path.is_empty())
+                .collect(),
+            double_submit,
+        }
+    }
+}
+
+/// the tracking cookie. with `require_consent`, it is only set once the client granted consent
+/// in the `consent` cookie or the `x-tracking-consent` header.
+#[derive(Clone, Debug, new)]
+pub struct Tracking {
+    pub(crate) cookie_name: String,
+    pub(crate) max_age: Duration,
+    /// a host-only cookie is set when `None`.
+    pub(crate) domain: Option<String>,
+    pub(crate) require_consent: bool,
+}
+
+impl Default for Tracking {
+    fn default() -> Self {
+        Tracking::new(
+            constants::TRACKING_COOKIE_ID.to_string(),
+            Duration::from_secs(86400 * 365),
+            None,
+            true,
+        )
+    }
+}
+
+/// origins are compared as `scheme://host[:port]`, the form browsers send.
+pub(crate) fn normalize_origin(origin: &str) -> String {
+    origin.trim().trim_end_matches('/').to_ascii_lowercase()
+}
+
+/// cross-origin access for a single page app served from another origin.
+/// its origin also has to be in the csrf origin allow-list, and `x-from` in `allow_headers`.
+/// the session cookie is `SameSite=Strict`, so the app has to be on the same site as the api.
+#[derive(Clone, Debug)]
+pub struct Cors {
+    pub(crate) allow_origins: Vec<HeaderValue>,
+    pub(crate) allow_methods: Vec<Method>,
+    pub(crate) allow_headers: Vec<HeaderName>,
+    pub(crate) allow_credentials: bool,
+    pub(crate) max_age: Duration,
+}
+
+impl Cors {
+    pub fn new(
+        allow_origins: impl IntoIterator<Item = String>,
+        allow_methods: impl IntoIterator<Item = String>,
+        allow_headers: impl IntoIterator<Item = String>,
+        allow_credentials: bool,
+        max_age: Duration,
+    ) -> Result<Cors> {
+        let allow_origins: Vec<String> = allow_origins
+            .into_iter()
+            .map(|origin| normalize_origin(&origin))
+            .collect();
+        // browsers refuse credentials with a wildcard, and tower_http panics on it in a list.
+        if allow_origins.iter().any(|origin| origin == "*") {
+            bail!(unexpected!(
+                "cors origins must be listed explicitly, not as `*`"
+            ));
+        }
+        Ok(Cors {
+            allow_origins: allow_origins
+                .into_iter()
+                .map(|origin| Ok(origin.parse()?))
+                .collect::<Result<_>>()?,
+            allow_methods: allow_methods
+                .into_iter()
+                .map(|method| Ok(method.trim().to_ascii_uppercase().parse()?))
+                .collect::<Result<_>>()?,
+            allow_headers: allow_headers
+                .into_iter()
+                .map(|header| Ok(header.trim().parse()?))
+                .collect::<Result<_>>()?,
+            allow_credentials,
+            max_age,
+        })
+    }
+}
+
+/// pem files for rustls termination. the files are watched and reloaded when they change,
+/// so that renewed certificates are served without a restart.
+#[derive(Clone, Debug, new)]
+pub struct Tls {
+    pub(crate) cert_path: PathBuf,
+    pub(crate) key_path: PathBuf,
+    /// requires and verifies client certificates signed by these CAs when set.
+    pub(crate) client_ca_path: Option<PathBuf>,
+    pub(crate) reload_interval: Duration,
 }
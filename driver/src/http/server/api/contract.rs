@@ -0,0 +1,450 @@
+//! calls every operation in `openapi/api/openapi.yaml` on the real router, with use cases answering
+//! fixed outputs, and checks the status codes and bodies against the documented responses.
+
+use crate::fixture::{account, fixtures, message};
+use crate::http::server::api::config::{Config, Csrf, Tracking};
+use crate::http::server::api::handler::openapi::SPEC;
+use crate::http::server::api::presenter::openapi::OpenAPIServerPresenter;
+use crate::http::server::api::route::define_route;
+use crate::UsePresenter;
+use application::interface::config::{Auth, ConfigBuilder, RateLimit, System};
+use application::interface::gateway::health::Dependency;
+use application::interface::gateway::rate_limit::{Bucket, RateLimitGateway, UseRateLimitGateway};
+use application::interface::{UseConfig, UseContext};
+use application::usecase::account::{
+    GetAccountInput, GetAccountOutput, UpdateLocaleInput, UpdateLocaleOutput,
+};
+use application::usecase::analytics::{RecordAnalyticsEventInput, RecordAnalyticsEventOutput};
+use application::usecase::auth::{
+    ForgetPasswordInput, ForgetPasswordOutput, GetAuthStatusInput, GetAuthStatusOutput,
+    ResetPasswordInput, ResetPasswordOutput, SignInInput, SignInOutput, SignOutInput,
+    SignOutOutput, SignUpFinishInput, SignUpFinishOutput, SignUpInput, SignUpOutput,
+};
+use application::usecase::channel::{
+    AddModeratorInput, AddModeratorOutput, GetUnreadCountsInput, GetUnreadCountsOutput,
+    MarkReadInput, MarkReadOutput, PubSubInput, PubSubOutput, PublishInput, PublishOutput,
+    RemoveModeratorInput, RemoveModeratorOutput, ResubscribeInput, SubscribeInput, SubscribeOutput,
+};
+use application::usecase::idempotency::{
+    BeginIdempotencyInput, BeginIdempotencyOutput, CompleteIdempotencyInput,
+    CompleteIdempotencyOutput, IdempotencyState,
+};
+use application::usecase::message::{
+    AddReactionInput, AddReactionOutput, DeleteMessageInput, DeleteMessageOutput, EditMessageInput,
+    EditMessageOutput, GetThreadInput, GetThreadOutput, ListMessageEditsInput,
+    ListMessageEditsOutput, RemoveReactionInput, RemoveReactionOutput,
+};
+use application::usecase::rate_limit::{RateLimitInput, RateLimitOutput, RateLimitUseCase};
+use application::usecase::session::{GetSessionInput, GetSessionOutput};
+use application::usecase::status::{
+    DependencyCheck, LivenessInput, LivenessOutput, ReadinessInput, ReadinessOutput, StatusInput,
+    StatusOutput,
+};
+use application::usecase::webhook::{
+    CreateWebhookInput, CreateWebhookOutput, DeleteWebhookInput, DeleteWebhookOutput,
+    ListWebhookDeliveriesInput, ListWebhookDeliveriesOutput, ListWebhooksInput, ListWebhooksOutput,
+};
+use application::usecase::UseUseCase;
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::header::{CONTENT_TYPE, COOKIE, HOST};
+use axum::http::Request;
+use axum::Server;
+use chrono::Utc;
+use jsonschema::JSONSchema;
+use kernel::entity::{
+    ChannelModerator, MessageEdit, ReactionCount, ReadPosition, Session, UnreadCount, Webhook,
+    WebhookDelivery, WebhookDeliveryStatus,
+};
+use kernel::Result;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tower::ServiceExt;
+
+static METHODS: &[&str] = &["get", "put", "post", "delete", "patch"];
+
+/// answers fixed outputs, except for the rate limit that runs the real use case on `rate_limit`
+/// with buckets kept in memory. no rule applies by default.
+#[derive(Clone, Default)]
+pub(crate) struct TestMods {
+    rate_limit: RateLimit,
+    buckets: MemoryRateLimitGateway,
+}
+
+impl TestMods {
+    pub(crate) fn with_rate_limit(rate_limit: RateLimit) -> TestMods {
+        TestMods {
+            rate_limit,
+            buckets: MemoryRateLimitGateway::default(),
+        }
+    }
+}
+
+/// a bucket per key that is never refilled, so that a test can empty it.
+#[derive(Clone, Default)]
+pub(crate) struct MemoryRateLimitGateway(Arc<Mutex<HashMap<String, u32>>>);
+
+#[async_trait]
+impl RateLimitGateway<()> for MemoryRateLimitGateway {
+    async fn acquire(&self, _: (), key: String, capacity: u32, _: f64) -> Result<Bucket> {
+        let mut buckets = self.0.lock().unwrap();
+        let taken = buckets.entry(key).or_default();
+        let allowed = *taken < capacity;
+        if allowed {
+            *taken += 1;
+        }
+        Ok(Bucket::new(
+            allowed,
+            capacity - *taken,
+            Duration::from_millis(1500),
+            Duration::from_secs(*taken as u64),
+        ))
+    }
+}
+
+#[async_trait]
+impl UseContext for TestMods {
+    type Context = ();
+    async fn context(&self) -> Result<Self::Context> {
+        Ok(())
+    }
+}
+
+impl UseConfig for TestMods {
+    fn config(&self) -> application::interface::config::Config {
+        ConfigBuilder::default()
+            .auth(Auth::default())
+            .system(System::default())
+            .rate_limit(self.rate_limit.clone())
+            .build()
+            .unwrap()
+    }
+}
+
+impl UseRateLimitGateway<()> for TestMods {
+    type Gateway = MemoryRateLimitGateway;
+    fn rate_limit_gateway(&self) -> Self::Gateway {
+        self.buckets.clone()
+    }
+}
+
+impl UseUseCase<RateLimitInput, RateLimitOutput> for TestMods {
+    type UseCase = RateLimitUseCase<(), TestMods>;
+    fn usecase(&self) -> Self::UseCase {
+        RateLimitUseCase::new(self.clone())
+    }
+}
+
+impl UsePresenter for TestMods {
+    type Presenter = OpenAPIServerPresenter;
+    fn presenter(&self) -> Self::Presenter {
+        OpenAPIServerPresenter
+    }
+}
+
+fixtures! {
+    TestMods {
+        StatusInput => StatusOutput = || {
+            Ok(StatusOutput::new(Some("1.0.0".to_string()), "2023-04-01T00:00:00Z".to_string()))
+        };
+        LivenessInput => LivenessOutput = || Ok(LivenessOutput);
+        ReadinessInput => ReadinessOutput = || {
+            Ok(ReadinessOutput::new(vec![DependencyCheck::new(
+                Dependency::Database,
+                "mysql".to_string(),
+                Duration::from_millis(1),
+                None,
+            )]))
+        };
+        GetAccountInput => GetAccountOutput = || Ok(GetAccountOutput::new(Some(account())));
+        UpdateLocaleInput => UpdateLocaleOutput = || Ok(UpdateLocaleOutput::new(account()));
+        GetAuthStatusInput => GetAuthStatusOutput = || Ok(GetAuthStatusOutput);
+        SignUpInput => SignUpOutput = || Ok(SignUpOutput);
+        SignUpFinishInput => SignUpFinishOutput = || Ok(SignUpFinishOutput::new("sid".to_string()));
+        SignInInput => SignInOutput = || Ok(SignInOutput::new("sid".to_string(), true));
+        SignOutInput => SignOutOutput = || Ok(SignOutOutput);
+        ForgetPasswordInput => ForgetPasswordOutput = || Ok(ForgetPasswordOutput);
+        ResetPasswordInput => ResetPasswordOutput = || Ok(ResetPasswordOutput);
+        PublishInput => PublishOutput = || Ok(PublishOutput::new(message()));
+        // the sender is dropped, so streams end right away.
+        SubscribeInput => SubscribeOutput = || {
+            Ok(SubscribeOutput::new(tokio::sync::mpsc::channel(1).1))
+        };
+        ResubscribeInput => SubscribeOutput = || {
+            Ok(SubscribeOutput::new(tokio::sync::mpsc::channel(1).1))
+        };
+        PubSubInput => PubSubOutput = || Ok(PubSubOutput::new(tokio::sync::mpsc::channel(1).1));
+        MarkReadInput => MarkReadOutput = || {
+            Ok(MarkReadOutput::new(ReadPosition::new(
+                "channel".to_string(),
+                "account".to_string(),
+                "message".to_string(),
+                Utc::now(),
+                Utc::now(),
+            )))
+        };
+        GetUnreadCountsInput => GetUnreadCountsOutput = || {
+            Ok(GetUnreadCountsOutput::new(vec![
+                UnreadCount::new("channel".to_string(), Some("message".to_string()), 1),
+                UnreadCount::new("unread".to_string(), None, 2),
+            ]))
+        };
+        AddModeratorInput => AddModeratorOutput = || Ok(AddModeratorOutput::new(moderators()));
+        RemoveModeratorInput => RemoveModeratorOutput = || {
+            Ok(RemoveModeratorOutput::new(moderators()))
+        };
+        GetSessionInput => GetSessionOutput = || {
+            Ok(GetSessionOutput::new(Some(Session::new(
+                "sid".to_string(),
+                account(),
+                Utc::now(),
+            ))))
+        };
+        BeginIdempotencyInput => BeginIdempotencyOutput = || {
+            Ok(BeginIdempotencyOutput::new("key".to_string(), IdempotencyState::Started))
+        };
+        CompleteIdempotencyInput => CompleteIdempotencyOutput = || Ok(CompleteIdempotencyOutput);
+        EditMessageInput => EditMessageOutput = || Ok(EditMessageOutput::new(message()));
+        DeleteMessageInput => DeleteMessageOutput = || Ok(DeleteMessageOutput);
+        ListMessageEditsInput => ListMessageEditsOutput = || {
+            Ok(ListMessageEditsOutput::new(vec![MessageEdit::new(
+                "edit".to_string(),
+                "message".to_string(),
+                "body".to_string(),
+                "account".to_string(),
+                Utc::now(),
+            )]))
+        };
+        GetThreadInput => GetThreadOutput = || Ok(GetThreadOutput::new(message(), vec![message()]));
+        AddReactionInput => AddReactionOutput = || Ok(AddReactionOutput::new(reactions()));
+        RemoveReactionInput => RemoveReactionOutput = || Ok(RemoveReactionOutput::new(reactions()));
+        CreateWebhookInput => CreateWebhookOutput = || Ok(CreateWebhookOutput::new(webhook()));
+        ListWebhooksInput => ListWebhooksOutput = || Ok(ListWebhooksOutput::new(vec![webhook()]));
+        DeleteWebhookInput => DeleteWebhookOutput = || Ok(DeleteWebhookOutput);
+        ListWebhookDeliveriesInput => ListWebhookDeliveriesOutput = || {
+            Ok(ListWebhookDeliveriesOutput::new(vec![WebhookDelivery::new(
+                "delivery".to_string(),
+                "webhook".to_string(),
+                "channel".to_string(),
+                vec![],
+                WebhookDeliveryStatus::Succeeded,
+                1,
+                Some(200),
+                None,
+                Utc::now(),
+                Utc::now(),
+            )]))
+        };
+        RecordAnalyticsEventInput => RecordAnalyticsEventOutput = || {
+            Ok(RecordAnalyticsEventOutput::new(true))
+        };
+    }
+}
+
+fn moderators() -> Vec<ChannelModerator> {
+    vec![ChannelModerator::new(
+        "channel".to_string(),
+        "account".to_string(),
+        Utc::now(),
+    )]
+}
+
+fn reactions() -> Vec<ReactionCount> {
+    vec![ReactionCount::new("+1".to_string(), 1)]
+}
+
+fn webhook() -> Webhook {
+    Webhook::new(
+        "webhook".to_string(),
+        "channel".to_string(),
+        "account".to_string(),
+        "https://example.com/hook".to_string(),
+        "secret".to_string(),
+        Utc::now(),
+    )
+}
+
+/// serves `localhost` over plain http with the given csrf protection.
+pub(crate) fn config(csrf: Csrf) -> Config {
+    Config::new(
+        "127.0.0.1:0".parse().unwrap(),
+        None,
+        Duration::from_secs(1),
+        csrf,
+        Tracking::default(),
+        None,
+        None,
+    )
+}
+
+#[tokio::test]
+async fn router_conforms_to_spec() {
+    let spec: Value = serde_yaml::from_str(SPEC).unwrap();
+    let config = config(Csrf::new(
+        vec!["localhost".to_string()],
+        vec![],
+        vec![],
+        false,
+    ));
+    let mut failures = vec![];
+    let mut operations = 0;
+    for (path, item) in spec["paths"].as_object().unwrap() {
+        for method in METHODS {
+            let Some(operation) = item.get(*method) else {
+                continue;
+            };
+            operations += 1;
+            let name = format!("{} {}", method.to_uppercase(), path);
+            let result = if operation["responses"].get("101").is_some() {
+                upgrade(&config, path).await
+            } else {
+                call(&spec, &config, path, method, operation).await
+            };
+            if let Err(e) = result {
+                failures.push(format!("{name}: {e}"));
+            }
+        }
+    }
+    assert!(operations > 0);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+async fn call(
+    spec: &Value,
+    config: &Config,
+    path: &str,
+    method: &str,
+    operation: &Value,
+) -> std::result::Result<(), String> {
+    let mut request = Request::builder()
+        .method(method.to_uppercase().as_str())
+        .uri(uri(path))
+        .header(HOST, "localhost")
+        .header("x-from", "test")
+        .header(COOKIE, "sid=test");
+    let body = match operation["requestBody"]["content"]["application/json"].get("schema") {
+        Some(schema) => {
+            request = request.header(CONTENT_TYPE, "application/json");
+            Body::from(sample(spec, schema).to_string())
+        }
+        None => Body::empty(),
+    };
+    let response = define_route(config, TestMods::default())
+        .oneshot(request.body(body).unwrap())
+        .await
+        .unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<mime::Mime>().ok())
+        .map(|value| value.essence_str().to_string());
+    let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+    let body = String::from_utf8_lossy(&body);
+    let responses = &operation["responses"];
+    let Some(documented) = responses.get(status.as_str()) else {
+        return Err(format!("{status} is not documented: {body}"));
+    };
+    if !status.is_success() {
+        return Err(format!("{status}: {body}"));
+    }
+    let Some(content) = documented.get("content") else {
+        return Ok(());
+    };
+    let Some(content_type) = content_type else {
+        return Err("no content type".to_string());
+    };
+    let Some(media) = content.get(&content_type) else {
+        return Err(format!("{content_type} is not documented"));
+    };
+    if !content_type.ends_with("json") {
+        return Ok(());
+    }
+    let instance: Value = serde_json::from_str(&body).map_err(|e| format!("{e}: {body}"))?;
+    // references in the schema are resolved against `components` next to it.
+    let schema = json!({"allOf": [media["schema"]], "components": spec["components"]});
+    let schema = JSONSchema::compile(&schema).map_err(|e| e.to_string())?;
+    if let Err(errors) = schema.validate(&instance) {
+        let errors = errors.map(|e| e.to_string()).collect::<Vec<_>>();
+        return Err(format!("{}: {body}", errors.join(", ")));
+    }
+    Ok(())
+}
+
+/// an upgrade needs a real connection, so the router is served on one and sent a websocket handshake.
+async fn upgrade(config: &Config, path: &str) -> std::result::Result<(), String> {
+    let app = define_route(config, TestMods::default())
+        .into_make_service_with_connect_info::<SocketAddr>();
+    let server = Server::bind(&"127.0.0.1:0".parse().unwrap()).serve(app);
+    let address = server.local_addr();
+    let server = tokio::spawn(server);
+    let mut stream = TcpStream::connect(address)
+        .await
+        .map_err(|e| e.to_string())?;
+    let handshake = format!(
+        "GET {} HTTP/1.1\r\nhost: localhost\r\nx-from: test\r\ncookie: sid=test\r\n\
+         connection: upgrade\r\nupgrade: websocket\r\nsec-websocket-version: 13\r\n\
+         sec-websocket-key: dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n",
+        uri(path)
+    );
+    stream
+        .write_all(handshake.as_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut status_line = String::new();
+    BufReader::new(stream)
+        .read_line(&mut status_line)
+        .await
+        .map_err(|e| e.to_string())?;
+    server.abort();
+    match status_line.split(' ').nth(1) {
+        Some("101") => Ok(()),
+        _ => Err(format!("not upgraded: {}", status_line.trim_end())),
+    }
+}
+
+/// the path with its parameters filled in.
+fn uri(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment.starts_with('{') {
+                "test"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// the example of the schema, or a value with only the required properties.
+fn sample(spec: &Value, schema: &Value) -> Value {
+    if let Some(reference) = schema["$ref"].as_str() {
+        let resolved = spec.pointer(reference.trim_start_matches('#'));
+        return sample(spec, resolved.unwrap_or(&Value::Null));
+    }
+    if let Some(example) = schema.get("example") {
+        return example.clone();
+    }
+    match schema["type"].as_str() {
+        Some("object") => Value::Object(
+            schema["required"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(|key| (key.to_string(), sample(spec, &schema["properties"][key])))
+                .collect(),
+        ),
+        Some("array") => json!([]),
+        Some("integer") | Some("number") => json!(0),
+        Some("boolean") => json!(false),
+        _ => json!("test"),
+    }
+}
@@ -1,15 +1,19 @@
 use crate::dispatch;
 use crate::http::server::api::{Mods, Presenter};
+use crate::http::server::middleware::locale::RequestLocale;
 use crate::http::server::middleware::session::RequireSession;
-use application::usecase::account::GetAccountInput;
+use application::usecase::account::{GetAccountInput, UpdateLocaleInput};
 use axum::extract::{Path, State};
 use axum::response::Response;
-use axum::routing::get;
-use axum::Router;
+use axum::routing::{get, put};
+use axum::{Json, Router};
 use kernel::Result;
+use openapi::models::UpdateLocaleRequest;
 
 pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
-    Router::new().route("/account/:id", get(get_account::<M, P>))
+    Router::new()
+        .route("/account/:id", get(get_account::<M, P>))
+        .route("/account/me/locale", put(update_locale::<M, P>))
 }
 
 async fn get_account<M: Mods<P>, P: Presenter>(
@@ -19,3 +23,18 @@ async fn get_account<M: Mods<P>, P: Presenter>(
 ) -> Result<Response, ()> {
     dispatch(GetAccountInput::new(id, Some(session.id)), mods).await
 }
+
+async fn update_locale<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    RequireSession(session): RequireSession,
+    RequestLocale(locale): RequestLocale,
+    Json(UpdateLocaleRequest {
+        locale: account_locale,
+    }): Json<UpdateLocaleRequest>,
+) -> Result<Response, ()> {
+    dispatch(
+        UpdateLocaleInput::new(session.id, account_locale, locale),
+        mods,
+    )
+    .await
+}
@@ -0,0 +1,28 @@
+use crate::dispatch;
+use crate::http::server::api::{Mods, Presenter};
+use crate::http::server::middleware::tracking::Tracking;
+use application::usecase::analytics::RecordAnalyticsEventInput;
+use axum::extract::State;
+use axum::response::Response;
+use axum::routing::post;
+use axum::{Json, Router};
+use kernel::Result;
+use openapi::models::AnalyticsEventRequest;
+
+pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
+    Router::new().route("/analytics/events", post(record_analytics_event::<M, P>))
+}
+
+/// accepted without a tracking id as well, the event is dropped when the client did not consent.
+async fn record_analytics_event<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    tracking: Option<Tracking>,
+    Json(AnalyticsEventRequest { name, properties }): Json<AnalyticsEventRequest>,
+) -> Result<Response, ()> {
+    let input = RecordAnalyticsEventInput::new(
+        tracking.map(|Tracking(tracking)| tracking.id),
+        name,
+        properties.unwrap_or_default(),
+    );
+    dispatch(input, mods).await
+}
@@ -1,5 +1,7 @@
 use crate::dispatch;
 use crate::http::server::api::{Mods, Presenter};
+use crate::http::server::middleware::idempotency::IdempotencyLayer;
+use crate::http::server::middleware::locale::RequestLocale;
 use crate::http::server::middleware::session::{ExtractSession, RequireSession};
 use application::usecase::auth::{
     ForgetPasswordInput, GetAuthStatusInput, ResetPasswordInput, SignInInput, SignOutInput,
@@ -14,48 +16,58 @@ use openapi::models::{
     ForgetPasswordRequest, ResetPasswordRequest, SignUpFinishRequest, SignUpRequest, SigninRequest,
 };
 
-pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
+pub(crate) fn route<M: Mods<P>, P: Presenter>(mods: M) -> Router<M> {
     Router::new()
         .route("/auth/status", get(auth_status::<M, P>))
-        .route("/auth/signup", post(signup::<M, P>))
+        .route(
+            "/auth/signup",
+            post(signup::<M, P>).layer(IdempotencyLayer::new(mods.clone())),
+        )
         .route("/auth/signup/finish", post(signup_finish::<M, P>))
         .route("/auth/signin", post(signin::<M, P>))
         .route("/auth/signout", post(signout::<M, P>))
-        .route("/auth/forget_password", post(forget_password::<M, P>))
+        .route(
+            "/auth/forget_password",
+            post(forget_password::<M, P>).layer(IdempotencyLayer::new(mods)),
+        )
         .route("/auth/reset_password", post(reset_password::<M, P>))
 }
 
 async fn auth_status<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
     ExtractSession(session): ExtractSession,
+    RequestLocale(locale): RequestLocale,
 ) -> Result<Response, ()> {
-    dispatch(GetAuthStatusInput::new(session), mods).await
+    dispatch(GetAuthStatusInput::new(session, locale), mods).await
 }
 
 async fn signup<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
     Host(host): Host,
+    RequestLocale(locale): RequestLocale,
     Json(SignUpRequest { mail, password }): Json<SignUpRequest>,
 ) -> Result<Response, ()> {
-    dispatch(SignUpInput::new(mail, password, host), mods).await
+    dispatch(SignUpInput::new(mail, password, host, locale), mods).await
 }
 
 async fn signup_finish<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
+    RequestLocale(locale): RequestLocale,
     Json(SignUpFinishRequest { code }): Json<SignUpFinishRequest>,
 ) -> Result<Response, ()> {
-    dispatch(SignUpFinishInput::new(code), mods).await
+    dispatch(SignUpFinishInput::new(code, locale), mods).await
 }
 
 async fn signin<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
+    RequestLocale(locale): RequestLocale,
     Json(SigninRequest {
         mail,
         password,
         remember_me,
     }): Json<SigninRequest>,
 ) -> Result<Response, ()> {
-    dispatch(SignInInput::new(mail, password, remember_me), mods).await
+    dispatch(SignInInput::new(mail, password, remember_me, locale), mods).await
 }
 
 async fn signout<M: Mods<P>, P: Presenter>(
@@ -68,14 +80,16 @@ async fn signout<M: Mods<P>, P: Presenter>(
 async fn forget_password<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
     Host(host): Host,
+    RequestLocale(locale): RequestLocale,
     Json(ForgetPasswordRequest { mail }): Json<ForgetPasswordRequest>,
 ) -> Result<Response, ()> {
-    dispatch(ForgetPasswordInput::new(mail, host), mods).await
+    dispatch(ForgetPasswordInput::new(mail, host, locale), mods).await
 }
 
 async fn reset_password<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
+    RequestLocale(locale): RequestLocale,
     Json(ResetPasswordRequest { code, password }): Json<ResetPasswordRequest>,
 ) -> Result<Response, ()> {
-    dispatch(ResetPasswordInput::new(code, password), mods).await
+    dispatch(ResetPasswordInput::new(code, password, locale), mods).await
 }
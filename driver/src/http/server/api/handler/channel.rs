@@ -1,59 +1,142 @@
 use crate::http::server::api::{Mods, Presenter};
-use crate::http::server::middleware::session::RequireSession;
+use crate::http::server::middleware::idempotency::IdempotencyLayer;
+use crate::http::server::middleware::session::{watch_session, RequireSession};
+use crate::http::server::shutdown;
+use crate::metrics::{GaugeGuard, WEBSOCKET_CONNECTIONS};
 use crate::{dispatch, dispatch_with};
-use application::usecase::channel::{PubSubInput, PublishInput, SubscribeInput};
-use axum::extract::ws::{Message, WebSocket};
+use application::usecase::channel::{
+    AddModeratorInput, GetUnreadCountsInput, MarkReadInput, PubSubInput, PublishInput,
+    RemoveModeratorInput, ResubscribeInput, SubscribeInput,
+};
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket};
 use axum::extract::{Path, State, WebSocketUpgrade};
+use axum::http::HeaderMap;
 use axum::response::Response;
-use axum::routing::{get, post};
-use axum::Router;
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
 use futures::{SinkExt, StreamExt};
 use helper::uuid;
 use helper::uuid::ToBase62;
 use kernel::Result;
 use log;
+use openapi::models::MarkReadRequest;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc::channel;
 use tokio::sync::Mutex;
 use tokio_stream::wrappers::ReceiverStream;
 
-pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
+/// how soon a revoked or expired session ends its event stream.
+const SESSION_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+/// sent by browsers reconnecting an event stream, with the id of the last event received.
+const LAST_EVENT_ID: &str = "last-event-id";
+
+pub(crate) fn route<M: Mods<P>, P: Presenter>(mods: M) -> Router<M> {
     Router::new()
         .route("/channel/:channel_id", get(subscribe_channel::<M, P>))
-        .route("/channel/:channel_id", post(publish_channel::<M, P>))
+        .route(
+            "/channel/:channel_id",
+            post(publish_channel::<M, P>).layer(IdempotencyLayer::new(mods)),
+        )
         .route("/channel/:channel_id/socket", get(channel_socket::<M, P>))
+        .route("/channel/:channel_id/read", put(mark_read::<M, P>))
+        .route(
+            "/channel/:channel_id/moderators/:account_id",
+            put(add_moderator::<M, P>).delete(remove_moderator::<M, P>),
+        )
+        .route("/unread_counts", get(get_unread_counts::<M, P>))
 }
 
 async fn channel_socket<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
     Path(channel_id): Path<String>,
-    RequireSession(_): RequireSession,
+    RequireSession(session): RequireSession,
     ws: WebSocketUpgrade,
 ) -> Response {
+    let account_id = session.account.id;
     ws.protocols(["x-protocol"])
-        .on_upgrade(move |socket| async { handle_socket(mods, channel_id, socket).await })
+        .on_upgrade(move |socket| async {
+            handle_socket(mods, channel_id, account_id, socket).await
+        })
 }
 
 async fn subscribe_channel<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
     Path(channel_id): Path<String>,
-    RequireSession(_): RequireSession,
+    RequireSession(session): RequireSession,
+    headers: HeaderMap,
 ) -> Result<Response, ()> {
-    dispatch(SubscribeInput::new(channel_id), mods).await
+    let session_end = watch_session(mods.clone(), session.id, SESSION_CHECK_INTERVAL);
+    let account_id = Some(session.account.id);
+    match headers.get(LAST_EVENT_ID).and_then(|id| id.to_str().ok()) {
+        Some(last_message_id) => {
+            let input = ResubscribeInput::new(channel_id, account_id, last_message_id.to_string());
+            dispatch_with(input, session_end, mods).await
+        }
+        None => {
+            let input = SubscribeInput::new(channel_id, account_id);
+            dispatch_with(input, session_end, mods).await
+        }
+    }
 }
 
 async fn publish_channel<M: Mods<P>, P: Presenter>(
     State(mods): State<M>,
     Path(channel_id): Path<String>,
-    RequireSession(_): RequireSession,
+    RequireSession(session): RequireSession,
     message: String,
 ) -> Result<Response, ()> {
-    let input = PublishInput::new(channel_id, message.as_bytes().to_vec());
+    let input = PublishInput::new(
+        channel_id,
+        session.account.id,
+        None,
+        message.as_bytes().to_vec(),
+    );
     dispatch(input, mods).await
 }
 
-async fn handle_socket<M: Mods<P>, P: Presenter>(mods: M, channel_id: String, socket: WebSocket) {
+async fn mark_read<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path(channel_id): Path<String>,
+    RequireSession(session): RequireSession,
+    Json(MarkReadRequest { message_id }): Json<MarkReadRequest>,
+) -> Result<Response, ()> {
+    let input = MarkReadInput::new(session.account.id, channel_id, message_id);
+    dispatch(input, mods).await
+}
+
+async fn get_unread_counts<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    dispatch(GetUnreadCountsInput::new(session.account.id), mods).await
+}
+
+async fn add_moderator<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, account_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = AddModeratorInput::new(session.account.id, channel_id, account_id);
+    dispatch(input, mods).await
+}
+
+async fn remove_moderator<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, account_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = RemoveModeratorInput::new(session.account.id, channel_id, account_id);
+    dispatch(input, mods).await
+}
+
+async fn handle_socket<M: Mods<P>, P: Presenter>(
+    mods: M,
+    channel_id: String,
+    account_id: String,
+    socket: WebSocket,
+) {
+    let connection = GaugeGuard::new(&WEBSOCKET_CONNECTIONS);
     let (outbound, mut inbound) = socket.split();
     let outbound = Arc::new(Mutex::new(outbound));
     let (exchange_sender, receiver) = channel::<Vec<u8>>(1000);
@@ -62,13 +145,30 @@ async fn handle_socket<M: Mods<P>, P: Presenter>(mods: M, channel_id: String, so
     let cloned_outbound = outbound.clone();
     let cloned_ping_message = ping_message.clone();
     tokio::spawn(async move {
-        while let Some(msg) = inbound.next().await {
+        // the connection is open as long as it is read.
+        let _connection = connection;
+        loop {
+            let msg = tokio::select! {
+                msg = inbound.next() => msg,
+                _ = shutdown::started() => {
+                    // stop reading, the messages already received are still published.
+                    let close = Message::Close(Some(CloseFrame {
+                        code: close_code::AWAY,
+                        reason: "server is shutting down".into(),
+                    }));
+                    if let Err(err) = cloned_outbound.lock().await.send(close).await {
+                        log::debug!("send close error: {}", err);
+                    }
+                    break;
+                }
+            };
             let msg = match msg {
-                Ok(msg) => msg,
-                Err(err) => {
+                Some(Ok(msg)) => msg,
+                Some(Err(err)) => {
                     log::debug!("receive inbound message error: {}", err);
                     break;
                 }
+                None => break,
             };
             match msg {
                 Message::Text(msg) => {
@@ -125,7 +225,10 @@ async fn handle_socket<M: Mods<P>, P: Presenter>(mods: M, channel_id: String, so
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(Duration::from_millis(10000));
         loop {
-            interval.tick().await;
+            tokio::select! {
+                _ = interval.tick() => (),
+                _ = shutdown::started() => break,
+            }
             match cloned_outbound
                 .lock()
                 .await
@@ -140,6 +243,6 @@ async fn handle_socket<M: Mods<P>, P: Presenter>(mods: M, channel_id: String, so
             }
         }
     });
-    let input = PubSubInput::new(channel_id, receiver);
+    let input = PubSubInput::new(channel_id, account_id, receiver);
     dispatch_with(input, sender, mods).await;
 }
@@ -0,0 +1,23 @@
+use crate::dispatch;
+use crate::http::server::api::{Mods, Presenter};
+
+use application::usecase::status::{LivenessInput, ReadinessInput};
+use axum::extract::State;
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use kernel::Result;
+
+pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
+    Router::new()
+        .route("/health/live", get(get_liveness::<M, P>))
+        .route("/health/ready", get(get_readiness::<M, P>))
+}
+
+async fn get_liveness<M: Mods<P>, P: Presenter>(State(mods): State<M>) -> Result<Response, ()> {
+    dispatch(LivenessInput, mods).await
+}
+
+async fn get_readiness<M: Mods<P>, P: Presenter>(State(mods): State<M>) -> Result<Response, ()> {
+    dispatch(ReadinessInput, mods).await
+}
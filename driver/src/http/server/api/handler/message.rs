@@ -0,0 +1,112 @@
+use crate::dispatch;
+use crate::http::server::api::{Mods, Presenter};
+use crate::http::server::middleware::session::RequireSession;
+use application::usecase::channel::PublishInput;
+use application::usecase::message::{
+    AddReactionInput, DeleteMessageInput, EditMessageInput, GetThreadInput, ListMessageEditsInput,
+    RemoveReactionInput,
+};
+use axum::extract::{Path, State};
+use axum::response::Response;
+use axum::routing::{get, patch, post, put};
+use axum::{Json, Router};
+use kernel::Result;
+use openapi::models::EditMessageRequest;
+
+pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
+    Router::new()
+        .route(
+            "/channel/:channel_id/messages/:message_id",
+            patch(edit_message::<M, P>).delete(delete_message::<M, P>),
+        )
+        .route(
+            "/channel/:channel_id/messages/:message_id/edits",
+            get(list_message_edits::<M, P>),
+        )
+        .route(
+            "/channel/:channel_id/messages/:message_id/thread",
+            get(get_thread::<M, P>),
+        )
+        .route(
+            "/channel/:channel_id/messages/:message_id/replies",
+            post(reply_message::<M, P>),
+        )
+        .route(
+            "/channel/:channel_id/messages/:message_id/reactions/:emoji",
+            put(add_reaction::<M, P>).delete(remove_reaction::<M, P>),
+        )
+}
+
+async fn edit_message<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, message_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+    Json(EditMessageRequest { body }): Json<EditMessageRequest>,
+) -> Result<Response, ()> {
+    let input = EditMessageInput::new(
+        session.account.id,
+        channel_id,
+        message_id,
+        body.into_bytes(),
+    );
+    dispatch(input, mods).await
+}
+
+async fn delete_message<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, message_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = DeleteMessageInput::new(session.account.id, channel_id, message_id);
+    dispatch(input, mods).await
+}
+
+async fn list_message_edits<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, message_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = ListMessageEditsInput::new(session.account.id, channel_id, message_id);
+    dispatch(input, mods).await
+}
+
+async fn get_thread<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, message_id)): Path<(String, String)>,
+    RequireSession(_): RequireSession,
+) -> Result<Response, ()> {
+    dispatch(GetThreadInput::new(channel_id, message_id), mods).await
+}
+
+async fn reply_message<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, message_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+    message: String,
+) -> Result<Response, ()> {
+    let input = PublishInput::new(
+        channel_id,
+        session.account.id,
+        Some(message_id),
+        message.as_bytes().to_vec(),
+    );
+    dispatch(input, mods).await
+}
+
+async fn add_reaction<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, message_id, emoji)): Path<(String, String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = AddReactionInput::new(session.account.id, channel_id, message_id, emoji);
+    dispatch(input, mods).await
+}
+
+async fn remove_reaction<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, message_id, emoji)): Path<(String, String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = RemoveReactionInput::new(session.account.id, channel_id, message_id, emoji);
+    dispatch(input, mods).await
+}
@@ -0,0 +1,57 @@
+use crate::http::server::response::problem;
+use crate::metrics;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use kernel::error::Codes;
+
+/// served on its own internal listener rather than nested in the api.
+pub(crate) fn route() -> Router {
+    Router::new().route("/metrics", get(get_metrics))
+}
+
+async fn get_metrics() -> Response {
+    match metrics::render() {
+        Ok((content_type, body)) => ([(CONTENT_TYPE, content_type)], body).into_response(),
+        Err(e) => {
+            log::error!("render metrics error: {:?}", e);
+            problem(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                &Codes::CommonUnexpected,
+                None,
+                vec![],
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::route;
+    use crate::metrics::HTTP_REQUESTS;
+    use axum::body::Body;
+    use axum::http::header::CONTENT_TYPE;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn metrics_are_rendered_in_text_format() {
+        HTTP_REQUESTS
+            .with_label_values(&["GET", "/rendered", "200"])
+            .inc();
+        let request = Request::get("/metrics").body(Body::empty()).unwrap();
+        let response = route().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers()[CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .starts_with("text/plain"));
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(
+            body.contains(r#"http_requests_total{method="GET",route="/rendered",status="200"}"#)
+        );
+    }
+}
@@ -1,4 +1,10 @@
 pub(crate) mod account;
+pub(crate) mod analytics;
 pub(crate) mod auth;
 pub(crate) mod channel;
+pub(crate) mod health;
+pub(crate) mod message;
+pub(crate) mod metrics;
+pub(crate) mod openapi;
 pub(crate) mod status;
+pub(crate) mod webhook;
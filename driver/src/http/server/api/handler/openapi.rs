@@ -0,0 +1,62 @@
+use crate::http::server::api::{Mods, Presenter};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{Html, IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+
+/// the document the `openapi` crate is generated from, served as is.
+pub(crate) static SPEC: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../openapi/api/openapi.yaml"
+));
+
+/// swagger-ui-dist 5.17.14, vendored with its license so that the docs load without a cdn.
+static SWAGGER_UI_CSS: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/assets/swagger-ui/swagger-ui.css"
+));
+static SWAGGER_UI_BUNDLE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/assets/swagger-ui/swagger-ui-bundle.js"
+));
+
+static SWAGGER_UI: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>API reference</title>
+  <link rel="stylesheet" href="docs/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="docs/swagger-ui-bundle.js"></script>
+  <script>
+    window.ui = SwaggerUIBundle({ url: "openapi.yaml", dom_id: "#swagger-ui" });
+  </script>
+</body>
+</html>
+"##;
+
+pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
+    Router::new()
+        .route("/openapi.yaml", get(get_spec))
+        .route("/docs", get(get_docs))
+        .route("/docs/swagger-ui.css", get(get_docs_css))
+        .route("/docs/swagger-ui-bundle.js", get(get_docs_bundle))
+}
+
+async fn get_spec() -> Response {
+    ([(CONTENT_TYPE, "application/yaml")], SPEC).into_response()
+}
+
+async fn get_docs() -> Html<&'static str> {
+    Html(SWAGGER_UI)
+}
+
+async fn get_docs_css() -> Response {
+    ([(CONTENT_TYPE, "text/css")], SWAGGER_UI_CSS).into_response()
+}
+
+async fn get_docs_bundle() -> Response {
+    ([(CONTENT_TYPE, "text/javascript")], SWAGGER_UI_BUNDLE).into_response()
+}
@@ -0,0 +1,65 @@
+use crate::dispatch;
+use crate::http::server::api::{Mods, Presenter};
+use crate::http::server::middleware::session::RequireSession;
+use application::usecase::webhook::{
+    CreateWebhookInput, DeleteWebhookInput, ListWebhookDeliveriesInput, ListWebhooksInput,
+};
+use axum::extract::{Path, State};
+use axum::response::Response;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use kernel::Result;
+use openapi::models::CreateWebhookRequest;
+
+pub(crate) fn route<M: Mods<P>, P: Presenter>(_: M) -> Router<M> {
+    Router::new()
+        .route("/channel/:channel_id/webhooks", get(list_webhooks::<M, P>))
+        .route(
+            "/channel/:channel_id/webhooks",
+            post(create_webhook::<M, P>),
+        )
+        .route(
+            "/channel/:channel_id/webhooks/:webhook_id",
+            delete(delete_webhook::<M, P>),
+        )
+        .route(
+            "/channel/:channel_id/webhooks/:webhook_id/deliveries",
+            get(list_webhook_deliveries::<M, P>),
+        )
+}
+
+async fn list_webhooks<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path(channel_id): Path<String>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    dispatch(ListWebhooksInput::new(session.account.id, channel_id), mods).await
+}
+
+async fn create_webhook<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path(channel_id): Path<String>,
+    RequireSession(session): RequireSession,
+    Json(CreateWebhookRequest { url }): Json<CreateWebhookRequest>,
+) -> Result<Response, ()> {
+    let input = CreateWebhookInput::new(session.account.id, channel_id, url);
+    dispatch(input, mods).await
+}
+
+async fn delete_webhook<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, webhook_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = DeleteWebhookInput::new(session.account.id, channel_id, webhook_id);
+    dispatch(input, mods).await
+}
+
+async fn list_webhook_deliveries<M: Mods<P>, P: Presenter>(
+    State(mods): State<M>,
+    Path((channel_id, webhook_id)): Path<(String, String)>,
+    RequireSession(session): RequireSession,
+) -> Result<Response, ()> {
+    let input = ListWebhookDeliveriesInput::new(session.account.id, channel_id, webhook_id);
+    dispatch(input, mods).await
+}
@@ -1,19 +1,43 @@
 use crate::http::server::api::config::Config;
+use crate::http::server::api::handler::metrics;
 use crate::http::server::api::route::define_route;
+use crate::http::server::middleware::session::SessionEnd;
+use crate::http::server::{shutdown, tls};
 use crate::{Present, UsePresenter};
 use anyhow::Context as _;
 use application::interface::Component;
-use application::usecase::account::{GetAccountInput, GetAccountOutput};
+use application::usecase::account::{
+    GetAccountInput, GetAccountOutput, UpdateLocaleInput, UpdateLocaleOutput,
+};
+use application::usecase::analytics::{RecordAnalyticsEventInput, RecordAnalyticsEventOutput};
 use application::usecase::auth::{
     ForgetPasswordInput, ForgetPasswordOutput, GetAuthStatusInput, GetAuthStatusOutput,
     ResetPasswordInput, ResetPasswordOutput, SignInInput, SignInOutput, SignOutInput,
     SignOutOutput, SignUpFinishInput, SignUpFinishOutput, SignUpInput, SignUpOutput,
 };
 use application::usecase::channel::{
-    PubSubInput, PubSubOutput, PublishInput, PublishOutput, SubscribeInput, SubscribeOutput,
+    AddModeratorInput, AddModeratorOutput, GetUnreadCountsInput, GetUnreadCountsOutput,
+    MarkReadInput, MarkReadOutput, PubSubInput, PubSubOutput, PublishInput, PublishOutput,
+    RemoveModeratorInput, RemoveModeratorOutput, ResubscribeInput, SubscribeInput, SubscribeOutput,
+};
+use application::usecase::idempotency::{
+    BeginIdempotencyInput, BeginIdempotencyOutput, CompleteIdempotencyInput,
+    CompleteIdempotencyOutput,
+};
+use application::usecase::message::{
+    AddReactionInput, AddReactionOutput, DeleteMessageInput, DeleteMessageOutput, EditMessageInput,
+    EditMessageOutput, GetThreadInput, GetThreadOutput, ListMessageEditsInput,
+    ListMessageEditsOutput, RemoveReactionInput, RemoveReactionOutput,
 };
+use application::usecase::rate_limit::{RateLimitInput, RateLimitOutput};
 use application::usecase::session::{GetSessionInput, GetSessionOutput};
-use application::usecase::status::{StatusInput, StatusOutput};
+use application::usecase::status::{
+    LivenessInput, LivenessOutput, ReadinessInput, ReadinessOutput, StatusInput, StatusOutput,
+};
+use application::usecase::webhook::{
+    CreateWebhookInput, CreateWebhookOutput, DeleteWebhookInput, DeleteWebhookOutput,
+    ListWebhookDeliveriesInput, ListWebhookDeliveriesOutput, ListWebhooksInput, ListWebhooksOutput,
+};
 use application::usecase::UseUseCase;
 use axum::response::Response;
 use axum::Server;
@@ -23,22 +47,71 @@ use tokio::sync::mpsc::Sender;
 use trait_set::trait_set;
 
 pub mod config;
+#[cfg(test)]
+pub(crate) mod contract;
 mod handler;
 pub mod presenter;
-mod route;
+pub(crate) mod route;
 
+/// serves until SIGTERM or SIGINT, then drains connections for up to `shutdown_timeout`.
+/// https is served when `tls` is configured, both speak HTTP/2 as well as HTTP/1.1.
+/// `/metrics` is served over plain http on its own listener when `metrics_bind_address` is set.
 pub async fn start<M: Mods<P>, P: Presenter>(config: Config, mods: M) -> Result<()> {
-    let app = define_route(mods);
-    Server::bind(&config.bind_address)
-        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
-        .await
-        .with_context(|| unexpected!("server error"))
+    tokio::spawn(shutdown::signal());
+    serve(config, mods).await
+}
+
+/// serves until the shutdown starts, then drains connections for up to `shutdown_timeout`.
+async fn serve<M: Mods<P>, P: Presenter>(config: Config, mods: M) -> Result<()> {
+    let app = define_route(&config, mods).into_make_service_with_connect_info::<SocketAddr>();
+    let server = async {
+        match &config.tls {
+            Some(tls) => tls::serve(config.bind_address, tls, app).await,
+            None => Server::bind(&config.bind_address)
+                .serve(app)
+                .with_graceful_shutdown(shutdown::started())
+                .await
+                .with_context(|| unexpected!("server error")),
+        }
+    };
+    let metrics = async {
+        match config.metrics_bind_address {
+            Some(address) => Server::bind(&address)
+                .serve(metrics::route().into_make_service())
+                .with_graceful_shutdown(shutdown::started())
+                .await
+                .with_context(|| unexpected!("metrics server error")),
+            None => Ok(()),
+        }
+    };
+    let graceful = async {
+        tokio::try_join!(server, metrics)?;
+        shutdown::drained().await;
+        Ok(())
+    };
+    let deadline = async {
+        shutdown::started().await;
+        tokio::time::sleep(config.shutdown_timeout).await;
+    };
+    tokio::select! {
+        res = graceful => {
+            log::info!("server is shut down.");
+            res
+        }
+        _ = deadline => {
+            log::warn!("shutdown timeout {:?} exceeded.", config.shutdown_timeout);
+            Ok(())
+        }
+    }
 }
 
 trait_set! {
     pub trait Mods<P: Presenter> = Component
     + UseUseCase<StatusInput, StatusOutput>
+    + UseUseCase<LivenessInput, LivenessOutput>
+    + UseUseCase<ReadinessInput, ReadinessOutput>
     + UseUseCase<GetAccountInput, GetAccountOutput>
+    + UseUseCase<UpdateLocaleInput, UpdateLocaleOutput>
     + UseUseCase<GetAuthStatusInput, GetAuthStatusOutput>
     + UseUseCase<SignUpInput, SignUpOutput>
     + UseUseCase<SignUpFinishInput, SignUpFinishOutput>
@@ -48,14 +121,36 @@ trait_set! {
     + UseUseCase<ResetPasswordInput, ResetPasswordOutput>
     + UseUseCase<PublishInput, PublishOutput>
     + UseUseCase<SubscribeInput, SubscribeOutput>
+    + UseUseCase<ResubscribeInput, SubscribeOutput>
     + UseUseCase<PubSubInput, PubSubOutput>
+    + UseUseCase<MarkReadInput, MarkReadOutput>
+    + UseUseCase<GetUnreadCountsInput, GetUnreadCountsOutput>
+    + UseUseCase<AddModeratorInput, AddModeratorOutput>
+    + UseUseCase<RemoveModeratorInput, RemoveModeratorOutput>
     + UseUseCase<GetSessionInput, GetSessionOutput>
+    + UseUseCase<RateLimitInput, RateLimitOutput>
+    + UseUseCase<BeginIdempotencyInput, BeginIdempotencyOutput>
+    + UseUseCase<CompleteIdempotencyInput, CompleteIdempotencyOutput>
+    + UseUseCase<EditMessageInput, EditMessageOutput>
+    + UseUseCase<DeleteMessageInput, DeleteMessageOutput>
+    + UseUseCase<ListMessageEditsInput, ListMessageEditsOutput>
+    + UseUseCase<GetThreadInput, GetThreadOutput>
+    + UseUseCase<AddReactionInput, AddReactionOutput>
+    + UseUseCase<RemoveReactionInput, RemoveReactionOutput>
+    + UseUseCase<CreateWebhookInput, CreateWebhookOutput>
+    + UseUseCase<ListWebhooksInput, ListWebhooksOutput>
+    + UseUseCase<DeleteWebhookInput, DeleteWebhookOutput>
+    + UseUseCase<ListWebhookDeliveriesInput, ListWebhookDeliveriesOutput>
+    + UseUseCase<RecordAnalyticsEventInput, RecordAnalyticsEventOutput>
     + UsePresenter<Presenter = P>
     ;
     pub trait PresentResponse<D> = Present<Result<D>, Output = Result<Response, ()>>;
     pub trait Presenter = Component
     + PresentResponse<StatusOutput>
+    + PresentResponse<LivenessOutput>
+    + PresentResponse<ReadinessOutput>
     + PresentResponse<GetAccountOutput>
+    + PresentResponse<UpdateLocaleOutput>
     + PresentResponse<GetAuthStatusOutput>
     + PresentResponse<SignUpOutput>
     + PresentResponse<SignUpFinishOutput>
@@ -64,7 +159,175 @@ trait_set! {
     + PresentResponse<ForgetPasswordOutput>
     + PresentResponse<ResetPasswordOutput>
     + PresentResponse<PublishOutput>
-    + PresentResponse<SubscribeOutput>
+    + PresentResponse<MarkReadOutput>
+    + PresentResponse<GetUnreadCountsOutput>
+    + PresentResponse<AddModeratorOutput>
+    + PresentResponse<RemoveModeratorOutput>
+    + PresentResponse<EditMessageOutput>
+    + PresentResponse<DeleteMessageOutput>
+    + PresentResponse<ListMessageEditsOutput>
+    + PresentResponse<GetThreadOutput>
+    + PresentResponse<AddReactionOutput>
+    + PresentResponse<RemoveReactionOutput>
+    + PresentResponse<CreateWebhookOutput>
+    + PresentResponse<ListWebhooksOutput>
+    + PresentResponse<DeleteWebhookOutput>
+    + PresentResponse<ListWebhookDeliveriesOutput>
+    + PresentResponse<RecordAnalyticsEventOutput>
     + Present<Result<PubSubOutput>, Sender<Vec<u8>>, Output=()>
+    + Present<Result<SubscribeOutput>, SessionEnd, Output=Result<Response, ()>>
     ;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::http::server::api::config::{Config, Csrf};
+    use crate::http::server::api::contract::{config, TestMods};
+    use crate::http::server::api::serve;
+    use crate::http::server::shutdown;
+    use axum::extract::ws::close_code;
+    use kernel::Result;
+    use std::net::SocketAddr;
+    use std::time::Duration;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpStream;
+    use tokio::task::JoinHandle;
+    use tokio::time::timeout;
+
+    const PENDING: Duration = Duration::from_millis(50);
+
+    /// serves the api on a free port of localhost.
+    fn spawn_server(shutdown_timeout: Duration) -> (SocketAddr, JoinHandle<Result<()>>) {
+        let bind_address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let csrf = Csrf::new(vec!["localhost".to_string()], vec![], vec![], false);
+        let config = Config {
+            bind_address,
+            shutdown_timeout,
+            ..config(csrf)
+        };
+        let server = tokio::spawn(serve(config, TestMods::default()));
+        (bind_address, server)
+    }
+
+    /// connects once the spawned server is listening.
+    async fn connect(address: SocketAddr) -> BufReader<TcpStream> {
+        for _ in 0..100 {
+            if let Ok(stream) = TcpStream::connect(address).await {
+                return BufReader::new(stream);
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("{address} is not listening");
+    }
+
+    async fn read_line(stream: &mut BufReader<TcpStream>) -> String {
+        let mut line = String::new();
+        stream.read_line(&mut line).await.unwrap();
+        line.trim_end().to_string()
+    }
+
+    #[tokio::test]
+    async fn shutdown_finishes_in_flight_requests_and_refuses_new_ones() {
+        let (address, server) = spawn_server(Duration::from_secs(10));
+        let body = r#"{"mail":"test@example.com","password":"password","remember_me":false}"#;
+        let mut in_flight = connect(address).await;
+        let head = format!(
+            "POST /api/v1/auth/signin HTTP/1.1\r\nhost: localhost\r\nx-from: test\r\n\
+             content-type: application/json\r\ncontent-length: {}\r\nexpect: 100-continue\r\n\r\n",
+            body.len()
+        );
+        in_flight.write_all(head.as_bytes()).await.unwrap();
+        // the client is told to continue once the handler reads the body.
+        assert_eq!(read_line(&mut in_flight).await, "HTTP/1.1 100 Continue");
+        assert_eq!(read_line(&mut in_flight).await, "");
+        shutdown::start();
+        let refused = match TcpStream::connect(address).await {
+            Err(_) => true,
+            Ok(mut stream) => {
+                let request = "GET /api/v1/status HTTP/1.1\r\nhost: localhost\r\n\r\n";
+                let _ = stream.write_all(request.as_bytes()).await;
+                let mut response = vec![];
+                let read = stream.read_to_end(&mut response).await;
+                read.is_err() || response.is_empty()
+            }
+        };
+        assert!(refused);
+        in_flight.write_all(body.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        // read to the end, as the connection is closed after the response.
+        in_flight.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{response}");
+        timeout(Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn shutdown_waits_for_in_flight_guards() {
+        let (address, mut server) = spawn_server(Duration::from_secs(10));
+        connect(address).await;
+        let in_flight = shutdown::InFlight::new();
+        shutdown::start();
+        assert!(timeout(PENDING, &mut server).await.is_err());
+        drop(in_flight);
+        timeout(Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn shutdown_gives_up_at_the_timeout() {
+        let (address, mut server) = spawn_server(Duration::from_millis(200));
+        connect(address).await;
+        let _in_flight = shutdown::InFlight::new();
+        shutdown::start();
+        assert!(timeout(PENDING, &mut server).await.is_err());
+        timeout(Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn shutdown_closes_websockets_as_going_away() {
+        let (address, server) = spawn_server(Duration::from_secs(10));
+        let mut socket = connect(address).await;
+        let handshake = "GET /api/v1/channel/test/socket HTTP/1.1\r\nhost: localhost\r\n\
+                         x-from: test\r\ncookie: sid=test\r\nconnection: upgrade\r\n\
+                         upgrade: websocket\r\nsec-websocket-version: 13\r\n\
+                         sec-websocket-key: dGhlIHNhbXBsZSBub25jZQ==\r\n\r\n";
+        socket.write_all(handshake.as_bytes()).await.unwrap();
+        let status_line = read_line(&mut socket).await;
+        assert!(status_line.starts_with("HTTP/1.1 101"), "{status_line}");
+        while !read_line(&mut socket).await.is_empty() {}
+        shutdown::start();
+        // frames from the server are unmasked, heartbeat pings may come before the close.
+        let payload = loop {
+            let mut header = [0; 2];
+            socket.read_exact(&mut header).await.unwrap();
+            let mut payload = vec![0; (header[1] & 0x7f) as usize];
+            socket.read_exact(&mut payload).await.unwrap();
+            if header[0] == 0x88 {
+                break payload;
+            }
+        };
+        assert_eq!(
+            u16::from_be_bytes([payload[0], payload[1]]),
+            close_code::AWAY
+        );
+        assert_eq!(&payload[2..], b"server is shutting down");
+        timeout(Duration::from_secs(5), server)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+    }
+}
@@ -1,13 +1,31 @@
-use crate::http::server::response::{constants, response_with_code, WithSetCookie};
+use crate::http::server::middleware::locale::current_locale;
+use crate::http::server::middleware::session::SessionEnd;
+use crate::http::server::response::{constants, problem, response_with_code, WithSetCookie};
+use crate::http::server::shutdown;
+use crate::metrics::{GaugeGuard, SSE_CONNECTIONS};
 use crate::Present;
-use ::openapi::models::{Account, ErrorMessage, StatusOk, StatusResponse};
-use application::usecase::account::GetAccountOutput;
+use ::openapi::models::{
+    Account, ChannelModerator, Health, HealthCheck, Message, MessageEdit, ReactionCount,
+    ReadPosition, StatusOk, StatusResponse, Thread, UnreadCount, Webhook, WebhookDelivery,
+};
+use application::usecase::account::{GetAccountOutput, UpdateLocaleOutput};
+use application::usecase::analytics::RecordAnalyticsEventOutput;
 use application::usecase::auth::{
     ForgetPasswordOutput, GetAuthStatusOutput, ResetPasswordOutput, SignInOutput, SignOutOutput,
     SignUpFinishOutput, SignUpOutput,
 };
-use application::usecase::channel::{PubSubOutput, PublishOutput, SubscribeOutput};
-use application::usecase::status::StatusOutput;
+use application::usecase::channel::{
+    AddModeratorOutput, GetUnreadCountsOutput, MarkReadOutput, PubSubOutput, PublishOutput,
+    RemoveModeratorOutput, SubscribeOutput,
+};
+use application::usecase::message::{
+    AddReactionOutput, DeleteMessageOutput, EditMessageOutput, GetThreadOutput,
+    ListMessageEditsOutput, RemoveReactionOutput,
+};
+use application::usecase::status::{LivenessOutput, ReadinessOutput, StatusOutput};
+use application::usecase::webhook::{
+    CreateWebhookOutput, DeleteWebhookOutput, ListWebhookDeliveriesOutput, ListWebhooksOutput,
+};
 use async_trait::async_trait;
 use axum::http::StatusCode;
 use axum::response::sse::Event;
@@ -15,15 +33,28 @@ use axum::response::{IntoResponse, Response, Sse};
 use axum::Json;
 use cookie::{Cookie, CookieBuilder, SameSite};
 use futures::StreamExt;
-use kernel::error::Codes;
+use kernel::error::{Codes, FieldViolation};
 use kernel::Result;
 use log;
-use num_traits::ToPrimitive;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::convert::Infallible;
 use std::time::Duration;
 use tokio::sync::mpsc::Sender;
 use tokio_stream::wrappers::ReceiverStream;
+use validator::ValidationErrors;
+
+static HEALTH_UP: &str = "UP";
+static HEALTH_DOWN: &str = "DOWN";
+
+/// sent on frames that are not a typed channel event.
+static SSE_EVENT_MESSAGE: &str = "message";
+/// the only event whose id is the message id.
+static SSE_EVENT_MESSAGE_CREATED: &str = "message_created";
+/// sent before the stream ends because the session was revoked or expired.
+static SSE_EVENT_SESSION_EXPIRED: &str = "session_expired";
+/// well below the idle timeout of common proxies.
+const SSE_KEEP_ALIVE: Duration = Duration::from_secs(15);
+const SSE_RETRY: Duration = Duration::from_secs(3);
 
 #[derive(Clone, Default)]
 pub struct OpenAPIServerPresenter;
@@ -36,6 +67,20 @@ impl Present<Result<StatusOutput>> for OpenAPIServerPresenter {
     }
 }
 #[async_trait]
+impl Present<Result<LivenessOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+    async fn present(&self, data: Result<LivenessOutput>, _: ()) -> Self::Output {
+        Ok(present_liveness_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<ReadinessOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+    async fn present(&self, data: Result<ReadinessOutput>, _: ()) -> Self::Output {
+        Ok(present_readiness_output(data))
+    }
+}
+#[async_trait]
 impl Present<Result<GetAccountOutput>> for OpenAPIServerPresenter {
     type Output = Result<Response, ()>;
 
@@ -44,6 +89,21 @@ impl Present<Result<GetAccountOutput>> for OpenAPIServerPresenter {
     }
 }
 #[async_trait]
+impl Present<Result<UpdateLocaleOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<UpdateLocaleOutput>, _: ()) -> Self::Output {
+        Ok(match data {
+            Ok(UpdateLocaleOutput { account }) => ok_response_with_message(Account::new(
+                account.id,
+                account.name,
+                account.display_name,
+            )),
+            Err(e) => convert_server_error(&e),
+        })
+    }
+}
+#[async_trait]
 impl Present<Result<GetAuthStatusOutput>> for OpenAPIServerPresenter {
     type Output = Result<Response, ()>;
 
@@ -108,14 +168,138 @@ impl Present<Result<PublishOutput>> for OpenAPIServerPresenter {
     }
 }
 #[async_trait]
-impl Present<Result<SubscribeOutput>> for OpenAPIServerPresenter {
+impl Present<Result<SubscribeOutput>, SessionEnd> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(
+        &self,
+        data: Result<SubscribeOutput>,
+        session_end: SessionEnd,
+    ) -> Self::Output {
+        Ok(present_subscribe_output(data, session_end))
+    }
+}
+#[async_trait]
+impl Present<Result<MarkReadOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<MarkReadOutput>, _: ()) -> Self::Output {
+        Ok(present_mark_read_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<GetUnreadCountsOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<GetUnreadCountsOutput>, _: ()) -> Self::Output {
+        Ok(present_get_unread_counts_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<AddModeratorOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<AddModeratorOutput>, _: ()) -> Self::Output {
+        Ok(present_moderators(data.map(|o| o.moderators)))
+    }
+}
+#[async_trait]
+impl Present<Result<RemoveModeratorOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<RemoveModeratorOutput>, _: ()) -> Self::Output {
+        Ok(present_moderators(data.map(|o| o.moderators)))
+    }
+}
+
+#[async_trait]
+impl Present<Result<EditMessageOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<EditMessageOutput>, _: ()) -> Self::Output {
+        Ok(present_edit_message_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<DeleteMessageOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<DeleteMessageOutput>, _: ()) -> Self::Output {
+        Ok(present_status_ok(data))
+    }
+}
+#[async_trait]
+impl Present<Result<ListMessageEditsOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<ListMessageEditsOutput>, _: ()) -> Self::Output {
+        Ok(present_list_message_edits_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<GetThreadOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<GetThreadOutput>, _: ()) -> Self::Output {
+        Ok(present_get_thread_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<AddReactionOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<AddReactionOutput>, _: ()) -> Self::Output {
+        Ok(present_reactions(data.map(|o| o.reactions)))
+    }
+}
+#[async_trait]
+impl Present<Result<RemoveReactionOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<RemoveReactionOutput>, _: ()) -> Self::Output {
+        Ok(present_reactions(data.map(|o| o.reactions)))
+    }
+}
+#[async_trait]
+impl Present<Result<CreateWebhookOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<CreateWebhookOutput>, _: ()) -> Self::Output {
+        Ok(present_create_webhook_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<ListWebhooksOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<ListWebhooksOutput>, _: ()) -> Self::Output {
+        Ok(present_list_webhooks_output(data))
+    }
+}
+#[async_trait]
+impl Present<Result<DeleteWebhookOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
+
+    async fn present(&self, data: Result<DeleteWebhookOutput>, _: ()) -> Self::Output {
+        Ok(present_status_ok(data))
+    }
+}
+#[async_trait]
+impl Present<Result<ListWebhookDeliveriesOutput>> for OpenAPIServerPresenter {
     type Output = Result<Response, ()>;
 
-    async fn present(&self, data: Result<SubscribeOutput>, _: ()) -> Self::Output {
-        Ok(present_subscribe_output(data))
+    async fn present(&self, data: Result<ListWebhookDeliveriesOutput>, _: ()) -> Self::Output {
+        Ok(present_list_webhook_deliveries_output(data))
     }
 }
+#[async_trait]
+impl Present<Result<RecordAnalyticsEventOutput>> for OpenAPIServerPresenter {
+    type Output = Result<Response, ()>;
 
+    async fn present(&self, data: Result<RecordAnalyticsEventOutput>, _: ()) -> Self::Output {
+        Ok(present_status_ok(data))
+    }
+}
 #[async_trait]
 impl Present<Result<PubSubOutput>, Sender<Vec<u8>>> for OpenAPIServerPresenter {
     type Output = ();
@@ -129,6 +313,8 @@ impl Present<Result<PubSubOutput>, Sender<Vec<u8>>> for OpenAPIServerPresenter {
             return;
         };
         tokio::spawn(async move {
+            // the stream ends after the pending inbound messages are published.
+            let _in_flight = shutdown::InFlight::new();
             let mut stream = ReceiverStream::new(output.rx);
             while let Some(msg) = stream.next().await {
                 let _ = attachment.send(msg).await;
@@ -148,11 +334,58 @@ fn present_status_output(data: Result<StatusOutput>) -> Response {
     }
 }
 
+fn present_liveness_output(data: Result<LivenessOutput>) -> Response {
+    match data {
+        Ok(_) => ok_response_with_message(Health::new(HEALTH_UP.to_string())),
+        Err(e) => convert_server_error(&e),
+    }
+}
+
+/// answers 503 when any dependency is down, so that the load balancer stops routing to this node.
+fn present_readiness_output(data: Result<ReadinessOutput>) -> Response {
+    match data {
+        Ok(output) => {
+            let (code, status) = if output.ready() {
+                (StatusCode::OK, HEALTH_UP)
+            } else {
+                (StatusCode::SERVICE_UNAVAILABLE, HEALTH_DOWN)
+            };
+            let mut health = Health::new(status.to_string());
+            health.checks = Some(
+                output
+                    .checks
+                    .into_iter()
+                    .map(|check| {
+                        // the error is only logged, it may reveal the internals of the dependency.
+                        let status = match &check.error {
+                            None => HEALTH_UP,
+                            Some(e) => {
+                                log::warn!("{} is not ready: {}", check.name, e);
+                                HEALTH_DOWN
+                            }
+                        };
+                        HealthCheck::new(
+                            check.name,
+                            status.to_string(),
+                            i64::try_from(check.latency.as_millis()).unwrap_or(i64::MAX),
+                        )
+                    })
+                    .collect(),
+            );
+            server_response_message(code, health)
+        }
+        Err(e) => convert_server_error(&e),
+    }
+}
+
 fn present_get_account_output(data: Result<GetAccountOutput>) -> Response {
     match data {
         Ok(output) => {
-            let Some(account) = output.account else  {
-                return not_found(&Codes::CommonNotFound, Some("account not found".to_string()));
+            let Some(account) = output.account else {
+                return not_found(
+                    &Codes::AccountNotFound,
+                    Some("account not found".to_string()),
+                );
             };
             ok_response_with_message(Account::new(account.id, account.name, account.display_name))
         }
@@ -199,15 +432,35 @@ fn present_forget_password_output(data: Result<ForgetPasswordOutput>) -> Respons
         Err(e) => convert_server_error(&e),
     }
 }
-fn present_subscribe_output(data: Result<SubscribeOutput>) -> Response {
+fn present_subscribe_output(data: Result<SubscribeOutput>, session_end: SessionEnd) -> Response {
     match data {
         Ok(output) => {
             let (sender, rx) = tokio::sync::mpsc::channel::<Result<Event, Infallible>>(1000);
             tokio::spawn(async move {
+                let _connection = GaugeGuard::new(&SSE_CONNECTIONS);
                 let mut stream = ReceiverStream::new(output.rx);
-                while let Some(msg) = stream.next().await {
-                    let result = String::from_utf8_lossy(msg.as_slice()).to_string();
-                    match sender.send(Ok(Event::default().data(result))).await {
+                let mut session_end = session_end;
+                if sender
+                    .send(Ok(Event::default().retry(SSE_RETRY)))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+                loop {
+                    let msg = tokio::select! {
+                        msg = stream.next() => msg,
+                        _ = &mut session_end => {
+                            let event = Event::default().event(SSE_EVENT_SESSION_EXPIRED).data("");
+                            let _ = sender.send(Ok(event)).await;
+                            break;
+                        }
+                        _ = shutdown::started() => break,
+                    };
+                    let Some(msg) = msg else {
+                        break;
+                    };
+                    match sender.send(Ok(channel_event(msg))).await {
                         Ok(_) => (),
                         Err(e) => {
                             log::debug!("send error: {}", e);
@@ -219,8 +472,8 @@ fn present_subscribe_output(data: Result<SubscribeOutput>) -> Response {
             Sse::new(ReceiverStream::new(rx))
                 .keep_alive(
                     axum::response::sse::KeepAlive::new()
-                        .interval(Duration::from_secs(1))
-                        .text("keep-alive-text"),
+                        .interval(SSE_KEEP_ALIVE)
+                        .text("keep-alive"),
                 )
                 .into_response()
         }
@@ -228,6 +481,199 @@ fn present_subscribe_output(data: Result<SubscribeOutput>) -> Response {
     }
 }
 
+#[derive(Deserialize)]
+struct EventType {
+    #[serde(rename = "type")]
+    name: String,
+    message: Option<EventMessage>,
+}
+
+#[derive(Deserialize)]
+struct EventMessage {
+    id: String,
+}
+
+/// names the event after the `type` of the channel event, so that clients can listen per type.
+/// only created messages carry an id, which the browser sends back as `Last-Event-ID` to resume
+/// after the last message it received; the other events keep the last id as it is.
+fn channel_event(msg: Vec<u8>) -> Event {
+    let (name, id) = match serde_json::from_slice::<EventType>(&msg) {
+        Ok(EventType {
+            name,
+            message: Some(message),
+        }) if name == SSE_EVENT_MESSAGE_CREATED => (name, Some(message.id)),
+        Ok(event) => (event.name, None),
+        Err(_) => (SSE_EVENT_MESSAGE.to_string(), None),
+    };
+    let event = Event::default()
+        .event(name)
+        .data(String::from_utf8_lossy(msg.as_slice()));
+    match id {
+        Some(id) => event.id(id),
+        None => event,
+    }
+}
+
+fn present_mark_read_output(data: Result<MarkReadOutput>) -> Response {
+    match data {
+        Ok(output) => {
+            let position = output.read_position;
+            ok_response_with_message(ReadPosition::new(
+                position.channel_id,
+                position.account_id,
+                position.message_id,
+                position.update_time.to_rfc3339(),
+            ))
+        }
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_get_unread_counts_output(data: Result<GetUnreadCountsOutput>) -> Response {
+    match data {
+        Ok(output) => ok_response_with_message(
+            output
+                .unread_counts
+                .into_iter()
+                .map(|c| {
+                    let mut count = UnreadCount::new(c.channel_id, c.count);
+                    count.message_id = c.message_id;
+                    count
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_moderators(data: Result<Vec<kernel::entity::ChannelModerator>>) -> Response {
+    match data {
+        Ok(moderators) => ok_response_with_message(
+            moderators
+                .into_iter()
+                .map(|m| {
+                    ChannelModerator::new(m.channel_id, m.account_id, m.create_time.to_rfc3339())
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn convert_message(message: kernel::entity::Message) -> Message {
+    let mut res = Message::new(
+        message.id,
+        message.channel_id,
+        message.account_id,
+        message.body,
+        message.reply_count,
+        message.create_time.to_rfc3339(),
+        message.update_time.to_rfc3339(),
+    );
+    res.parent_id = message.parent_id;
+    res
+}
+fn present_edit_message_output(data: Result<EditMessageOutput>) -> Response {
+    match data {
+        Ok(output) => ok_response_with_message(convert_message(output.message)),
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_get_thread_output(data: Result<GetThreadOutput>) -> Response {
+    match data {
+        Ok(output) => ok_response_with_message(Thread::new(
+            convert_message(output.parent),
+            output.replies.into_iter().map(convert_message).collect(),
+        )),
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_list_message_edits_output(data: Result<ListMessageEditsOutput>) -> Response {
+    match data {
+        Ok(output) => ok_response_with_message(
+            output
+                .edits
+                .into_iter()
+                .map(|e| {
+                    MessageEdit::new(
+                        e.id,
+                        e.message_id,
+                        e.body,
+                        e.edited_by,
+                        e.create_time.to_rfc3339(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_reactions(data: Result<Vec<kernel::entity::ReactionCount>>) -> Response {
+    match data {
+        Ok(reactions) => ok_response_with_message(
+            reactions
+                .into_iter()
+                .map(|r| ReactionCount::new(r.emoji, r.count))
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_create_webhook_output(data: Result<CreateWebhookOutput>) -> Response {
+    match data {
+        Ok(output) => {
+            let webhook = output.webhook;
+            let secret = webhook.secret.clone();
+            let mut message = to_webhook_message(webhook);
+            message.secret = Some(secret);
+            ok_response_with_message(message)
+        }
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_list_webhooks_output(data: Result<ListWebhooksOutput>) -> Response {
+    match data {
+        Ok(output) => ok_response_with_message(
+            output
+                .webhooks
+                .into_iter()
+                .map(to_webhook_message)
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => convert_server_error(&e),
+    }
+}
+fn present_list_webhook_deliveries_output(data: Result<ListWebhookDeliveriesOutput>) -> Response {
+    match data {
+        Ok(output) => ok_response_with_message(
+            output
+                .deliveries
+                .into_iter()
+                .map(|d| {
+                    let mut message = WebhookDelivery::new(
+                        d.id,
+                        d.webhook_id,
+                        d.status.to_string(),
+                        d.attempts,
+                        d.create_time.to_rfc3339(),
+                        d.update_time.to_rfc3339(),
+                    );
+                    message.response_status = d.response_status;
+                    message.last_error = d.last_error;
+                    message
+                })
+                .collect::<Vec<_>>(),
+        ),
+        Err(e) => convert_server_error(&e),
+    }
+}
+
+fn to_webhook_message(webhook: kernel::entity::Webhook) -> Webhook {
+    Webhook::new(
+        webhook.id,
+        webhook.channel_id,
+        webhook.url,
+        webhook.create_time.to_rfc3339(),
+    )
+}
+
 fn present_status_ok<A>(data: Result<A>) -> Response {
     match data {
         Ok(_) => status_ok_response(),
@@ -259,6 +705,9 @@ fn convert_server_error(err: &anyhow::Error) -> Response {
                 internal_server_error(type_code, message.clone())
             }
         }
+    } else if let Some(errors) = err.downcast_ref::<ValidationErrors>() {
+        log::warn!("{}", err);
+        validation_failed(FieldViolation::from_errors(errors))
     } else {
         log::error!("{:?}", err);
         internal_server_error(
@@ -269,29 +718,31 @@ fn convert_server_error(err: &anyhow::Error) -> Response {
 }
 
 fn bad_request(type_code: &Codes, message: Option<String>) -> Response {
-    server_error_message(StatusCode::BAD_REQUEST, type_code, message)
+    problem(StatusCode::BAD_REQUEST, type_code, message, vec![])
 }
 fn unauthorized(type_code: &Codes, message: Option<String>) -> Response {
-    server_error_message(StatusCode::UNAUTHORIZED, type_code, message)
+    problem(StatusCode::UNAUTHORIZED, type_code, message, vec![])
 }
 fn forbidden(type_code: &Codes, message: Option<String>) -> Response {
-    server_error_message(StatusCode::FORBIDDEN, type_code, message)
+    problem(StatusCode::FORBIDDEN, type_code, message, vec![])
 }
 fn not_found(type_code: &Codes, message: Option<String>) -> Response {
-    server_error_message(StatusCode::NOT_FOUND, type_code, message)
+    problem(StatusCode::NOT_FOUND, type_code, message, vec![])
 }
 fn internal_server_error(type_code: &Codes, message: Option<String>) -> Response {
-    server_error_message(StatusCode::INTERNAL_SERVER_ERROR, type_code, message)
+    problem(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        type_code,
+        message,
+        vec![],
+    )
 }
-
-fn server_error_message(code: StatusCode, type_code: &Codes, message: Option<String>) -> Response {
-    server_response_message(
-        code,
-        ErrorMessage {
-            status: code.as_u16().to_i32().unwrap(),
-            r#type: type_code.to_string(),
-            message,
-        },
+fn validation_failed(violations: Vec<FieldViolation>) -> Response {
+    problem(
+        StatusCode::BAD_REQUEST,
+        &Codes::ValidationFailed,
+        Some(current_locale().message("validation-failed-detail")),
+        violations,
     )
 }
 
@@ -314,11 +765,11 @@ fn status_ok_response() -> Response {
     ok_response_with_message(StatusOk::new())
 }
 
-fn delete_session_cookie() -> Cookie<'static> {
+pub(crate) fn delete_session_cookie() -> Cookie<'static> {
     set_session_cookie("", Some(time::Duration::seconds(0)))
 }
 
-fn set_session_cookie(
+pub(crate) fn set_session_cookie(
     session_id: impl Into<String>,
     max_age: Option<time::Duration>,
 ) -> Cookie<'static> {
@@ -332,3 +783,130 @@ fn set_session_cookie(
         .same_site(SameSite::Strict)
         .finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{present_readiness_output, present_subscribe_output};
+    use crate::fixture::{account, fixtures, message};
+    use crate::http::server::middleware::session::watch_session;
+    use application::interface::gateway::health::Dependency;
+    use application::usecase::channel::SubscribeOutput;
+    use application::usecase::session::{GetSessionInput, GetSessionOutput};
+    use application::usecase::status::{DependencyCheck, ReadinessOutput};
+    use axum::http::StatusCode;
+    use axum::response::Response;
+    use chrono::Utc;
+    use kernel::entity::{ChannelEvent, Session};
+    use std::time::Duration;
+
+    #[derive(Clone)]
+    struct Active;
+    #[derive(Clone)]
+    struct Revoked;
+
+    fixtures! {
+        Active {
+            GetSessionInput => GetSessionOutput = || {
+                Ok(GetSessionOutput::new(Some(Session::new(
+                    "sid".to_string(),
+                    account(),
+                    Utc::now(),
+                ))))
+            };
+        }
+    }
+    fixtures! {
+        Revoked {
+            GetSessionInput => GetSessionOutput = || Ok(GetSessionOutput::new(None));
+        }
+    }
+
+    /// the events of the stream, each as its lines.
+    async fn events(response: Response) -> Vec<Vec<String>> {
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        String::from_utf8(body.to_vec())
+            .unwrap()
+            .split("\n\n")
+            .filter(|event| !event.is_empty())
+            .map(|event| event.lines().map(str::to_string).collect())
+            .collect()
+    }
+
+    fn payload(event: &ChannelEvent) -> Vec<u8> {
+        serde_json::to_vec(event).unwrap()
+    }
+
+    #[tokio::test]
+    async fn only_created_messages_have_event_ids() {
+        let (sender, rx) = tokio::sync::mpsc::channel(10);
+        let created = ChannelEvent::MessageCreated {
+            message: message(),
+            thread: None,
+        };
+        let edited = ChannelEvent::MessageEdited { message: message() };
+        for msg in [payload(&created), payload(&edited), b"text".to_vec()] {
+            sender.send(msg).await.unwrap();
+        }
+        drop(sender);
+        let session_end = watch_session(Active, "sid".to_string(), Duration::from_secs(60));
+
+        let events = events(present_subscribe_output(
+            Ok(SubscribeOutput::new(rx)),
+            session_end,
+        ))
+        .await;
+        assert_eq!(events[0], vec!["retry:3000"]);
+        assert!(events[1].contains(&"event:message_created".to_string()));
+        assert!(events[1].contains(&"id:message".to_string()));
+        assert!(events[2].contains(&"event:message_edited".to_string()));
+        assert!(events[3].contains(&"event:message".to_string()));
+        for event in &events[2..] {
+            assert!(
+                !event.iter().any(|line| line.starts_with("id:")),
+                "{event:?}"
+            );
+        }
+        assert_eq!(events.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn revoked_session_ends_the_stream() {
+        let (sender, rx) = tokio::sync::mpsc::channel(10);
+        let session_end = watch_session(Revoked, "sid".to_string(), Duration::from_millis(10));
+
+        let response = present_subscribe_output(Ok(SubscribeOutput::new(rx)), session_end);
+        let events = tokio::time::timeout(Duration::from_secs(5), events(response))
+            .await
+            .unwrap();
+        assert_eq!(
+            events.last().unwrap(),
+            &vec!["event:session_expired".to_string(), "data:".to_string()]
+        );
+        drop(sender);
+    }
+
+    #[tokio::test]
+    async fn readiness_hides_dependency_errors() {
+        let output = ReadinessOutput::new(vec![
+            DependencyCheck::new(
+                Dependency::Database,
+                "postgres".to_string(),
+                Duration::from_millis(1),
+                None,
+            ),
+            DependencyCheck::new(
+                Dependency::RedisPrimary,
+                "redis_primary".to_string(),
+                Duration::from_millis(1),
+                Some("connection refused: redis://10.0.0.1:6379".to_string()),
+            ),
+        ]);
+        let response = present_readiness_output(Ok(output));
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains(r#""status":"DOWN""#));
+        assert!(body.contains(r#""name":"postgres""#), "{body}");
+        assert!(!body.contains("10.0.0.1"));
+    }
+}
@@ -1,31 +1,104 @@
-use super::handler::{account, auth, channel, status};
+use super::handler::{
+    account, analytics, auth, channel, health, message, openapi, status, webhook,
+};
+use crate::http::server::api::config::Config;
 use crate::http::server::api::{Mods, Presenter};
+use crate::http::server::graphql;
+use crate::http::server::middleware::cors::cors_layer;
 use crate::http::server::middleware::csrf::csrf_protection;
-use crate::http::server::middleware::request_id::MakeRequestBase62Uuid;
+use crate::http::server::middleware::locale::scope_locale;
+use crate::http::server::middleware::metrics::track_metrics;
+use crate::http::server::middleware::rate_limit::RateLimitLayer;
+use crate::http::server::middleware::request_id::{scope_request_id, MakeRequestBase62Uuid};
+use crate::http::server::middleware::session::resolve_session;
+use crate::http::server::middleware::telemetry::{MakeRequestSpan, RecordStatus};
 
 use crate::http::server::middleware::tracking::TrackingLayer;
-use axum::middleware::from_fn;
+use axum::middleware::{from_fn, from_fn_with_state};
 use axum::routing::Router;
+use std::sync::Arc;
 use tower_http::request_id::{PropagateRequestIdLayer, SetRequestIdLayer};
-use tower_http::trace::{DefaultMakeSpan, TraceLayer};
+use tower_http::trace::TraceLayer;
 
-pub(crate) fn define_route<M: Mods<P>, P: Presenter>(mods: M) -> Router {
-    Router::new()
-        .nest(
-            "/api/v1",
-            Router::new()
-                .merge(status::route(mods.clone()))
-                .merge(account::route(mods.clone()))
-                .merge(auth::route(mods.clone()))
-                .merge(channel::route(mods.clone())), // .route("/status", get(status::get_status::<M, P>))
-        )
+pub(crate) fn define_route<M: Mods<P>, P: Presenter>(config: &Config, mods: M) -> Router {
+    let api = Router::new()
+        .merge(status::route(mods.clone()))
+        .merge(account::route(mods.clone()))
+        .merge(auth::route(mods.clone()))
+        .merge(channel::route(mods.clone()))
+        .merge(message::route(mods.clone()))
+        .merge(webhook::route(mods.clone()))
+        .merge(analytics::route(mods.clone()))
+        .merge(graphql::route(mods.clone()))
         .layer(
             TraceLayer::new_for_http()
-                .make_span_with(DefaultMakeSpan::default().include_headers(true)),
+                .make_span_with(MakeRequestSpan)
+                .on_response(RecordStatus),
         )
+        .layer(RateLimitLayer::new(mods.clone()))
+        .layer(from_fn(scope_locale))
+        .layer(from_fn_with_state(mods.clone(), resolve_session::<M>))
+        .layer(from_fn(scope_request_id))
         .layer(PropagateRequestIdLayer::x_request_id())
         .layer(SetRequestIdLayer::x_request_id(MakeRequestBase62Uuid))
-        .layer(from_fn(csrf_protection))
-        .layer(TrackingLayer::default())
-        .with_state(mods)
+        .layer(from_fn_with_state(
+            Arc::new(config.csrf.clone()),
+            csrf_protection,
+        ))
+        // counts the requests rejected by the layers above as well.
+        .layer(from_fn(track_metrics))
+        .layer(TrackingLayer::new(config.tracking.clone()))
+        // probed by the load balancer, so kept out of the layers above.
+        .merge(health::route(mods.clone()))
+        .merge(openapi::route(mods.clone()));
+    let router = Router::new().nest("/api/v1", api).with_state(mods);
+    // answers preflight requests before they reach the csrf protection.
+    match &config.cors {
+        Some(cors) => router.layer(cors_layer(cors)),
+        None => router,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::server::api::config::Csrf;
+    use crate::http::server::api::contract::{config, TestMods};
+    use crate::http::server::api::route::define_route;
+    use axum::body::Body;
+    use axum::http::header::HOST;
+    use axum::http::{Method, Request, StatusCode};
+    use tower::ServiceExt;
+
+    async fn status(csrf: Csrf, method: Method, path: &str) -> StatusCode {
+        let request = Request::builder()
+            .method(method)
+            .uri(path)
+            .header(HOST, "localhost")
+            .body(Body::empty())
+            .unwrap();
+        define_route(&config(csrf), TestMods::default())
+            .oneshot(request)
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn layers_match_the_mounted_path() {
+        let csrf = || {
+            Csrf::new(
+                vec!["localhost".to_string()],
+                vec![],
+                vec!["/api/v1/status".to_string()],
+                false,
+            )
+        };
+        // the exempt path passes without `x-from`, and reaches the method check of the route.
+        let exempt = status(csrf(), Method::GET, "/api/v1/status").await;
+        assert_eq!(exempt, StatusCode::OK);
+        let exempt = status(csrf(), Method::POST, "/api/v1/status").await;
+        assert_eq!(exempt, StatusCode::METHOD_NOT_ALLOWED);
+        let protected = status(csrf(), Method::GET, "/api/v1/account/me").await;
+        assert_eq!(protected, StatusCode::FORBIDDEN);
+    }
 }
@@ -0,0 +1,318 @@
+use crate::http::server::graphql::schema::{MutationRoot, QueryRoot, SubscriptionRoot};
+use crate::http::server::middleware::locale::{with_locale, RequestLocale};
+use crate::http::server::middleware::session::ExtractSession;
+use application::interface::Component;
+use application::usecase::account::{GetAccountInput, GetAccountOutput};
+use application::usecase::auth::{
+    ForgetPasswordInput, ForgetPasswordOutput, ResetPasswordInput, ResetPasswordOutput,
+    SignInInput, SignInOutput, SignOutInput, SignOutOutput, SignUpFinishInput, SignUpFinishOutput,
+    SignUpInput, SignUpOutput,
+};
+use application::usecase::channel::{PublishInput, PublishOutput, SubscribeInput, SubscribeOutput};
+use application::usecase::session::{GetSessionInput, GetSessionOutput};
+use application::usecase::UseUseCase;
+use async_graphql::{Data, Schema};
+use async_graphql_axum::{GraphQLProtocol, GraphQLRequest, GraphQLResponse, GraphQLWebSocket};
+use axum::extract::{Host, WebSocketUpgrade};
+use axum::response::Response;
+use axum::routing::get;
+use axum::{Extension, Router};
+use kernel::entity::Session;
+use trait_set::trait_set;
+
+mod presenter;
+mod schema;
+
+pub(crate) type GraphQLSchema<M> = Schema<QueryRoot<M>, MutationRoot<M>, SubscriptionRoot<M>>;
+
+/// the session of the request, resolved by the same extractor as the rest of the api.
+#[derive(Clone)]
+pub(crate) struct RequestSession(pub(crate) Option<Session>);
+
+/// the host the request was sent to, for the links in mails.
+pub(crate) struct SiteUrl(pub(crate) String);
+
+trait_set! {
+    pub trait Mods = Component
+    + UseUseCase<GetAccountInput, GetAccountOutput>
+    + UseUseCase<GetSessionInput, GetSessionOutput>
+    + UseUseCase<SignUpInput, SignUpOutput>
+    + UseUseCase<SignUpFinishInput, SignUpFinishOutput>
+    + UseUseCase<SignInInput, SignInOutput>
+    + UseUseCase<SignOutInput, SignOutOutput>
+    + UseUseCase<ForgetPasswordInput, ForgetPasswordOutput>
+    + UseUseCase<ResetPasswordInput, ResetPasswordOutput>
+    + UseUseCase<PublishInput, PublishOutput>
+    + UseUseCase<SubscribeInput, SubscribeOutput>
+    ;
+}
+
+/// deep enough for the introspection query of graphql tools, the schema itself nests 3 levels.
+const MAX_DEPTH: usize = 16;
+/// every field counts 1, so this caps the fields selected by one request.
+const MAX_COMPLEXITY: usize = 256;
+
+/// queries and mutations are posted to `/graphql`, subscriptions open a websocket on it.
+pub(crate) fn route<M: Mods>(mods: M) -> Router<M> {
+    Router::new()
+        .route("/graphql", get(subscribe::<M>).post(execute::<M>))
+        .layer(Extension(schema(mods)))
+}
+
+fn schema<M: Mods>(mods: M) -> GraphQLSchema<M> {
+    Schema::build(
+        QueryRoot::new(mods.clone()),
+        MutationRoot::new(mods.clone()),
+        SubscriptionRoot::new(mods),
+    )
+    .limit_depth(MAX_DEPTH)
+    .limit_complexity(MAX_COMPLEXITY)
+    .finish()
+}
+
+async fn execute<M: Mods>(
+    Extension(schema): Extension<GraphQLSchema<M>>,
+    ExtractSession(session): ExtractSession,
+    Host(host): Host,
+    locale: RequestLocale,
+    request: GraphQLRequest,
+) -> GraphQLResponse {
+    let request = request
+        .into_inner()
+        .data(RequestSession(session))
+        .data(SiteUrl(host))
+        .data(locale);
+    schema.execute(request).await.into()
+}
+
+async fn subscribe<M: Mods>(
+    Extension(schema): Extension<GraphQLSchema<M>>,
+    ExtractSession(session): ExtractSession,
+    locale: RequestLocale,
+    protocol: GraphQLProtocol,
+    ws: WebSocketUpgrade,
+) -> Response {
+    ws.protocols(async_graphql::http::ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| {
+            let mut data = Data::default();
+            data.insert(RequestSession(session));
+            data.insert(locale);
+            let RequestLocale(request_locale) = locale;
+            // the socket outlives the request, so the locale is scoped again for its errors.
+            with_locale(
+                request_locale,
+                GraphQLWebSocket::new(stream, schema, protocol)
+                    .with_data(data)
+                    .serve(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{schema, RequestSession, SiteUrl};
+    use crate::fixture::{account, fixtures, message};
+    use crate::http::server::middleware::locale::with_locale;
+    use application::usecase::account::{GetAccountInput, GetAccountOutput};
+    use application::usecase::auth::{
+        ForgetPasswordInput, ForgetPasswordOutput, ResetPasswordInput, ResetPasswordOutput,
+        SignInInput, SignInOutput, SignOutInput, SignOutOutput, SignUpFinishInput,
+        SignUpFinishOutput, SignUpInput, SignUpOutput,
+    };
+    use application::usecase::channel::{
+        PublishInput, PublishOutput, SubscribeInput, SubscribeOutput,
+    };
+    use application::usecase::session::{GetSessionInput, GetSessionOutput};
+    use async_graphql::{Request, Response, Value};
+    use chrono::Utc;
+    use kernel::entity::Session;
+    use kernel::i18n::Locale;
+    use serde_json::json;
+
+    #[derive(Clone)]
+    struct TestMods;
+
+    fixtures! {
+        TestMods {
+            GetAccountInput => GetAccountOutput = || Ok(GetAccountOutput::new(Some(account())));
+            GetSessionInput => GetSessionOutput = || Ok(GetSessionOutput::new(Some(session())));
+            SignUpInput => SignUpOutput = || Ok(SignUpOutput);
+            SignUpFinishInput => SignUpFinishOutput = || {
+                Ok(SignUpFinishOutput::new("sid".to_string()))
+            };
+            SignInInput => SignInOutput = || Ok(SignInOutput::new("sid".to_string(), false));
+            SignOutInput => SignOutOutput = || Ok(SignOutOutput);
+            ForgetPasswordInput => ForgetPasswordOutput = || Ok(ForgetPasswordOutput);
+            ResetPasswordInput => ResetPasswordOutput = || Ok(ResetPasswordOutput);
+            PublishInput => PublishOutput = || Ok(PublishOutput::new(message()));
+            SubscribeInput => SubscribeOutput = || {
+                Ok(SubscribeOutput::new(tokio::sync::mpsc::channel(1).1))
+            };
+        }
+    }
+
+    fn session() -> Session {
+        Session::new("sid".to_string(), account(), Utc::now())
+    }
+
+    async fn execute(query: &str, session: Option<Session>) -> Response {
+        let request = Request::new(query)
+            .data(RequestSession(session))
+            .data(SiteUrl("localhost".to_string()));
+        schema(TestMods).execute(request).await
+    }
+
+    fn data(response: Response) -> serde_json::Value {
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()
+    }
+
+    fn codes(response: &Response) -> Vec<String> {
+        response
+            .errors
+            .iter()
+            .filter_map(|e| e.extensions.as_ref()?.get("code"))
+            .map(|code| match code {
+                Value::String(code) => code.clone(),
+                other => other.to_string(),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn sdl_has_the_roots() {
+        let sdl = schema(TestMods).sdl();
+        assert!(sdl.contains("type QueryRoot"), "{sdl}");
+        assert!(sdl.contains("type MutationRoot"), "{sdl}");
+        assert!(sdl.contains("type SubscriptionRoot"), "{sdl}");
+    }
+
+    #[tokio::test]
+    async fn account_is_queried() {
+        let response = execute(
+            r#"{ account(id: "account") { id name displayName } }"#,
+            None,
+        );
+        assert_eq!(
+            data(response.await),
+            json!({"account": {"id": "account", "name": "name", "displayName": "display name"}})
+        );
+    }
+
+    #[tokio::test]
+    async fn session_is_the_request_session() {
+        let query = "{ session { id account { id } } }";
+        assert_eq!(data(execute(query, None).await), json!({ "session": null }));
+        assert_eq!(
+            data(execute(query, Some(session())).await),
+            json!({"session": {"id": "sid", "account": {"id": "account"}}})
+        );
+    }
+
+    #[tokio::test]
+    async fn publish_requires_session() {
+        let query = r#"mutation { publish(channelId: "channel", body: "body") { id body } }"#;
+        let response = execute(query, None).await;
+        assert_eq!(codes(&response), vec!["auth/session_required"]);
+
+        let response = execute(query, Some(session())).await;
+        assert_eq!(
+            data(response),
+            json!({"publish": {"id": "message", "body": "body"}})
+        );
+    }
+
+    #[tokio::test]
+    async fn error_titles_are_localized() {
+        let query = r#"mutation { publish(channelId: "channel", body: "body") { id } }"#;
+        let response = with_locale(Locale::Ja, execute(query, None)).await;
+        assert_eq!(response.errors[0].message, "ログインが必要です");
+        let response = with_locale(Locale::En, execute(query, None)).await;
+        assert_eq!(response.errors[0].message, "Session required");
+    }
+
+    #[tokio::test]
+    async fn signin_sets_session_cookie() {
+        let query = r#"mutation { signin(mail: "mail@example.com", password: "password") }"#;
+        let response = execute(query, None).await;
+        let cookie = response.http_headers.get("set-cookie").unwrap();
+        assert!(
+            cookie.to_str().unwrap().starts_with("sid=sid"),
+            "{cookie:?}"
+        );
+        assert_eq!(data(response), json!({ "signin": true }));
+    }
+
+    #[tokio::test]
+    async fn deep_query_is_rejected() {
+        let mut query = "name".to_string();
+        for _ in 0..20 {
+            query = format!("ofType {{ {query} }}");
+        }
+        let query = format!("{{ __schema {{ types {{ {query} }} }} }}");
+        let response = execute(&query, None).await;
+        assert!(response.data == Value::Null, "{:?}", response.data);
+        assert!(
+            response
+                .errors
+                .iter()
+                .any(|e| e.message.contains("nested too deep")),
+            "{:?}",
+            response.errors
+        );
+    }
+
+    #[tokio::test]
+    async fn complex_query_is_rejected() {
+        let fields: Vec<String> = (0..300)
+            .map(|i| format!("a{i}: session {{ id }}"))
+            .collect();
+        let response = execute(&format!("{{ {} }}", fields.join(" ")), None).await;
+        assert!(
+            response
+                .errors
+                .iter()
+                .any(|e| e.message.contains("too complex")),
+            "{:?}",
+            response.errors
+        );
+    }
+
+    /// the introspection query graphiql sends, which the limits must let through.
+    const INTROSPECTION: &str = r#"
+        query IntrospectionQuery {
+          __schema {
+            queryType { name }
+            mutationType { name }
+            subscriptionType { name }
+            types { ...FullType }
+            directives { name description locations args { ...InputValue } }
+          }
+        }
+        fragment FullType on __Type {
+          kind name description
+          fields(includeDeprecated: true) {
+            name description args { ...InputValue } type { ...TypeRef }
+            isDeprecated deprecationReason
+          }
+          inputFields { ...InputValue }
+          interfaces { ...TypeRef }
+          enumValues(includeDeprecated: true) { name description isDeprecated deprecationReason }
+          possibleTypes { ...TypeRef }
+        }
+        fragment InputValue on __InputValue {
+          name description type { ...TypeRef } defaultValue
+        }
+        fragment TypeRef on __Type {
+          kind name
+          ofType { kind name ofType { kind name ofType { kind name ofType { kind name
+            ofType { kind name ofType { kind name ofType { kind name } } } } } } }
+        }
+    "#;
+
+    #[tokio::test]
+    async fn introspection_is_within_the_limits() {
+        let response = execute(INTROSPECTION, None).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::http::server::api::presenter::openapi::{delete_session_cookie, set_session_cookie};
+use crate::http::server::graphql::schema::{Account, Message};
+use crate::http::server::middleware::locale::current_locale;
+use crate::http::server::shutdown;
+use crate::metrics::{GaugeGuard, GRAPHQL_SUBSCRIPTIONS};
+use crate::Present;
+use application::usecase::account::GetAccountOutput;
+use application::usecase::auth::{
+    ForgetPasswordOutput, ResetPasswordOutput, SignInOutput, SignOutOutput, SignUpFinishOutput,
+    SignUpOutput,
+};
+use application::usecase::channel::{PublishOutput, SubscribeOutput};
+use async_graphql::ErrorExtensions;
+use async_trait::async_trait;
+use cookie::Cookie;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use kernel::error::Codes;
+use kernel::Result;
+use tokio_stream::wrappers::ReceiverStream;
+use validator::ValidationErrors;
+
+/// presents outputs as resolver results. errors carry the error code of the http api's problem
+/// details in the `code` extension.
+#[derive(Clone, Default)]
+pub(crate) struct GraphQLPresenter;
+
+type GraphQLResult<T> = async_graphql::Result<T>;
+
+#[async_trait]
+impl Present<Result<GetAccountOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<Option<Account>>;
+
+    async fn present(&self, data: Result<GetAccountOutput>, _: ()) -> Self::Output {
+        Ok(data.map_err(to_error)?.account.map(Account::from))
+    }
+}
+#[async_trait]
+impl Present<Result<SignUpOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<bool>;
+
+    async fn present(&self, data: Result<SignUpOutput>, _: ()) -> Self::Output {
+        data.map_err(to_error).map(|_| true)
+    }
+}
+#[async_trait]
+impl Present<Result<SignUpFinishOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<Cookie<'static>>;
+
+    async fn present(&self, data: Result<SignUpFinishOutput>, _: ()) -> Self::Output {
+        let SignUpFinishOutput { session_id } = data.map_err(to_error)?;
+        Ok(set_session_cookie(session_id, None))
+    }
+}
+#[async_trait]
+impl Present<Result<SignInOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<Cookie<'static>>;
+
+    async fn present(&self, data: Result<SignInOutput>, _: ()) -> Self::Output {
+        let SignInOutput {
+            session_id,
+            remember_me,
+        } = data.map_err(to_error)?;
+        let max_age = remember_me.then(|| time::Duration::seconds(86400 * 10));
+        Ok(set_session_cookie(session_id, max_age))
+    }
+}
+#[async_trait]
+impl Present<Result<SignOutOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<Cookie<'static>>;
+
+    async fn present(&self, data: Result<SignOutOutput>, _: ()) -> Self::Output {
+        data.map_err(to_error).map(|_| delete_session_cookie())
+    }
+}
+#[async_trait]
+impl Present<Result<ForgetPasswordOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<Cookie<'static>>;
+
+    async fn present(&self, data: Result<ForgetPasswordOutput>, _: ()) -> Self::Output {
+        data.map_err(to_error).map(|_| delete_session_cookie())
+    }
+}
+#[async_trait]
+impl Present<Result<ResetPasswordOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<bool>;
+
+    async fn present(&self, data: Result<ResetPasswordOutput>, _: ()) -> Self::Output {
+        data.map_err(to_error).map(|_| true)
+    }
+}
+#[async_trait]
+impl Present<Result<PublishOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<Message>;
+
+    async fn present(&self, data: Result<PublishOutput>, _: ()) -> Self::Output {
+        Ok(data.map_err(to_error)?.message.into())
+    }
+}
+#[async_trait]
+impl Present<Result<SubscribeOutput>> for GraphQLPresenter {
+    type Output = GraphQLResult<BoxStream<'static, String>>;
+
+    async fn present(&self, data: Result<SubscribeOutput>, _: ()) -> Self::Output {
+        let output = data.map_err(to_error)?;
+        let subscription = GaugeGuard::new(&GRAPHQL_SUBSCRIPTIONS);
+        Ok(ReceiverStream::new(output.rx)
+            .map(move |msg| {
+                // the gauge is decremented as the stream is dropped.
+                let _ = &subscription;
+                String::from_utf8_lossy(msg.as_slice()).to_string()
+            })
+            .take_until(shutdown::started())
+            .boxed())
+    }
+}
+
+impl GraphQLPresenter {
+    /// the message falls back to the title of the code in the locale of the request.
+    pub(crate) fn error(type_code: &Codes, message: Option<String>) -> async_graphql::Error {
+        async_graphql::Error::new(
+            message.unwrap_or_else(|| type_code.localized_title(current_locale())),
+        )
+        .extend_with(|_, e| e.set("code", type_code.to_string()))
+    }
+}
+
+fn to_error(err: anyhow::Error) -> async_graphql::Error {
+    if let Some(usecase_err) = err.downcast_ref::<kernel::Error>() {
+        match usecase_err {
+            kernel::Error::Unexpected(..) => log::error!("{:?}", err),
+            _ => log::warn!("{}", err),
+        }
+        let message = match usecase_err {
+            kernel::Error::BadRequest(_, message)
+            | kernel::Error::Unauthorized(_, message)
+            | kernel::Error::Forbidden(_, message)
+            | kernel::Error::NotFound(_, message)
+            | kernel::Error::Unexpected(_, message) => message.clone(),
+        };
+        GraphQLPresenter::error(usecase_err.codes(), message)
+    } else if let Some(errors) = err.downcast_ref::<ValidationErrors>() {
+        log::warn!("{}", err);
+        GraphQLPresenter::error(&Codes::ValidationFailed, Some(errors.to_string()))
+    } else {
+        log::error!("{:?}", err);
+        GraphQLPresenter::error(&Codes::CommonUnexpected, None)
+    }
+}
@@ -0,0 +1,215 @@
+use crate::dispatch_to;
+use crate::http::server::graphql::presenter::GraphQLPresenter;
+use crate::http::server::graphql::{Mods, RequestSession, SiteUrl};
+use crate::http::server::middleware::locale::RequestLocale;
+use application::usecase::account::GetAccountInput;
+use application::usecase::auth::{
+    ForgetPasswordInput, ResetPasswordInput, SignInInput, SignOutInput, SignUpFinishInput,
+    SignUpInput,
+};
+use application::usecase::channel::{PublishInput, SubscribeInput};
+use async_graphql::{Context, Object, SimpleObject, Subscription};
+use axum::http::header::SET_COOKIE;
+use cookie::Cookie;
+use derive_new::new;
+use futures::stream::BoxStream;
+use kernel::entity;
+use kernel::error::Codes;
+use kernel::i18n::Locale;
+
+#[derive(SimpleObject)]
+pub(crate) struct Account {
+    id: String,
+    name: String,
+    display_name: String,
+}
+
+impl From<entity::Account> for Account {
+    fn from(account: entity::Account) -> Self {
+        Account {
+            id: account.id,
+            name: account.name,
+            display_name: account.display_name,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub(crate) struct Session {
+    id: String,
+    account: Account,
+}
+
+/// times are RFC 3339, as in the rest of the api.
+#[derive(SimpleObject)]
+pub(crate) struct Message {
+    id: String,
+    channel_id: String,
+    account_id: String,
+    body: String,
+    parent_id: Option<String>,
+    reply_count: i64,
+    create_time: String,
+    update_time: String,
+}
+
+impl From<entity::Message> for Message {
+    fn from(message: entity::Message) -> Self {
+        Message {
+            id: message.id,
+            channel_id: message.channel_id,
+            account_id: message.account_id,
+            body: message.body,
+            parent_id: message.parent_id,
+            reply_count: message.reply_count,
+            create_time: message.create_time.to_rfc3339(),
+            update_time: message.update_time.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(new)]
+pub(crate) struct QueryRoot<M> {
+    mods: M,
+}
+
+#[Object]
+impl<M: Mods> QueryRoot<M> {
+    /// the account with the id, or the account of the session when the id is `"me"`.
+    async fn account(
+        &self,
+        ctx: &Context<'_>,
+        id: String,
+    ) -> async_graphql::Result<Option<Account>> {
+        let input = GetAccountInput::new(id, session(ctx)?.map(|s| s.id));
+        dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await
+    }
+
+    async fn session(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<Session>> {
+        Ok(session(ctx)?.map(|session| Session {
+            id: session.id,
+            account: session.account.into(),
+        }))
+    }
+}
+
+#[derive(new)]
+pub(crate) struct MutationRoot<M> {
+    mods: M,
+}
+
+#[Object]
+impl<M: Mods> MutationRoot<M> {
+    async fn signup(
+        &self,
+        ctx: &Context<'_>,
+        mail: String,
+        password: String,
+    ) -> async_graphql::Result<bool> {
+        let SiteUrl(site_url) = ctx.data::<SiteUrl>()?;
+        let input = SignUpInput::new(mail, password, site_url.clone(), locale(ctx));
+        dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await
+    }
+
+    async fn signup_finish(&self, ctx: &Context<'_>, code: String) -> async_graphql::Result<bool> {
+        let cookie = dispatch_to(
+            SignUpFinishInput::new(code, locale(ctx)),
+            (),
+            self.mods.clone(),
+            &GraphQLPresenter,
+        )
+        .await?;
+        set_cookie(ctx, cookie)
+    }
+
+    async fn signin(
+        &self,
+        ctx: &Context<'_>,
+        mail: String,
+        password: String,
+        #[graphql(default)] remember_me: bool,
+    ) -> async_graphql::Result<bool> {
+        let input = SignInInput::new(mail, password, remember_me, locale(ctx));
+        let cookie = dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await?;
+        set_cookie(ctx, cookie)
+    }
+
+    async fn signout(&self, ctx: &Context<'_>) -> async_graphql::Result<bool> {
+        let session = require_session(ctx)?;
+        let input = SignOutInput::new(session.id);
+        let cookie = dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await?;
+        set_cookie(ctx, cookie)
+    }
+
+    async fn forget_password(
+        &self,
+        ctx: &Context<'_>,
+        mail: String,
+    ) -> async_graphql::Result<bool> {
+        let SiteUrl(site_url) = ctx.data::<SiteUrl>()?;
+        let input = ForgetPasswordInput::new(mail, site_url.clone(), locale(ctx));
+        let cookie = dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await?;
+        set_cookie(ctx, cookie)
+    }
+
+    async fn reset_password(
+        &self,
+        ctx: &Context<'_>,
+        code: String,
+        password: String,
+    ) -> async_graphql::Result<bool> {
+        let input = ResetPasswordInput::new(code, password, locale(ctx));
+        dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await
+    }
+
+    async fn publish(
+        &self,
+        ctx: &Context<'_>,
+        channel_id: String,
+        body: String,
+        parent_id: Option<String>,
+    ) -> async_graphql::Result<Message> {
+        let session = require_session(ctx)?;
+        let input = PublishInput::new(channel_id, session.account.id, parent_id, body.into_bytes());
+        dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await
+    }
+}
+
+#[derive(new)]
+pub(crate) struct SubscriptionRoot<M> {
+    mods: M,
+}
+
+#[Subscription]
+impl<M: Mods> SubscriptionRoot<M> {
+    /// the messages published to the channel, until the server shuts down.
+    async fn channel(
+        &self,
+        ctx: &Context<'_>,
+        channel_id: String,
+    ) -> async_graphql::Result<BoxStream<'static, String>> {
+        let session = require_session(ctx)?;
+        let input = SubscribeInput::new(channel_id, Some(session.account.id));
+        dispatch_to(input, (), self.mods.clone(), &GraphQLPresenter).await
+    }
+}
+
+fn locale(ctx: &Context<'_>) -> Locale {
+    ctx.data_opt::<RequestLocale>()
+        .map(|RequestLocale(locale)| *locale)
+        .unwrap_or_default()
+}
+
+fn session(ctx: &Context<'_>) -> async_graphql::Result<Option<entity::Session>> {
+    let RequestSession(session) = ctx.data::<RequestSession>()?;
+    Ok(session.clone())
+}
+
+fn require_session(ctx: &Context<'_>) -> async_graphql::Result<entity::Session> {
+    session(ctx)?.ok_or_else(|| GraphQLPresenter::error(&Codes::SessionRequired, None))
+}
+
+fn set_cookie(ctx: &Context<'_>, cookie: Cookie<'static>) -> async_graphql::Result<bool> {
+    ctx.append_http_header(SET_COOKIE, cookie.to_string());
+    Ok(true)
+}
@@ -0,0 +1,96 @@
+use crate::http::server::api::config::Cors;
+use axum::http::header::RETRY_AFTER;
+use axum::http::HeaderName;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// headers set by our middlewares that scripts on other origins may read.
+static EXPOSE_HEADERS: [HeaderName; 5] = [
+    HeaderName::from_static("x-request-id"),
+    HeaderName::from_static("ratelimit-limit"),
+    HeaderName::from_static("ratelimit-remaining"),
+    HeaderName::from_static("ratelimit-reset"),
+    RETRY_AFTER,
+];
+
+pub(crate) fn cors_layer(config: &Cors) -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(config.allow_origins.clone()))
+        .allow_methods(config.allow_methods.clone())
+        .allow_headers(config.allow_headers.clone())
+        .allow_credentials(config.allow_credentials)
+        .expose_headers(EXPOSE_HEADERS.clone())
+        .max_age(config.max_age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cors_layer;
+    use crate::http::server::api::config::{Cors, Csrf};
+    use crate::http::server::middleware::csrf::csrf_protection;
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode};
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::post;
+    use axum::Router;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    #[test]
+    fn wildcard_origin_is_rejected() {
+        let cors = |origins: &str| {
+            Cors::new(
+                vec![origins.to_string()],
+                vec!["POST".to_string()],
+                vec!["content-type".to_string()],
+                true,
+                Duration::from_secs(600),
+            )
+        };
+        assert!(cors("*").is_err());
+        assert!(cors("https://app.example.com").is_ok());
+    }
+
+    #[tokio::test]
+    async fn preflight_is_answered_before_csrf_protection() {
+        let csrf = Csrf::new(
+            vec!["api.example.com".to_string()],
+            vec!["https://app.example.com".to_string()],
+            vec![],
+            false,
+        );
+        let cors = Cors::new(
+            vec!["https://app.example.com".to_string()],
+            vec!["POST".to_string()],
+            vec!["content-type".to_string(), "x-from".to_string()],
+            true,
+            Duration::from_secs(600),
+        )
+        .unwrap();
+        let app = Router::new()
+            .route("/", post(|| async { "ok" }))
+            .layer(from_fn_with_state(Arc::new(csrf), csrf_protection))
+            .layer(cors_layer(&cors));
+        let preflight = Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/")
+            .header("host", "api.example.com")
+            .header("origin", "https://app.example.com")
+            .header("access-control-request-method", "POST")
+            .header("access-control-request-headers", "x-from")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(preflight).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(
+            headers["access-control-allow-origin"],
+            "https://app.example.com"
+        );
+        assert_eq!(headers["access-control-allow-credentials"], "true");
+        assert!(headers["access-control-allow-headers"]
+            .to_str()
+            .unwrap()
+            .contains("x-from"));
+    }
+}
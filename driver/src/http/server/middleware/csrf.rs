@@ -1,55 +1,148 @@
-use crate::http::server::middleware::{get_header, require_header};
-use crate::http::server::response::response_with_code;
+use crate::http::server::api::config::{normalize_origin, Csrf};
+use crate::http::server::middleware::{get_cookie, get_header, original_path, require_header};
+use crate::http::server::response::{constants, problem, response_with_code, WithSetCookie};
+use axum::body::{Body, Bytes};
+use axum::extract::State;
 use axum::headers::HeaderName;
-use axum::http::header::{ACCEPT, HOST, ORIGIN, SEC_WEBSOCKET_PROTOCOL};
-use axum::http::{Request, StatusCode};
+use axum::http::header::{ACCEPT, CONTENT_TYPE, HOST, ORIGIN, SEC_WEBSOCKET_PROTOCOL};
+use axum::http::{Method, Request, StatusCode};
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
-use helper::env::get_var;
+use cookie::{CookieBuilder, SameSite};
+use helper::uuid;
+use helper::uuid::ToBase62;
+use http_body::{LengthLimitError, Limited};
+use kernel::error::Codes;
 use kernel::Result;
 use log;
+use std::collections::HashMap;
 use std::string::ToString;
-
-static CSRF_CHECK_WHITELIST: &[&str] = &["/api/v1/status"];
+use std::sync::Arc;
 
 static X_FROM: HeaderName = HeaderName::from_static("x-from");
+static X_CSRF_TOKEN: HeaderName = HeaderName::from_static("x-csrf-token");
+static CSRF_TOKEN_FIELD: &str = "csrf_token";
+/// forms are read whole to find the token, larger uploads are expected to send the header instead.
+const MAX_FORM_BYTES: usize = 64 * 1024;
 
-pub(crate) async fn csrf_protection<B>(
-    request: Request<B>,
-    next: Next<B>,
+pub(crate) async fn csrf_protection(
+    State(config): State<Arc<Csrf>>,
+    request: Request<Body>,
+    next: Next<Body>,
 ) -> Result<Response, CSRFError> {
     // TODO refactor to interactor
-    if CSRF_CHECK_WHITELIST.contains(&request.uri().path()) {
+    if config.exempt_paths.contains(original_path(&request)) {
         return Ok(next.run(request).await);
     }
     let headers = request.headers();
     let host = require_header(headers, &HOST)?;
-    let env_hosts: String = get_var::<String>("CSRF_ALLOW_SITE_HOSTS")?;
-    if !env_hosts.contains(&host) {
-        return Ok(response_with_code(
-            StatusCode::FORBIDDEN,
-            format!("invalid host: {host}"),
-        ));
+    if !config.allow_hosts.contains(&host.to_ascii_lowercase()) {
+        return Ok(reject(format!("invalid host: {host}")));
+    }
+    if let Some(origin) = get_header(headers, &ORIGIN)? {
+        if !config.allow_origins.contains(&normalize_origin(&origin)) {
+            return Ok(reject(format!("invalid origin: {origin}")));
+        }
     }
     let is_websocket = get_header(headers, &SEC_WEBSOCKET_PROTOCOL)?.is_some();
     let is_event_stream =
         get_header(headers, &ACCEPT)? == Some(mime::TEXT_EVENT_STREAM.to_string());
     let x_from = get_header(headers, &X_FROM)?;
-    let env_urls: String = get_var::<String>("CSRF_ALLOW_X_FROM")?;
-    match x_from {
-        Some(_) => match get_header(headers, &ORIGIN)? {
-            Some(origin) if !env_urls.contains(&origin) => Ok(response_with_code(
-                StatusCode::FORBIDDEN,
-                format!("invalid origin: {origin}"),
-            )),
-            _ => Ok(next.run(request).await),
+    let token = get_cookie(headers, constants::CSRF_COOKIE_ID);
+    let request = match x_from {
+        Some(_) => request,
+        _ if is_websocket || is_event_stream => request,
+        // safe requests change nothing, and are how form-only clients get the cookie to submit.
+        _ if config.double_submit && is_safe(request.method()) => request,
+        _ if config.double_submit => match double_submit(request, token.as_deref()).await? {
+            Ok(request) => request,
+            Err(rejection) => return Ok(rejection),
         },
-        _ if is_websocket || is_event_stream => Ok(next.run(request).await),
-        _ => Ok(response_with_code(
-            StatusCode::FORBIDDEN,
-            format!("invalid x-from: {x_from:?}"),
-        )),
+        _ => return Ok(reject(format!("invalid x-from: {x_from:?}"))),
+    };
+    let response = next.run(request).await;
+    if config.double_submit && token.is_none() {
+        return Ok(response.with_cookie(csrf_cookie()));
+    }
+    Ok(response)
+}
+
+/// passes the request on when the csrf cookie is echoed in the `x-csrf-token` header,
+/// or in the `csrf_token` field of a url-encoded form, and rejects it otherwise.
+async fn double_submit(
+    request: Request<Body>,
+    token: Option<&str>,
+) -> Result<std::result::Result<Request<Body>, Response>> {
+    let invalid = || reject("invalid csrf token".to_string());
+    let Some(token) = token else {
+        return Ok(Err(invalid()));
+    };
+    if let Some(submitted) = get_header(request.headers(), &X_CSRF_TOKEN)? {
+        return Ok(if constant_time_eq(&submitted, token) {
+            Ok(request)
+        } else {
+            Err(invalid())
+        });
+    }
+    let is_form = get_header(request.headers(), &CONTENT_TYPE)?
+        .and_then(|content_type| content_type.parse::<mime::Mime>().ok())
+        .map(|content_type| content_type.essence_str() == mime::APPLICATION_WWW_FORM_URLENCODED)
+        .unwrap_or(false);
+    if !is_form {
+        return Ok(Err(invalid()));
     }
+    let (parts, body) = request.into_parts();
+    let body: Bytes = match hyper::body::to_bytes(Limited::new(body, MAX_FORM_BYTES)).await {
+        Ok(body) => body,
+        Err(e) if e.is::<LengthLimitError>() => {
+            return Ok(Err(problem(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                &Codes::PayloadTooLarge,
+                Some(format!("a form must be at most {MAX_FORM_BYTES} bytes.")),
+                vec![],
+            )))
+        }
+        Err(e) => return Err(anyhow::anyhow!(e)),
+    };
+    let submitted = serde_urlencoded::from_bytes::<HashMap<String, String>>(&body)
+        .ok()
+        .and_then(|mut form| form.remove(CSRF_TOKEN_FIELD));
+    match submitted {
+        Some(submitted) if constant_time_eq(&submitted, token) => {
+            Ok(Ok(Request::from_parts(parts, Body::from(body))))
+        }
+        _ => Ok(Err(invalid())),
+    }
+}
+
+fn is_safe(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+/// readable from scripts so that single page apps can echo it in `x-csrf-token`.
+fn csrf_cookie() -> cookie::Cookie<'static> {
+    CookieBuilder::new(constants::CSRF_COOKIE_ID, uuid::new_v4().to_base62())
+        .secure(true)
+        .path("/")
+        .same_site(SameSite::Strict)
+        .finish()
+}
+
+fn reject(message: String) -> Response {
+    problem(
+        StatusCode::FORBIDDEN,
+        &Codes::CsrfRejected,
+        Some(message),
+        vec![],
+    )
 }
 
 #[derive(Debug)]
@@ -67,3 +160,130 @@ impl From<anyhow::Error> for CSRFError {
         CSRFError(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::csrf_protection;
+    use crate::http::server::api::config::Csrf;
+    use crate::http::server::api::contract::{config, TestMods};
+    use crate::http::server::api::route::define_route;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::middleware::from_fn_with_state;
+    use axum::routing::get;
+    use axum::Router;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    fn app(double_submit: bool) -> Router {
+        let config = Csrf::new(
+            vec!["example.com".to_string()],
+            vec!["https://example.com/".to_string()],
+            vec![],
+            double_submit,
+        );
+        Router::new()
+            .route("/", get(|| async { "ok" }).post(|| async { "ok" }))
+            .layer(from_fn_with_state(Arc::new(config), csrf_protection))
+    }
+
+    async fn status(app: Router, request: Request<Body>) -> StatusCode {
+        app.oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn allow_lists_match_exactly() {
+        let request = |host: &str, origin: &str| {
+            Request::post("/")
+                .header("host", host)
+                .header("origin", origin)
+                .header("x-from", "web")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let ok = request("example.com", "https://example.com");
+        assert_eq!(status(app(false), ok).await, StatusCode::OK);
+        let evil_host = request("evil-example.com", "https://example.com");
+        assert_eq!(status(app(false), evil_host).await, StatusCode::FORBIDDEN);
+        let evil_origin = request("example.com", "https://example.com.evil.net");
+        assert_eq!(status(app(false), evil_origin).await, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn default_exempt_paths_pass_through_the_api_routes() {
+        let csrf = Csrf::new(
+            vec!["localhost".to_string()],
+            vec![],
+            vec![Csrf::DEFAULT_EXEMPT_PATHS.to_string()],
+            false,
+        );
+        let app = define_route(&config(csrf), TestMods::default());
+        let request = |method: &str, path: &str| {
+            Request::builder()
+                .method(method)
+                .uri(path)
+                .header("host", "localhost")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let probe = request("GET", "/api/v1/status");
+        assert_eq!(status(app.clone(), probe).await, StatusCode::OK);
+        // rejected by the route rather than the csrf protection.
+        let probe = request("POST", "/api/v1/status");
+        assert_eq!(
+            status(app.clone(), probe).await,
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+        let protected = request("POST", "/api/v1/auth/signin");
+        assert_eq!(status(app, protected).await, StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn form_post_requires_double_submit_token() {
+        let request = |field: &str| {
+            Request::post("/")
+                .header("host", "example.com")
+                .header("cookie", "csrf=token")
+                .header("content-type", "application/x-www-form-urlencoded")
+                .body(Body::from(format!("name=a&csrf_token={field}")))
+                .unwrap()
+        };
+        assert_eq!(
+            status(app(false), request("token")).await,
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(status(app(true), request("token")).await, StatusCode::OK);
+        assert_eq!(
+            status(app(true), request("other")).await,
+            StatusCode::FORBIDDEN
+        );
+        let oversized = Request::post("/")
+            .header("host", "example.com")
+            .header("cookie", "csrf=token")
+            .header("content-type", "application/x-www-form-urlencoded")
+            .body(Body::from(format!(
+                "name={}&csrf_token=token",
+                "a".repeat(super::MAX_FORM_BYTES)
+            )))
+            .unwrap();
+        assert_eq!(
+            status(app(true), oversized).await,
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+
+    #[tokio::test]
+    async fn safe_request_issues_double_submit_cookie() {
+        let request = || {
+            Request::get("/")
+                .header("host", "example.com")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = app(true).oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let cookie = response.headers()["set-cookie"].to_str().unwrap();
+        assert!(cookie.starts_with("csrf="));
+        assert_eq!(status(app(false), request()).await, StatusCode::FORBIDDEN);
+    }
+}
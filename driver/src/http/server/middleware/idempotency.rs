@@ -0,0 +1,476 @@
+use crate::http::server::middleware::{get_cookie, get_header};
+use crate::http::server::response::{constants, problem};
+use application::interface::Component;
+use application::usecase::idempotency::{
+    BeginIdempotencyInput, BeginIdempotencyOutput, CompleteIdempotencyInput,
+    CompleteIdempotencyOutput, IdempotencyState,
+};
+use application::usecase::{UseCase, UseUseCase};
+use axum::body::{Body, Bytes};
+use axum::http::header::{CONTENT_LANGUAGE, CONTENT_TYPE};
+use axum::http::{HeaderName, HeaderValue, Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use derive_new::new;
+use futures::future::BoxFuture;
+use http_body::{Body as _, LengthLimitError, Limited};
+use kernel::entity::IdempotentResponse;
+use kernel::error::Codes;
+use std::task::{Context, Poll};
+use tower::{Layer, Service};
+use trait_set::trait_set;
+
+static IDEMPOTENCY_KEY: HeaderName = HeaderName::from_static("idempotency-key");
+static IDEMPOTENT_REPLAYED: HeaderName = HeaderName::from_static("idempotent-replayed");
+
+const MAX_KEY_LENGTH: usize = 255;
+/// the default limit of the axum extractors, so that no body a handler accepts is refused here.
+/// responses larger than this are passed through without being stored.
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+/// the only headers replayed, the others belong to the request that was answered first.
+static STORED_HEADERS: [HeaderName; 2] = [CONTENT_TYPE, CONTENT_LANGUAGE];
+
+trait_set! {
+    pub trait State = Component
+    + UseUseCase<BeginIdempotencyInput, BeginIdempotencyOutput>
+    + UseUseCase<CompleteIdempotencyInput, CompleteIdempotencyOutput>;
+}
+
+/// replays the first response for an `Idempotency-Key` to retries from the same session, or
+/// to retries of the same request without one, answering `409 Conflict` while that first
+/// request is still running.
+/// server errors release the key so that the retry runs again, and a failing store lets requests
+/// through as if they had no key.
+#[derive(Clone, new)]
+pub struct IdempotencyLayer<M> {
+    mods: M,
+}
+
+#[derive(Clone)]
+pub struct IdempotencyMiddleware<S, M> {
+    inner: S,
+    mods: M,
+}
+
+impl<S, M: Clone> Layer<S> for IdempotencyLayer<M> {
+    type Service = IdempotencyMiddleware<S, M>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        IdempotencyMiddleware {
+            inner,
+            mods: self.mods.clone(),
+        }
+    }
+}
+
+impl<S, M> Service<Request<Body>> for IdempotencyMiddleware<S, M>
+where
+    S: Service<Request<Body>, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    M: State,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // the clone is not ready, so keep the service that was polled for this request.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let mods = self.mods.clone();
+        Box::pin(async move {
+            let key = match get_header(request.headers(), &IDEMPOTENCY_KEY) {
+                Ok(Some(key)) if !key.is_empty() && key.len() <= MAX_KEY_LENGTH => key,
+                Ok(None) => return inner.call(request).await,
+                _ => {
+                    return Ok(problem(
+                        StatusCode::BAD_REQUEST,
+                        &Codes::CommonBadRequest,
+                        Some(format!(
+                            "{} must be 1 to {} visible ascii characters.",
+                            IDEMPOTENCY_KEY, MAX_KEY_LENGTH
+                        )),
+                        vec![],
+                    ))
+                }
+            };
+            let (parts, body) = request.into_parts();
+            let body = match hyper::body::to_bytes(Limited::new(body, MAX_BODY_BYTES)).await {
+                Ok(body) => body,
+                Err(e) if e.is::<LengthLimitError>() => {
+                    return Ok(problem(
+                        StatusCode::PAYLOAD_TOO_LARGE,
+                        &Codes::PayloadTooLarge,
+                        Some(format!(
+                            "a request with {} must be at most {} bytes.",
+                            IDEMPOTENCY_KEY, MAX_BODY_BYTES
+                        )),
+                        vec![],
+                    ))
+                }
+                Err(e) => {
+                    log::warn!("request body read error: {:?}", e);
+                    return Ok(StatusCode::BAD_REQUEST.into_response());
+                }
+            };
+            let fingerprint = helper::auth::sha256(
+                &[
+                    parts.method.as_str().as_bytes(),
+                    parts.uri.path().as_bytes(),
+                    &body[..],
+                ]
+                .join(&b'\n'),
+            );
+            let input = BeginIdempotencyInput::new(
+                key,
+                get_cookie(&parts.headers, constants::SESSION_COOKIE_ID),
+                fingerprint.clone(),
+            );
+            let request = Request::from_parts(parts, Body::from(body));
+            let begin = UseUseCase::<BeginIdempotencyInput, BeginIdempotencyOutput>::usecase(&mods);
+            let output = match begin.handle(input).await {
+                Ok(output) => output,
+                Err(e) => {
+                    log::warn!("idempotency key is not applied: {:?}", e);
+                    return inner.call(request).await;
+                }
+            };
+            let key = match output.state {
+                IdempotencyState::Started => output.key,
+                IdempotencyState::Replay(response) => return Ok(replay(response)),
+                IdempotencyState::InProgress => {
+                    return Ok(problem(
+                        StatusCode::CONFLICT,
+                        &Codes::IdempotencyConflict,
+                        None,
+                        vec![],
+                    ))
+                }
+                IdempotencyState::Mismatch => {
+                    return Ok(problem(
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        &Codes::IdempotencyKeyReused,
+                        None,
+                        vec![],
+                    ))
+                }
+            };
+            let (parts, body) = inner.call(request).await?.into_parts();
+            let complete = |stored| async {
+                let input = CompleteIdempotencyInput::new(key, fingerprint, stored);
+                let completion =
+                    UseUseCase::<CompleteIdempotencyInput, CompleteIdempotencyOutput>::usecase(
+                        &mods,
+                    );
+                if let Err(e) = completion.handle(input).await {
+                    log::warn!("idempotency key is not completed: {:?}", e);
+                }
+            };
+            // streamed or large bodies are not buffered, the key is released for them instead.
+            let buffered =
+                matches!(body.size_hint().upper(), Some(size) if size <= MAX_BODY_BYTES as u64);
+            if !buffered {
+                complete(None).await;
+                return Ok(Response::from_parts(parts, body));
+            }
+            let body = hyper::body::to_bytes(body).await;
+            let stored = match &body {
+                Ok(body) if !parts.status.is_server_error() => Some(IdempotentResponse::new(
+                    parts.status.as_u16(),
+                    parts
+                        .headers
+                        .iter()
+                        .filter(|(name, _)| STORED_HEADERS.contains(name))
+                        .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec()))
+                        .collect(),
+                    body.to_vec(),
+                )),
+                _ => None,
+            };
+            complete(stored).await;
+            match body {
+                Ok(body) => Ok(Response::from_parts(
+                    parts,
+                    axum::body::boxed(Body::from(body)),
+                )),
+                Err(e) => {
+                    log::warn!("response body read error: {:?}", e);
+                    Ok(problem(
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        &Codes::CommonUnexpected,
+                        None,
+                        vec![],
+                    ))
+                }
+            }
+        })
+    }
+}
+
+fn replay(stored: IdempotentResponse) -> Response {
+    let mut response = Response::new(axum::body::boxed(Body::from(Bytes::from(stored.body))));
+    *response.status_mut() = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    let headers = response.headers_mut();
+    for (name, value) in stored.headers {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::from_bytes(&value))
+        {
+            headers.append(name, value);
+        }
+    }
+    headers.insert(
+        IDEMPOTENT_REPLAYED.clone(),
+        HeaderValue::from_static("true"),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IdempotencyLayer, MAX_BODY_BYTES};
+    use application::interface::config::{Auth, Config, ConfigBuilder, System};
+    use application::interface::repository::idempotency::{
+        IdempotencyRepository, UseIdempotencyRepository,
+    };
+    use application::interface::{UseConfig, UseContext};
+    use application::usecase::idempotency::{
+        BeginIdempotencyInput, BeginIdempotencyOutput, BeginIdempotencyUseCase,
+        CompleteIdempotencyInput, CompleteIdempotencyOutput, CompleteIdempotencyUseCase,
+    };
+    use application::usecase::UseUseCase;
+    use async_trait::async_trait;
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use axum::response::{IntoResponse, Response};
+    use axum::routing::post;
+    use axum::Router;
+    use kernel::entity::IdempotencyRecord;
+    use kernel::Result;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use tokio::sync::Notify;
+    use tower::ServiceExt;
+
+    #[derive(Clone, Default)]
+    struct MemoryRepository(Arc<Mutex<HashMap<String, IdempotencyRecord>>>);
+
+    #[async_trait]
+    impl IdempotencyRepository<()> for MemoryRepository {
+        async fn insert(
+            &self,
+            _: (),
+            key: String,
+            record: IdempotencyRecord,
+            _: Duration,
+        ) -> Result<Option<IdempotencyRecord>> {
+            let mut records = self.0.lock().unwrap();
+            match records.get(&key) {
+                Some(existing) => Ok(Some(existing.clone())),
+                None => {
+                    records.insert(key, record);
+                    Ok(None)
+                }
+            }
+        }
+        async fn set(
+            &self,
+            _: (),
+            key: String,
+            record: IdempotencyRecord,
+            _: Duration,
+        ) -> Result<()> {
+            self.0.lock().unwrap().insert(key, record);
+            Ok(())
+        }
+        async fn delete(&self, _: (), key: String) -> Result<()> {
+            self.0.lock().unwrap().remove(&key);
+            Ok(())
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct TestMods {
+        repository: MemoryRepository,
+    }
+    #[async_trait]
+    impl UseContext for TestMods {
+        type Context = ();
+        async fn context(&self) -> Result<Self::Context> {
+            Ok(())
+        }
+    }
+    impl UseConfig for TestMods {
+        fn config(&self) -> Config {
+            ConfigBuilder::default()
+                .auth(Auth::default())
+                .system(System::default())
+                .build()
+                .unwrap()
+        }
+    }
+    impl UseIdempotencyRepository<()> for TestMods {
+        type IdempotencyRepository = MemoryRepository;
+        fn idempotency_repository(&self) -> Self::IdempotencyRepository {
+            self.repository.clone()
+        }
+    }
+    impl UseUseCase<BeginIdempotencyInput, BeginIdempotencyOutput> for TestMods {
+        type UseCase = BeginIdempotencyUseCase<(), TestMods>;
+        fn usecase(&self) -> Self::UseCase {
+            BeginIdempotencyUseCase::new(self.clone())
+        }
+    }
+    impl UseUseCase<CompleteIdempotencyInput, CompleteIdempotencyOutput> for TestMods {
+        type UseCase = CompleteIdempotencyUseCase<(), TestMods>;
+        fn usecase(&self) -> Self::UseCase {
+            CompleteIdempotencyUseCase::new(self.clone())
+        }
+    }
+
+    /// answers with a body and headers that differ on every call.
+    fn app(calls: Arc<AtomicUsize>) -> Router {
+        let handler = move || async move {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            (
+                StatusCode::CREATED,
+                [
+                    ("content-type", "application/json".to_string()),
+                    ("x-request-id", format!("request-{call}")),
+                    ("set-cookie", format!("sid=session-{call}")),
+                ],
+                format!(r#"{{"call":{call}}}"#),
+            )
+        };
+        Router::new()
+            .route("/", post(handler))
+            .layer(IdempotencyLayer::new(TestMods::default()))
+    }
+
+    fn request(key: &str, body: impl Into<Body>) -> Request<Body> {
+        Request::post("/")
+            .header("cookie", "sid=session")
+            .header("idempotency-key", key)
+            .body(body.into())
+            .unwrap()
+    }
+
+    /// without a session, nor a tracking id as when the client did not consent.
+    fn anonymous_request(key: &str, body: impl Into<Body>) -> Request<Body> {
+        Request::post("/")
+            .header("idempotency-key", key)
+            .body(body.into())
+            .unwrap()
+    }
+
+    async fn into_parts(response: Response) -> (StatusCode, Vec<(String, Vec<u8>)>, Vec<u8>) {
+        let (parts, body) = response.into_parts();
+        let headers = parts
+            .headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.as_bytes().to_vec()))
+            .collect();
+        let body = hyper::body::to_bytes(body).await.unwrap().to_vec();
+        (parts.status, headers, body)
+    }
+
+    #[tokio::test]
+    async fn retry_replays_the_first_response() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(calls.clone());
+        let first = app.clone().oneshot(request("key", "{}")).await.unwrap();
+        let (status, _, body) = into_parts(first).await;
+        let retry = app.oneshot(request("key", "{}")).await.unwrap();
+        let (replayed_status, headers, replayed_body) = into_parts(retry).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(replayed_status, status);
+        assert_eq!(replayed_body, body);
+        let names: Vec<_> = headers.iter().map(|(name, _)| name.as_str()).collect();
+        assert!(names.contains(&"content-type"));
+        assert!(names.contains(&"idempotent-replayed"));
+        assert!(!names.contains(&"x-request-id"));
+        assert!(!names.contains(&"set-cookie"));
+    }
+
+    #[tokio::test]
+    async fn retry_without_session_replays_the_first_response() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = app(calls.clone());
+        let first = app.clone().oneshot(anonymous_request("key", "{}")).await;
+        let (status, _, body) = into_parts(first.unwrap()).await;
+        let retry = app.clone().oneshot(anonymous_request("key", "{}")).await;
+        let (replayed_status, _, replayed_body) = into_parts(retry.unwrap()).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!((replayed_status, replayed_body), (status, body));
+        // the same key from a session is another key.
+        app.oneshot(request("key", "{}")).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn key_reused_for_another_request_is_rejected() {
+        let app = app(Arc::new(AtomicUsize::new(0)));
+        let first = app.clone().oneshot(request("key", "{}")).await.unwrap();
+        assert_eq!(first.status(), StatusCode::CREATED);
+        let reused = app.oneshot(request("key", r#"{"other":1}"#)).await.unwrap();
+        assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn key_in_progress_conflicts() {
+        let (started, release) = (Arc::new(Notify::new()), Arc::new(Notify::new()));
+        let handler = {
+            let (started, release) = (started.clone(), release.clone());
+            move || async move {
+                started.notify_one();
+                release.notified().await;
+                StatusCode::CREATED.into_response()
+            }
+        };
+        let app = Router::new()
+            .route("/", post(handler))
+            .layer(IdempotencyLayer::new(TestMods::default()));
+        let first = tokio::spawn(app.clone().oneshot(request("key", "{}")));
+        started.notified().await;
+        let concurrent = app.oneshot(request("key", "{}")).await.unwrap();
+        assert_eq!(concurrent.status(), StatusCode::CONFLICT);
+        release.notify_one();
+        assert_eq!(first.await.unwrap().unwrap().status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn server_error_releases_the_key() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let handler = {
+            let calls = calls.clone();
+            move || async move {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => StatusCode::SERVICE_UNAVAILABLE,
+                    _ => StatusCode::CREATED,
+                }
+            }
+        };
+        let mods = TestMods::default();
+        let app = Router::new()
+            .route("/", post(handler))
+            .layer(IdempotencyLayer::new(mods.clone()));
+        let failed = app.clone().oneshot(request("key", "{}")).await.unwrap();
+        assert_eq!(failed.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(mods.repository.0.lock().unwrap().is_empty());
+        let retry = app.oneshot(request("key", "{}")).await.unwrap();
+        assert_eq!(retry.status(), StatusCode::CREATED);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(mods.repository.0.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn oversized_body_is_rejected() {
+        let app = app(Arc::new(AtomicUsize::new(0)));
+        let body = vec![b'a'; MAX_BODY_BYTES + 1];
+        let response = app.oneshot(request("key", body)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
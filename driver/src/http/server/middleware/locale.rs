@@ -0,0 +1,104 @@
+use crate::http::server::middleware::session::ResolvedSession;
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::extract::FromRequestParts;
+use axum::http::header::ACCEPT_LANGUAGE;
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, Request};
+use axum::middleware::Next;
+use axum::response::Response;
+use kernel::i18n::Locale;
+use std::convert::Infallible;
+use std::future::Future;
+
+tokio::task_local! {
+    static CURRENT_LOCALE: Locale;
+}
+
+/// the locale resolved by `scope_locale`, or the default one outside of it.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RequestLocale(pub Locale);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for RequestLocale {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<RequestLocale>()
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+/// prefers the locale of the signed in account over `Accept-Language`, and makes it visible to
+/// presenters while the request is handled.
+pub(crate) async fn scope_locale(mut request: Request<Body>, next: Next<Body>) -> Response {
+    let account_locale = match request.extensions().get::<ResolvedSession>() {
+        Some(ResolvedSession(Some(session))) => session.account.locale,
+        _ => None,
+    };
+    let locale = account_locale
+        .or_else(|| accept_language(request.headers()))
+        .unwrap_or_default();
+    request.extensions_mut().insert(RequestLocale(locale));
+    with_locale(locale, next.run(request)).await
+}
+
+/// makes `locale` visible to presenters, for work that outlives the request like websockets.
+pub(crate) async fn with_locale<F: Future>(locale: Locale, future: F) -> F::Output {
+    CURRENT_LOCALE.scope(locale, future).await
+}
+
+pub(crate) fn current_locale() -> Locale {
+    CURRENT_LOCALE
+        .try_with(|locale| *locale)
+        .unwrap_or_default()
+}
+
+/// the supported language of the highest quality, matched by its primary subtag.
+fn accept_language(headers: &HeaderMap) -> Option<Locale> {
+    let header = headers.get(ACCEPT_LANGUAGE)?.to_str().ok()?;
+    header
+        .split(',')
+        .filter_map(|range| {
+            let mut params = range.split(';');
+            let language = params.next()?.trim().split('-').next()?;
+            let locale = language.to_ascii_lowercase().parse::<Locale>().ok()?;
+            let quality = match params.find_map(|param| param.trim().strip_prefix("q=")) {
+                Some(quality) => quality.trim().parse::<f32>().ok()?,
+                None => 1.0,
+            };
+            (quality > 0.0).then_some((locale, quality))
+        })
+        // the first of equal qualities wins.
+        .fold(None, |best, (locale, quality)| match best {
+            Some((_, best_quality)) if best_quality >= quality => best,
+            _ => Some((locale, quality)),
+        })
+        .map(|(locale, _)| locale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::accept_language;
+    use axum::http::header::ACCEPT_LANGUAGE;
+    use axum::http::{HeaderMap, HeaderValue};
+    use kernel::i18n::Locale;
+
+    fn negotiate(value: &'static str) -> Option<Locale> {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static(value));
+        accept_language(&headers)
+    }
+
+    #[test]
+    fn negotiates_supported_language_by_quality() {
+        assert_eq!(negotiate("ja-JP,ja;q=0.9,en;q=0.8"), Some(Locale::Ja));
+        assert_eq!(negotiate("fr-FR, en-US;q=0.5, ja;q=0.7"), Some(Locale::Ja));
+        assert_eq!(negotiate("en, ja"), Some(Locale::En));
+        assert_eq!(negotiate("ja;q=0, en;q=0.1"), Some(Locale::En));
+        assert_eq!(negotiate("fr, *;q=0.5"), None);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::metrics::{HTTP_REQUESTS, HTTP_REQUEST_DURATION};
+use axum::extract::MatchedPath;
+use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
+use std::time::Instant;
+
+/// counts requests and their latencies by the matched route, so that path parameters do not
+/// blow up the label cardinality.
+pub(crate) async fn track_metrics<B>(request: Request<B>, next: Next<B>) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "unmatched".to_string(), |path| path.as_str().to_string());
+    let method = request.method().to_string();
+    let start = Instant::now();
+    let response = next.run(request).await;
+    let status = response.status().as_u16().to_string();
+    let labels = [method.as_str(), route.as_str(), status.as_str()];
+    HTTP_REQUESTS.with_label_values(&labels).inc();
+    HTTP_REQUEST_DURATION
+        .with_label_values(&labels)
+        .observe(start.elapsed().as_secs_f64());
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::track_metrics;
+    use crate::metrics::HTTP_REQUESTS;
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::middleware::from_fn;
+    use axum::routing::get;
+    use axum::Router;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn requests_are_counted_by_matched_route() {
+        let app = Router::new()
+            .route("/tracked/:id", get(|| async { "ok" }))
+            .layer(from_fn(track_metrics));
+        let count = |route: &str, status: &str| {
+            HTTP_REQUESTS
+                .with_label_values(&["GET", route, status])
+                .get()
+        };
+        let (matched, unmatched) = (count("/tracked/:id", "200"), count("unmatched", "404"));
+        for uri in ["/tracked/1", "/tracked/2", "/untracked"] {
+            let request = Request::get(uri).body(Body::empty()).unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+        assert_eq!(count("/tracked/:id", "200"), matched + 2);
+        assert_eq!(count("unmatched", "404"), unmatched + 1);
+    }
+}
@@ -1,12 +1,22 @@
 use anyhow::bail;
+use axum::extract::OriginalUri;
 use axum::headers::{HeaderMap, HeaderName};
+use axum::http::header::COOKIE;
+use axum::http::Request;
 pub use axum::middleware::*;
+use cookie::Cookie;
 
 use kernel::unexpected;
 
+pub mod cors;
 pub mod csrf;
+pub mod idempotency;
+pub mod locale;
+pub mod metrics;
+pub mod rate_limit;
 pub mod request_id;
 pub mod session;
+pub mod telemetry;
 pub mod tracking;
 
 pub(crate) fn get_header(
@@ -32,3 +42,23 @@ pub(crate) fn require_header(
     };
     Ok(value)
 }
+
+pub(crate) fn get_cookie(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|cookie| cookie.to_str().ok())
+        .flat_map(Cookie::split_parse_encoded)
+        .filter_map(|cookie| cookie.ok())
+        .find(|cookie| cookie.name() == name)
+        .map(|cookie| cookie.value().to_string())
+}
+
+/// the path the client requested. the layers of the nested api router see it without the
+/// `/api/v1` prefix in `uri()`, so configured paths are matched against this one.
+pub(crate) fn original_path<B>(request: &Request<B>) -> &str {
+    match request.extensions().get::<OriginalUri>() {
+        Some(OriginalUri(uri)) => uri.path(),
+        None => request.uri().path(),
+    }
+}
@@ -0,0 +1,253 @@
+use crate::http::server::middleware::locale::current_locale;
+use crate::http::server::middleware::original_path;
+use crate::http::server::middleware::session::ResolvedSession;
+use crate::http::server::middleware::tracking::Tracking;
+use crate::http::server::response::problem;
+use application::interface::Component;
+use application::usecase::rate_limit::{RateLimitDecision, RateLimitInput, RateLimitOutput};
+use application::usecase::{UseCase, UseUseCase};
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::header::RETRY_AFTER;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Request, StatusCode};
+use axum::response::Response;
+use derive_new::new;
+use futures::future::BoxFuture;
+use kernel::error::Codes;
+use std::net::SocketAddr;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tower::{Layer, Service};
+use trait_set::trait_set;
+
+static RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+static RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+static RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+trait_set! {
+    pub trait State = Component + UseUseCase<RateLimitInput, RateLimitOutput>;
+}
+
+/// enforces the configured rate limit rules, answering `429 Too Many Requests` once a bucket is empty.
+/// a failing rate limit store lets requests through rather than taking the api down with it.
+#[derive(Clone, new)]
+pub struct RateLimitLayer<M> {
+    mods: M,
+}
+
+#[derive(Clone)]
+pub struct RateLimitMiddleware<S, M> {
+    inner: S,
+    mods: M,
+}
+
+impl<S, M: Clone> Layer<S> for RateLimitLayer<M> {
+    type Service = RateLimitMiddleware<S, M>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitMiddleware {
+            inner,
+            mods: self.mods.clone(),
+        }
+    }
+}
+
+impl<S, M> Service<Request<Body>> for RateLimitMiddleware<S, M>
+where
+    S: Service<Request<Body>, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    M: State,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // the clone is not ready, so keep the service that was polled for this request.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let mods = self.mods.clone();
+        Box::pin(async move {
+            // only an existing session has a bucket, the ip or tracking one applies otherwise.
+            let session_id = match request.extensions().get::<ResolvedSession>() {
+                Some(ResolvedSession(Some(session))) => Some(session.id.clone()),
+                _ => None,
+            };
+            let input = RateLimitInput::new(
+                request.method().to_string(),
+                original_path(&request).to_string(),
+                request
+                    .extensions()
+                    .get::<ConnectInfo<SocketAddr>>()
+                    .map(|ConnectInfo(addr)| addr.ip().to_string()),
+                request
+                    .extensions()
+                    .get::<Tracking>()
+                    .map(|Tracking(tracking)| tracking.id.clone()),
+                session_id,
+            );
+            let decision = match UseUseCase::<RateLimitInput, RateLimitOutput>::usecase(&mods)
+                .handle(input)
+                .await
+            {
+                Ok(output) => output.decision,
+                Err(e) => {
+                    log::warn!("rate limit is not applied: {:?}", e);
+                    None
+                }
+            };
+            let Some(decision) = decision else {
+                return inner.call(request).await;
+            };
+            let mut response = if decision.bucket.allowed {
+                inner.call(request).await?
+            } else {
+                let mut response = problem(
+                    StatusCode::TOO_MANY_REQUESTS,
+                    &Codes::RateLimited,
+                    Some(current_locale().message("rate-limited-detail")),
+                    vec![],
+                );
+                response.headers_mut().insert(
+                    RETRY_AFTER,
+                    HeaderValue::from(seconds(decision.bucket.retry_after)),
+                );
+                response
+            };
+            insert_rate_limit_headers(response.headers_mut(), &decision);
+            Ok(response)
+        })
+    }
+}
+
+fn insert_rate_limit_headers(headers: &mut HeaderMap, decision: &RateLimitDecision) {
+    headers.insert(RATELIMIT_LIMIT.clone(), HeaderValue::from(decision.limit));
+    headers.insert(
+        RATELIMIT_REMAINING.clone(),
+        HeaderValue::from(decision.bucket.remaining),
+    );
+    headers.insert(
+        RATELIMIT_RESET.clone(),
+        HeaderValue::from(seconds(decision.bucket.reset_after)),
+    );
+}
+
+/// whole seconds, rounded up so that a client waiting that long finds a token.
+fn seconds(duration: Duration) -> u64 {
+    duration.as_secs_f64().ceil() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::server::api::config::Csrf;
+    use crate::http::server::api::contract::{config, TestMods};
+    use crate::http::server::api::route::define_route;
+    use application::interface::config::{RateLimit, RateLimitBuilder};
+    use axum::body::Body;
+    use axum::http::header::{CONTENT_TYPE, COOKIE, HOST, RETRY_AFTER};
+    use axum::http::{Request, StatusCode};
+    use axum::response::Response;
+    use axum::Router;
+    use kernel::entity::{RateLimitKey, RateLimitRule};
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    fn app(rules: Vec<RateLimitRule>) -> Router {
+        let rate_limit = RateLimitBuilder::default()
+            .rules(rules)
+            .exempt_paths(vec![RateLimit::DEFAULT_EXEMPT_PATHS.to_string()])
+            .build()
+            .unwrap();
+        let csrf = Csrf::new(vec!["localhost".to_string()], vec![], vec![], false);
+        define_route(&config(csrf), TestMods::with_rate_limit(rate_limit))
+    }
+
+    /// the rules are written with the `/api/v1` prefix, as they are configured.
+    fn rule(path: &str, capacity: u32) -> RateLimitRule {
+        RateLimitRule {
+            path: path.to_string(),
+            methods: vec!["GET".to_string()],
+            key: RateLimitKey::Session,
+            capacity,
+            refill_per_second: 1.0,
+        }
+    }
+
+    async fn get(app: &Router, path: &str) -> Response {
+        let request = Request::get(path)
+            .header(HOST, "localhost")
+            .header("x-from", "test")
+            .header(COOKIE, "sid=test")
+            .body(Body::empty())
+            .unwrap();
+        app.clone().oneshot(request).await.unwrap()
+    }
+
+    fn header<'a>(response: &'a Response, name: &str) -> Option<&'a str> {
+        response
+            .headers()
+            .get(name)
+            .map(|value| value.to_str().unwrap())
+    }
+
+    #[tokio::test]
+    async fn empty_bucket_is_answered_with_too_many_requests() {
+        let app = app(vec![rule("/api/v1/account/:id", 2)]);
+        let first = get(&app, "/api/v1/account/me").await;
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(header(&first, "ratelimit-limit"), Some("2"));
+        assert_eq!(header(&first, "ratelimit-remaining"), Some("1"));
+        assert_eq!(header(&first, "ratelimit-reset"), Some("1"));
+        let second = get(&app, "/api/v1/account/me").await;
+        assert_eq!(second.status(), StatusCode::OK);
+        assert_eq!(header(&second, "ratelimit-remaining"), Some("0"));
+
+        let limited = get(&app, "/api/v1/account/me").await;
+        assert_eq!(limited.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(header(&limited, "ratelimit-limit"), Some("2"));
+        assert_eq!(header(&limited, "ratelimit-remaining"), Some("0"));
+        assert_eq!(header(&limited, "ratelimit-reset"), Some("2"));
+        // 1.5 seconds are rounded up.
+        assert_eq!(header(&limited, RETRY_AFTER.as_str()), Some("2"));
+        assert_eq!(
+            header(&limited, CONTENT_TYPE.as_str()),
+            Some("application/problem+json")
+        );
+        let body = hyper::body::to_bytes(limited.into_body()).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["status"], 429);
+        assert_eq!(body["code"], "common/rate_limited");
+        assert_eq!(body["title"], "Too many requests");
+    }
+
+    #[tokio::test]
+    async fn exempt_and_unmatched_paths_are_not_limited() {
+        let exempt = app(vec![rule("/api/v1/*", 1)]);
+        for _ in 0..3 {
+            let status = get(&exempt, "/api/v1/status").await;
+            assert_eq!(status.status(), StatusCode::OK);
+            assert_eq!(header(&status, "ratelimit-limit"), None);
+        }
+        // matched by the rule once the prefix is there, so the second request is limited.
+        let prefixed = app(vec![rule("/api/v1/account/me", 1)]);
+        assert_eq!(
+            get(&prefixed, "/api/v1/account/me").await.status(),
+            StatusCode::OK
+        );
+        assert_eq!(
+            get(&prefixed, "/api/v1/account/me").await.status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // a rule without the prefix matches nothing.
+        let unprefixed = app(vec![rule("/account/me", 1)]);
+        for _ in 0..2 {
+            let response = get(&unprefixed, "/api/v1/account/me").await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(header(&response, "ratelimit-limit"), None);
+        }
+    }
+}
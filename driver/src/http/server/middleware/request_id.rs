@@ -1,8 +1,14 @@
 use axum::http::Request;
+use axum::middleware::Next;
+use axum::response::Response;
 use helper::uuid;
 use helper::uuid::ToBase62;
 pub use tower_http::request_id::*;
 
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: String;
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct MakeRequestBase62Uuid;
 
@@ -11,3 +17,23 @@ impl MakeRequestId for MakeRequestBase62Uuid {
         uuid::new_v4().to_base62().parse().ok().map(RequestId::new)
     }
 }
+
+/// makes the request id set by `SetRequestIdLayer` visible to presenters while the request is handled.
+pub(crate) async fn scope_request_id<B>(request: Request<B>, next: Next<B>) -> Response {
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .and_then(|id| id.header_value().to_str().ok())
+        .map(ToString::to_string);
+    match request_id {
+        Some(request_id) => {
+            let response = next.run(request);
+            CURRENT_REQUEST_ID.scope(request_id, response).await
+        }
+        None => next.run(request).await,
+    }
+}
+
+pub(crate) fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
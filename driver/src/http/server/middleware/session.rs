@@ -3,19 +3,44 @@ use crate::http::server::response::constants;
 use application::usecase::session::{GetSessionInput, GetSessionOutput};
 use application::usecase::{UseCase, UseUseCase};
 use async_trait::async_trait;
+use axum::body::Body;
 use axum::extract::FromRequestParts;
 use axum::http::header::COOKIE;
 use axum::http::request::Parts;
-use axum::http::StatusCode;
+use axum::http::{HeaderMap, Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
 use cookie::Cookie;
+use futures::future::BoxFuture;
 use kernel::entity::Session;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
 use trait_set::trait_set;
 
 pub struct ExtractSession(pub Option<Session>);
 pub struct RequireSession(pub Session);
 
+/// the session of the cookie, looked up once per request by [`resolve_session`] for the layers
+/// and extractors after it.
+#[derive(Clone)]
+pub(crate) struct ResolvedSession(pub(crate) Option<Session>);
+
+/// resolves once the session watched by [`watch_session`] is revoked or expired.
+/// a struct rather than an alias, so that presenter bounds do not name a trait object.
+pub(crate) struct SessionEnd(BoxFuture<'static, ()>);
+
+impl Future for SessionEnd {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.0.as_mut().poll(cx)
+    }
+}
+
 trait_set! {
-    trait State = Send + Sync + UseUseCase<GetSessionInput, GetSessionOutput>;
+    pub(crate) trait State = Send + Sync + UseUseCase<GetSessionInput, GetSessionOutput>;
 }
 
 #[async_trait]
@@ -23,7 +48,7 @@ impl<S: State> FromRequestParts<S> for ExtractSession {
     type Rejection = (StatusCode, &'static str);
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        Ok(ExtractSession(get_session(parts, state).await))
+        Ok(ExtractSession(session(parts, state).await))
     }
 }
 
@@ -32,15 +57,56 @@ impl<S: State> FromRequestParts<S> for RequireSession {
     type Rejection = (StatusCode, &'static str);
 
     async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
-        match get_session(parts, state).await {
+        match session(parts, state).await {
             Some(session) => Ok(RequireSession(session)),
             _ => Err((StatusCode::BAD_REQUEST, "invalid session")),
         }
     }
 }
 
-async fn get_session<S: State>(parts: &mut Parts, state: &S) -> Option<Session> {
-    for cookie in parts.headers.get_all(COOKIE) {
+/// checks the session every `interval` for long-lived responses that outlive the request.
+/// a failing store keeps the session alive, the next check decides.
+pub(crate) fn watch_session<S: State + 'static>(
+    state: S,
+    session_id: String,
+    interval: Duration,
+) -> SessionEnd {
+    SessionEnd(Box::pin(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            match state
+                .usecase()
+                .handle(GetSessionInput::new(session_id.clone()))
+                .await
+            {
+                Ok(GetSessionOutput { session: None }) => return,
+                Ok(_) => (),
+                Err(e) => log::warn!("session check error: {:?}", e),
+            }
+        }
+    }))
+}
+
+pub(crate) async fn resolve_session<S: State + Clone>(
+    axum::extract::State(state): axum::extract::State<S>,
+    mut request: Request<Body>,
+    next: Next<Body>,
+) -> Response {
+    let session = get_session(request.headers(), &state).await;
+    request.extensions_mut().insert(ResolvedSession(session));
+    next.run(request).await
+}
+
+/// the resolved session, or a lookup of its own on routes without [`resolve_session`].
+async fn session<S: State>(parts: &Parts, state: &S) -> Option<Session> {
+    match parts.extensions.get::<ResolvedSession>() {
+        Some(ResolvedSession(session)) => session.clone(),
+        None => get_session(&parts.headers, state).await,
+    }
+}
+
+async fn get_session<S: State>(headers: &HeaderMap, state: &S) -> Option<Session> {
+    for cookie in headers.get_all(COOKIE) {
         let Ok(cookie) = cookie.to_str() else {
             continue
         };
@@ -63,3 +129,114 @@ async fn get_session<S: State>(parts: &mut Parts, state: &S) -> Option<Session>
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_session, watch_session, RequireSession};
+    use crate::fixture::{account, fixtures};
+    use crate::http::server::middleware::locale::{scope_locale, RequestLocale};
+    use anyhow::anyhow;
+    use application::usecase::session::{GetSessionInput, GetSessionOutput};
+    use axum::body::Body;
+    use axum::http::Request;
+    use axum::middleware::{from_fn, from_fn_with_state};
+    use axum::routing::get;
+    use axum::Router;
+    use chrono::Utc;
+    use kernel::entity::Session;
+    use kernel::i18n::Locale;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use tokio::time::timeout;
+    use tower::ServiceExt;
+
+    const INTERVAL: Duration = Duration::from_millis(10);
+
+    #[derive(Clone)]
+    struct Active;
+    #[derive(Clone)]
+    struct Revoked;
+    #[derive(Clone)]
+    struct Failing;
+    #[derive(Clone)]
+    struct Counting;
+
+    static LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+
+    fixtures! {
+        Active {
+            GetSessionInput => GetSessionOutput = || {
+                Ok(GetSessionOutput::new(Some(Session::new(
+                    "sid".to_string(),
+                    account(),
+                    Utc::now(),
+                ))))
+            };
+        }
+    }
+    fixtures! {
+        Revoked {
+            GetSessionInput => GetSessionOutput = || Ok(GetSessionOutput::new(None));
+        }
+    }
+    fixtures! {
+        Failing {
+            GetSessionInput => GetSessionOutput = || Err(anyhow!("connection refused"));
+        }
+    }
+    fixtures! {
+        Counting {
+            GetSessionInput => GetSessionOutput = || {
+                LOOKUPS.fetch_add(1, Ordering::SeqCst);
+                let mut account = account();
+                account.locale = Some(Locale::Ja);
+                Ok(GetSessionOutput::new(Some(Session::new(
+                    "sid".to_string(),
+                    account,
+                    Utc::now(),
+                ))))
+            };
+        }
+    }
+
+    #[tokio::test]
+    async fn revoked_session_ends() {
+        let session_end = watch_session(Revoked, "sid".to_string(), INTERVAL);
+        assert!(timeout(Duration::from_secs(5), session_end).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn active_session_does_not_end() {
+        let session_end = watch_session(Active, "sid".to_string(), INTERVAL);
+        assert!(timeout(INTERVAL * 10, session_end).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn failing_store_keeps_the_session() {
+        let session_end = watch_session(Failing, "sid".to_string(), INTERVAL);
+        assert!(timeout(INTERVAL * 10, session_end).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn session_is_looked_up_once_per_request() {
+        async fn handler(
+            RequireSession(session): RequireSession,
+            RequestLocale(locale): RequestLocale,
+        ) -> String {
+            format!("{} {}", session.id, locale)
+        }
+        let app = Router::new()
+            .route("/", get(handler))
+            .layer(from_fn(scope_locale))
+            .layer(from_fn_with_state(Counting, resolve_session::<Counting>))
+            .with_state(Counting);
+        let request = Request::get("/")
+            .header("cookie", "sid=sid")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(body, "sid ja");
+        assert_eq!(LOOKUPS.load(Ordering::SeqCst), 1);
+    }
+}
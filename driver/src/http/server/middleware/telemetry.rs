@@ -0,0 +1,146 @@
+use crate::http::server::middleware::tracking::Tracking;
+use axum::extract::MatchedPath;
+use axum::http::{HeaderMap, Request};
+use axum::response::Response;
+use opentelemetry::global;
+use opentelemetry::propagation::Extractor;
+use std::time::Duration;
+use tower_http::request_id::RequestId;
+use tower_http::trace::{MakeSpan, OnResponse};
+use tracing::field::Empty;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// makes a server span continuing the W3C `traceparent` of the request, if any.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MakeRequestSpan;
+
+impl<B> MakeSpan<B> for MakeRequestSpan {
+    fn make_span(&mut self, request: &Request<B>) -> Span {
+        let route = request
+            .extensions()
+            .get::<MatchedPath>()
+            .map(|path| path.as_str().to_string());
+        let request_id = request
+            .extensions()
+            .get::<RequestId>()
+            .and_then(|id| id.header_value().to_str().ok());
+        let tracking_id = request
+            .extensions()
+            .get::<Tracking>()
+            .map(|Tracking(data)| data.id.as_str());
+        let name = format!(
+            "{} {}",
+            request.method(),
+            route.as_deref().unwrap_or("unmatched")
+        );
+        let span = tracing::info_span!(
+            "http request",
+            otel.name = %name,
+            otel.kind = "server",
+            http.method = %request.method(),
+            http.target = %request.uri(),
+            http.route = route.as_deref(),
+            http.status_code = Empty,
+            request_id = request_id,
+            tracking_id = tracking_id,
+        );
+        let parent = global::get_text_map_propagator(|propagator| {
+            propagator.extract(&HeaderExtractor(request.headers()))
+        });
+        span.set_parent(parent);
+        span
+    }
+}
+
+/// records the status code on the span made by [`MakeRequestSpan`].
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RecordStatus;
+
+impl<B> OnResponse<B> for RecordStatus {
+    fn on_response(self, response: &Response<B>, latency: Duration, span: &Span) {
+        span.record("http.status_code", response.status().as_u16());
+        tracing::debug!(
+            status = response.status().as_u16(),
+            ?latency,
+            "finished processing request"
+        );
+    }
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl<'a> Extractor for HeaderExtractor<'a> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MakeRequestSpan;
+    use axum::http::Request;
+    use futures::future::BoxFuture;
+    use opentelemetry::global;
+    use opentelemetry::sdk::export::trace::{ExportResult, SpanData, SpanExporter};
+    use opentelemetry::sdk::propagation::TraceContextPropagator;
+    use opentelemetry::sdk::trace::TracerProvider;
+    use opentelemetry::trace::{SpanId, TraceId, TracerProvider as _};
+    use std::sync::{Arc, Mutex};
+    use tower_http::trace::MakeSpan;
+    use tracing_subscriber::prelude::*;
+
+    /// an in-process collector keeping the exported spans.
+    #[derive(Clone, Debug, Default)]
+    struct Collector(Arc<Mutex<Vec<SpanData>>>);
+
+    impl SpanExporter for Collector {
+        fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+            self.0.lock().unwrap().extend(batch);
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    #[test]
+    fn request_span_continues_traceparent() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let collector = Collector::default();
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(collector.clone())
+            .build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let request = Request::get("/api/v1/status")
+            .header(
+                "traceparent",
+                "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+            )
+            .body(())
+            .unwrap();
+        tracing::subscriber::with_default(subscriber, || {
+            let span = MakeRequestSpan.make_span(&request);
+            span.in_scope(|| tracing::info_span!("usecase").in_scope(|| ()));
+        });
+        // the simple exporter sends from its own thread, which shutting down waits for.
+        drop(provider);
+
+        let spans = collector.0.lock().unwrap();
+        let trace_id = TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap();
+        let server = spans
+            .iter()
+            .find(|span| span.name == "GET unmatched")
+            .unwrap();
+        assert_eq!(server.span_context.trace_id(), trace_id);
+        assert_eq!(
+            server.parent_span_id,
+            SpanId::from_hex("00f067aa0ba902b7").unwrap()
+        );
+        let usecase = spans.iter().find(|span| span.name == "usecase").unwrap();
+        assert_eq!(usecase.span_context.trace_id(), trace_id);
+        assert_eq!(usecase.parent_span_id, server.span_context.span_id());
+    }
+}
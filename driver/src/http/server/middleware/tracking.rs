@@ -1,14 +1,15 @@
-use crate::http::server::response::constants;
+use crate::http::server::api::config;
+use crate::http::server::middleware::{get_cookie, get_header};
+use crate::http::server::response::{constants, WithSetCookie};
 use axum::body::Body;
-use axum::headers::HeaderValue;
-use axum::http::header::{COOKIE, SET_COOKIE};
 
 use async_trait::async_trait;
 use axum::extract::FromRequestParts;
 use axum::http::request::Parts;
-use axum::http::{Request, StatusCode};
+use axum::http::{HeaderMap, HeaderName, Request, StatusCode};
 use axum::response::Response;
 use cookie::{Cookie, CookieBuilder, SameSite};
+use derive_new::new;
 use futures::future::BoxFuture;
 use helper::uuid;
 use helper::uuid::ToBase62;
@@ -23,12 +24,21 @@ pub struct TrackingData {
     pub id: String,
 }
 
-#[derive(Clone, Default)]
-pub struct TrackingLayer;
+static CONSENT_HEADER: HeaderName = HeaderName::from_static("x-tracking-consent");
+static CONSENT_GRANTED: &str = "granted";
+static CONSENT_DENIED: &str = "denied";
+
+/// identifies the client with a cookie, which is withheld until the client consents
+/// when the config requires it, and withdrawn when the client denies.
+#[derive(Clone, new)]
+pub struct TrackingLayer {
+    config: config::Tracking,
+}
 
 #[derive(Clone)]
 pub struct TrackingMiddleware<S> {
     inner: S,
+    config: config::Tracking,
 }
 
 #[async_trait]
@@ -50,7 +60,10 @@ impl<S> Layer<S> for TrackingLayer {
     type Service = TrackingMiddleware<S>;
 
     fn layer(&self, inner: S) -> Self::Service {
-        TrackingMiddleware { inner }
+        TrackingMiddleware {
+            inner,
+            config: self.config.clone(),
+        }
     }
 }
 
@@ -68,49 +81,187 @@ where
     }
 
     fn call(&mut self, mut request: Request<Body>) -> Self::Future {
-        let tracking = get_tracking_data(&request).unwrap_or_else(|| {
-            Tracking(TrackingData {
-                id: uuid::new_v4().to_base62(),
-            })
+        let config = self.config.clone();
+        let id = get_cookie(request.headers(), &config.cookie_name);
+        let consented = match get_consent(request.headers()) {
+            Some(consent) => consent,
+            None => !config.require_consent,
+        };
+        if !consented {
+            let future = self.inner.call(request);
+            return Box::pin(async move {
+                let response = future.await?;
+                Ok(match id {
+                    Some(_) => {
+                        let mut removal = tracking_cookie(&config, String::new());
+                        removal.make_removal();
+                        response.with_cookie(removal)
+                    }
+                    None => response,
+                })
+            });
+        }
+        let tracking = Tracking(TrackingData {
+            id: id.unwrap_or_else(|| uuid::new_v4().to_base62()),
         });
         request.extensions_mut().insert(tracking.clone());
         let future = self.inner.call(request);
         Box::pin(async move {
-            let mut response: Response = future.await?;
-            let builder = CookieBuilder::new(constants::TRACKING_COOKIE_ID, tracking.0.id);
-            let c = builder
-                .secure(true)
-                .path("/")
-                .max_age(time::Duration::seconds(86400 * 365))
-                .same_site(SameSite::Strict)
-                .finish();
-            response.headers_mut().append(
-                SET_COOKIE,
-                HeaderValue::from_str(c.to_string().as_str()).unwrap(),
-            );
-            Ok(response)
+            let response = future.await?;
+            Ok(response.with_cookie(tracking_cookie(&config, tracking.0.id)))
         })
     }
 }
 
-fn get_tracking_data(request: &Request<Body>) -> Option<Tracking> {
-    for cookie in request.headers().get_all(COOKIE) {
-        let Ok(cookie) = cookie.to_str() else {
-            continue
+fn tracking_cookie(config: &config::Tracking, id: String) -> Cookie<'static> {
+    let mut builder = CookieBuilder::new(config.cookie_name.clone(), id)
+        .secure(true)
+        .path("/")
+        .max_age(time::Duration::seconds(config.max_age.as_secs() as i64))
+        .same_site(SameSite::Strict);
+    if let Some(domain) = &config.domain {
+        builder = builder.domain(domain.clone());
+    }
+    builder.finish()
+}
+
+/// the header wins over the cookie, so that a client can answer before the cookie is stored.
+/// `None` when the client did not answer yet.
+fn get_consent(headers: &HeaderMap) -> Option<bool> {
+    let consent = get_header(headers, &CONSENT_HEADER)
+        .ok()
+        .flatten()
+        .or_else(|| get_cookie(headers, constants::CONSENT_COOKIE_ID))?;
+    match consent.trim() {
+        x if x.eq_ignore_ascii_case(CONSENT_GRANTED) => Some(true),
+        x if x.eq_ignore_ascii_case(CONSENT_DENIED) => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Tracking, TrackingLayer};
+    use crate::http::server::api::config;
+    use axum::body::Body;
+    use axum::http::header::{COOKIE, SET_COOKIE};
+    use axum::http::Request;
+    use axum::routing::get;
+    use axum::Router;
+    use cookie::Cookie;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    /// answers the tracking id the handler sees, empty when the request is not tracked.
+    fn app(config: config::Tracking) -> Router {
+        let handler = |request: Request<Body>| async move {
+            request
+                .extensions()
+                .get::<Tracking>()
+                .map(|Tracking(tracking)| tracking.id.clone())
+                .unwrap_or_default()
         };
-        for cookie in Cookie::split_parse_encoded(cookie) {
-            let Ok(cookie) = cookie else {
-                continue
-            };
-            if cookie.name() != constants::TRACKING_COOKIE_ID {
-                continue;
-            }
-
-            let data = TrackingData {
-                id: cookie.value().to_string(),
-            };
-            return Some(Tracking(data));
+        Router::new()
+            .route("/", get(handler))
+            .layer(TrackingLayer::new(config))
+    }
+
+    /// the tracking id seen by the handler and the tracking cookie set by the response.
+    async fn call(
+        config: config::Tracking,
+        headers: &[(&str, &str)],
+    ) -> (String, Option<Cookie<'static>>) {
+        let mut request = Request::get("/");
+        for (name, value) in headers {
+            request = request.header(*name, *value);
         }
+        let response = app(config.clone())
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let cookie = response
+            .headers()
+            .get_all(SET_COOKIE)
+            .iter()
+            .filter_map(|value| Cookie::parse(value.to_str().unwrap().to_string()).ok())
+            .find(|cookie| cookie.name() == config.cookie_name);
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (String::from_utf8(body.to_vec()).unwrap(), cookie)
+    }
+
+    #[tokio::test]
+    async fn without_consent_nothing_is_tracked() {
+        let (id, cookie) = call(config::Tracking::default(), &[]).await;
+        assert_eq!(id, "");
+        assert!(cookie.is_none());
+    }
+
+    #[tokio::test]
+    async fn consent_not_required_tracks_by_default() {
+        let config = config::Tracking {
+            require_consent: false,
+            ..Default::default()
+        };
+        let (id, cookie) = call(config, &[]).await;
+        assert!(!id.is_empty());
+        assert_eq!(cookie.unwrap().value(), id);
+    }
+
+    #[tokio::test]
+    async fn granted_consent_keeps_the_id() {
+        let (id, cookie) = call(
+            config::Tracking::default(),
+            &[(COOKIE.as_str(), "tid=known; consent=granted")],
+        )
+        .await;
+        assert_eq!(id, "known");
+        assert_eq!(cookie.unwrap().value(), "known");
+
+        let (id, cookie) = call(
+            config::Tracking::default(),
+            &[("x-tracking-consent", "Granted")],
+        )
+        .await;
+        assert!(!id.is_empty());
+        assert_eq!(cookie.unwrap().value(), id);
+    }
+
+    #[tokio::test]
+    async fn withdrawn_consent_removes_the_cookie() {
+        let (id, cookie) = call(
+            config::Tracking::default(),
+            &[
+                (COOKIE.as_str(), "tid=known; consent=granted"),
+                ("x-tracking-consent", "denied"),
+            ],
+        )
+        .await;
+        assert_eq!(id, "");
+        let cookie = cookie.unwrap();
+        assert_eq!(cookie.value(), "");
+        assert_eq!(cookie.max_age(), Some(time::Duration::ZERO));
+    }
+
+    #[tokio::test]
+    async fn cookie_has_the_configured_domain_and_max_age() {
+        let config = config::Tracking::new(
+            "tid".to_string(),
+            Duration::from_secs(60),
+            Some("example.com".to_string()),
+            true,
+        );
+        let (_, cookie) = call(config, &[("x-tracking-consent", "granted")]).await;
+        let cookie = cookie.unwrap();
+        assert_eq!(cookie.domain(), Some("example.com"));
+        assert_eq!(cookie.max_age(), Some(time::Duration::seconds(60)));
+        assert_eq!(cookie.secure(), Some(true));
+        assert_eq!(cookie.path(), Some("/"));
+
+        let (_, cookie) = call(
+            config::Tracking::default(),
+            &[("x-tracking-consent", "granted")],
+        )
+        .await;
+        assert_eq!(cookie.unwrap().domain(), None);
     }
-    None
 }
@@ -1,3 +1,6 @@
 pub mod api;
+pub(crate) mod graphql;
 pub(crate) mod middleware;
 pub(crate) mod response;
+pub(crate) mod shutdown;
+pub(crate) mod tls;
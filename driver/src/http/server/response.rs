@@ -1,7 +1,12 @@
-use axum::http::header::SET_COOKIE;
+use crate::http::server::middleware::{locale, request_id};
+use axum::http::header::{CONTENT_TYPE, SET_COOKIE};
 use axum::http::{HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
+use axum::Json;
 use cookie::Cookie;
+use kernel::error::{Codes, FieldViolation};
+use num_traits::ToPrimitive;
+use openapi::models::{ErrorMessage, InvalidParam};
 
 pub fn response_with_code<T>(code: StatusCode, body: T) -> Response
 where
@@ -10,6 +15,41 @@ where
     (code, body).into_response()
 }
 
+/// RFC 7807 problem details.
+pub(crate) fn problem(
+    code: StatusCode,
+    type_code: &Codes,
+    message: Option<String>,
+    violations: Vec<FieldViolation>,
+) -> Response {
+    let mut problem = ErrorMessage::new(
+        type_code.problem_type(),
+        type_code.localized_title(locale::current_locale()),
+        code.as_u16().to_i32().unwrap(),
+        type_code.to_string(),
+    );
+    problem.detail = message;
+    problem.request_id = request_id::current_request_id();
+    if !violations.is_empty() {
+        problem.invalid_params = Some(
+            violations
+                .into_iter()
+                .map(|v| {
+                    let mut param = InvalidParam::new(v.field, v.code);
+                    param.reason = v.message;
+                    param
+                })
+                .collect(),
+        );
+    }
+    let mut response = response_with_code(code, Json(problem));
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static(constants::PROBLEM_JSON_CONTENT_TYPE),
+    );
+    response
+}
+
 pub trait WithSetCookie {
     fn with_cookie(self, c: Cookie) -> Response;
 }
@@ -27,4 +67,8 @@ impl WithSetCookie for Response {
 pub mod constants {
     pub static SESSION_COOKIE_ID: &str = "sid";
     pub static TRACKING_COOKIE_ID: &str = "tid";
+    /// `granted` or `denied`, set by the client's consent banner.
+    pub static CONSENT_COOKIE_ID: &str = "consent";
+    pub static CSRF_COOKIE_ID: &str = "csrf";
+    pub static PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
 }
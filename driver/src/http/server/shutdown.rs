@@ -0,0 +1,154 @@
+//! process wide shutdown state of the http server.
+//! long lived responses (websocket and SSE) watch [`started`] to close themselves,
+//! and hold an [`InFlight`] guard while they still have work to flush.
+use std::sync::Arc;
+use tokio::sync::watch;
+
+#[derive(Clone)]
+struct State {
+    started: Arc<watch::Sender<bool>>,
+    in_flight: Arc<watch::Sender<usize>>,
+}
+
+impl State {
+    fn new() -> State {
+        State {
+            started: Arc::new(watch::channel(false).0),
+            in_flight: Arc::new(watch::channel(0).0),
+        }
+    }
+}
+
+#[cfg(not(test))]
+fn state() -> State {
+    static STATE: once_cell::sync::Lazy<State> = once_cell::sync::Lazy::new(State::new);
+    STATE.clone()
+}
+
+/// every test runs on a thread of its own with a current thread runtime, so it is given a state
+/// of its own and shuts down without closing the streams of the others.
+#[cfg(test)]
+fn state() -> State {
+    thread_local! {
+        static STATE: State = State::new();
+    }
+    STATE.with(State::clone)
+}
+
+/// waits for SIGTERM or SIGINT, then starts the shutdown.
+pub(crate) async fn signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            log::error!("install ctrl_c handler error: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(e) => {
+                log::error!("install SIGTERM handler error: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    tokio::select! {
+        _ = ctrl_c => (),
+        _ = terminate => (),
+    }
+    log::info!("shutdown signal received.");
+    start();
+}
+
+/// starts the shutdown.
+pub(crate) fn start() {
+    state().started.send_replace(true);
+}
+
+/// resolves once the shutdown has started.
+pub(crate) async fn started() {
+    let mut rx = state().started.subscribe();
+    while !*rx.borrow_and_update() {
+        // the sender is kept by the state and never dropped.
+        let _ = rx.changed().await;
+    }
+}
+
+/// resolves once every [`InFlight`] guard is dropped.
+pub(crate) async fn drained() {
+    let mut rx = state().in_flight.subscribe();
+    while *rx.borrow_and_update() > 0 {
+        let _ = rx.changed().await;
+    }
+}
+
+/// keeps the shutdown waiting until dropped.
+pub(crate) struct InFlight(State);
+
+impl InFlight {
+    pub(crate) fn new() -> InFlight {
+        let state = state();
+        state.in_flight.send_modify(|n| *n += 1);
+        InFlight(state)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.in_flight.send_modify(|n| *n -= 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{drained, start, started, InFlight};
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    const PENDING: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn started_resolves_once_the_shutdown_starts() {
+        assert!(timeout(PENDING, started()).await.is_err());
+        let waiting = tokio::spawn(started());
+        start();
+        waiting.await.unwrap();
+        // and right away afterwards.
+        started().await;
+    }
+
+    #[tokio::test]
+    async fn drained_waits_for_every_in_flight_guard() {
+        drained().await;
+        let first = InFlight::new();
+        let second = InFlight::new();
+        assert!(timeout(PENDING, drained()).await.is_err());
+        drop(first);
+        assert!(timeout(PENDING, drained()).await.is_err());
+        drop(second);
+        drained().await;
+    }
+
+    #[tokio::test]
+    async fn state_is_not_shared_between_tests() {
+        let _in_flight = InFlight::new();
+        start();
+        let other = std::thread::spawn(|| {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(async {
+                    drained().await;
+                    timeout(PENDING, started()).await.is_err()
+                })
+        });
+        assert!(other.join().unwrap());
+    }
+}
@@ -0,0 +1,163 @@
+//! rustls termination for the http server.
+use crate::http::server::api::config::Tls;
+use crate::http::server::shutdown;
+use anyhow::{bail, Context as _};
+use axum::extract::connect_info::IntoMakeServiceWithConnectInfo;
+use axum::Router;
+use axum_server::tls_rustls::RustlsConfig;
+use axum_server::Handle;
+use kernel::{unexpected, Result};
+use rustls::server::AllowAnyAuthenticatedClient;
+use rustls::{Certificate, PrivateKey, RootCertStore, ServerConfig};
+use rustls_pemfile::Item;
+use std::fs::File;
+use std::io::BufReader;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+/// serves https until the shutdown starts, reloading the certificates when the files change.
+pub(crate) async fn serve(
+    bind_address: SocketAddr,
+    tls: &Tls,
+    app: IntoMakeServiceWithConnectInfo<Router, SocketAddr>,
+) -> Result<()> {
+    let rustls_config = RustlsConfig::from_config(Arc::new(server_config(tls)?));
+    tokio::spawn(watch(tls.clone(), rustls_config.clone()));
+    let handle = Handle::new();
+    let cloned_handle = handle.clone();
+    tokio::spawn(async move {
+        shutdown::started().await;
+        cloned_handle.graceful_shutdown(None);
+    });
+    axum_server::bind_rustls(bind_address, rustls_config)
+        .handle(handle)
+        .serve(app)
+        .await
+        .with_context(|| unexpected!("server error"))
+}
+
+/// offers HTTP/2 through ALPN, and verifies client certificates when a client CA is set.
+pub(crate) fn server_config(tls: &Tls) -> Result<ServerConfig> {
+    let builder = ServerConfig::builder().with_safe_defaults();
+    let builder = match &tls.client_ca_path {
+        Some(path) => {
+            let mut roots = RootCertStore::empty();
+            for cert in read_certs(path)? {
+                roots.add(&cert)?;
+            }
+            builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+        }
+        None => builder.with_no_client_auth(),
+    };
+    let mut config =
+        builder.with_single_cert(read_certs(&tls.cert_path)?, read_key(&tls.key_path)?)?;
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(config)
+}
+
+/// polls the modification times, a broken file keeps the certificates already loaded.
+async fn watch(tls: Tls, rustls_config: RustlsConfig) {
+    let mut modified = modified_times(&tls);
+    let mut interval = tokio::time::interval(tls.reload_interval);
+    loop {
+        tokio::select! {
+            _ = interval.tick() => (),
+            _ = shutdown::started() => break,
+        }
+        let current = modified_times(&tls);
+        if current == modified {
+            continue;
+        }
+        match server_config(&tls) {
+            Ok(config) => {
+                rustls_config.reload_from_config(Arc::new(config));
+                modified = current;
+                log::info!("tls certificates are reloaded.");
+            }
+            Err(e) => log::error!("reload tls certificates error: {:?}", e),
+        }
+    }
+}
+
+fn modified_times(tls: &Tls) -> Vec<Option<SystemTime>> {
+    [
+        Some(&tls.cert_path),
+        Some(&tls.key_path),
+        tls.client_ca_path.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+    .collect()
+}
+
+fn read_certs(path: &Path) -> Result<Vec<Certificate>> {
+    let mut reader = open(path)?;
+    let certs = rustls_pemfile::certs(&mut reader)
+        .with_context(|| unexpected!("read certificates error: {}", path.display()))?;
+    if certs.is_empty() {
+        bail!(unexpected!("no certificate found: {}", path.display()));
+    }
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn read_key(path: &Path) -> Result<PrivateKey> {
+    let mut reader = open(path)?;
+    loop {
+        let item = rustls_pemfile::read_one(&mut reader)
+            .with_context(|| unexpected!("read private key error: {}", path.display()))?;
+        match item {
+            Some(Item::PKCS8Key(key) | Item::RSAKey(key) | Item::ECKey(key)) => {
+                return Ok(PrivateKey(key))
+            }
+            Some(_) => continue,
+            None => bail!(unexpected!("no private key found: {}", path.display())),
+        }
+    }
+}
+
+fn open(path: &Path) -> Result<BufReader<File>> {
+    let file = File::open(path).with_context(|| unexpected!("open error: {}", path.display()))?;
+    Ok(BufReader::new(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::server_config;
+    use crate::http::server::api::config::Tls;
+    use std::time::Duration;
+
+    #[test]
+    fn server_config_offers_h2_and_fails_on_missing_files() {
+        let dir = std::env::temp_dir().join(format!("tls-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let cert_path = dir.join("cert.pem");
+        let key_path = dir.join("key.pem");
+        std::fs::write(&cert_path, cert.serialize_pem().unwrap()).unwrap();
+        std::fs::write(&key_path, cert.serialize_private_key_pem()).unwrap();
+
+        let tls = Tls::new(
+            cert_path.clone(),
+            key_path.clone(),
+            Some(cert_path.clone()),
+            Duration::from_secs(1),
+        );
+        let config = server_config(&tls).unwrap();
+        assert_eq!(
+            config.alpn_protocols,
+            vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+        );
+
+        let tls = Tls::new(
+            cert_path,
+            dir.join("missing.pem"),
+            None,
+            Duration::from_secs(1),
+        );
+        assert!(server_config(&tls).is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,14 +1,39 @@
 use application::usecase::{UseCase, UseUseCase};
 use async_trait::async_trait;
 use kernel::Result;
+use tracing::Instrument;
 
 pub mod adapter;
 
 pub mod aws;
 pub mod cli;
+#[cfg(test)]
+mod fixture;
+pub mod grpc;
 pub mod http;
+pub mod metrics;
 pub mod mysql;
+pub mod postgres;
+pub mod rdb;
 pub mod redis;
+pub mod sqlite;
+pub mod worker;
+
+/// awaits a function of the `$root` driver module in a span named after it,
+/// e.g. `traced!("redis", redis::repository, session::get(ctx, id))` in `redis session::get`.
+macro_rules! traced {
+    ($system:literal, $($root:ident)::+, $module:ident::$function:ident$(::<$dialect:ty>)?($($arg:expr),* $(,)?)) => {
+        tracing::Instrument::instrument(
+            $crate::$($root)::+::$module::$function$(::<$dialect>)?($($arg),*),
+            tracing::info_span!(
+                concat!($system, " ", stringify!($module), "::", stringify!($function)),
+                db.system = $system,
+            ),
+        )
+        .await
+    };
+}
+pub(crate) use traced;
 
 #[async_trait]
 pub trait Present<D, Attachment = ()> {
@@ -44,7 +69,20 @@ pub(crate) async fn dispatch_with<
     attachment: A,
     mods: M,
 ) -> P::Output {
-    mods.presenter()
-        .present(mods.usecase().handle(input).await, attachment)
-        .await
+    let presenter = mods.presenter();
+    dispatch_to(input, attachment, mods, &presenter).await
+}
+
+/// dispatches to a presenter other than the one of `mods`.
+pub(crate) async fn dispatch_to<M: UseUseCase<I, O>, I, O, P: Present<Result<O>, A>, A>(
+    input: I,
+    attachment: A,
+    mods: M,
+    presenter: &P,
+) -> P::Output {
+    let span = tracing::info_span!("usecase", otel.name = metrics::usecase_name::<I>());
+    let output = metrics::observe_usecase::<I, O>(mods.usecase().handle(input))
+        .instrument(span)
+        .await;
+    presenter.present(output, attachment).await
 }
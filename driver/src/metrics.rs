@@ -0,0 +1,221 @@
+use crate::rdb::RDB;
+use crate::redis::{Redis, RedisPools};
+use kernel::error::{Codes, Error};
+use kernel::Result;
+use once_cell::sync::Lazy;
+use prometheus::core::{Collector, Desc};
+use prometheus::proto::MetricFamily;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+use std::future::Future;
+use validator::ValidationErrors;
+
+static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
+
+pub(crate) static HTTP_REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register(
+        IntCounterVec::new(
+            Opts::new("http_requests_total", "http requests by route and status."),
+            &["method", "route", "status"],
+        )
+        .unwrap(),
+    )
+});
+pub(crate) static HTTP_REQUEST_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register(
+        HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "http request latencies by route and status.",
+            ),
+            &["method", "route", "status"],
+        )
+        .unwrap(),
+    )
+});
+static USECASE_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register(
+        HistogramVec::new(
+            HistogramOpts::new("usecase_duration_seconds", "use case latencies."),
+            &["usecase"],
+        )
+        .unwrap(),
+    )
+});
+static USECASE_ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register(
+        IntCounterVec::new(
+            Opts::new("usecase_errors_total", "use case errors by error code."),
+            &["usecase", "code"],
+        )
+        .unwrap(),
+    )
+});
+pub(crate) static WEBSOCKET_CONNECTIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register(IntGauge::new("websocket_connections", "open websocket connections.").unwrap())
+});
+pub(crate) static SSE_CONNECTIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register(IntGauge::new("sse_connections", "open server-sent event streams.").unwrap())
+});
+pub(crate) static GRPC_STREAMS: Lazy<IntGauge> = Lazy::new(|| {
+    register(IntGauge::new("grpc_streams", "open grpc subscription streams.").unwrap())
+});
+pub(crate) static GRAPHQL_SUBSCRIPTIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register(IntGauge::new("graphql_subscriptions", "open graphql subscriptions.").unwrap())
+});
+pub(crate) static PUBSUB_SUBSCRIPTIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register(IntGauge::new("pubsub_subscriptions", "active redis subscriptions.").unwrap())
+});
+pub(crate) static PUBSUB_PUBLISHED: Lazy<IntCounter> = Lazy::new(|| {
+    register(
+        IntCounter::new(
+            "pubsub_published_messages_total",
+            "messages published to redis.",
+        )
+        .unwrap(),
+    )
+});
+pub(crate) static PUBSUB_RECEIVED: Lazy<IntCounter> = Lazy::new(|| {
+    register(
+        IntCounter::new(
+            "pubsub_received_messages_total",
+            "messages received from redis subscriptions.",
+        )
+        .unwrap(),
+    )
+});
+
+fn register<T: Collector + Clone + 'static>(collector: T) -> T {
+    REGISTRY.register(Box::new(collector.clone())).unwrap();
+    collector
+}
+
+/// registers gauges of the connection pools, read on every scrape.
+pub fn register_pools(db: &RDB, redis: &Redis) -> Result<()> {
+    let connections = IntGaugeVec::new(
+        Opts::new("pool_connections", "pooled connections by state."),
+        &["pool", "state"],
+    )?;
+    let collector = PoolCollector {
+        db: db.clone(),
+        redis: redis.clone(),
+        connections,
+    };
+    REGISTRY.register(Box::new(collector))?;
+    Ok(())
+}
+
+struct PoolCollector {
+    db: RDB,
+    redis: Redis,
+    connections: IntGaugeVec,
+}
+
+impl PoolCollector {
+    fn set(&self, pool: &str, size: u32, idle: u32) {
+        self.connections
+            .with_label_values(&[pool, "idle"])
+            .set(idle as i64);
+        self.connections
+            .with_label_values(&[pool, "in_use"])
+            .set(size.saturating_sub(idle) as i64);
+    }
+}
+
+impl Collector for PoolCollector {
+    fn desc(&self) -> Vec<&Desc> {
+        self.connections.desc()
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        match &self.db {
+            RDB::MySQL(crate::mysql::DB::Pool(pool)) => {
+                self.set("mysql", pool.size(), pool.num_idle() as u32)
+            }
+            RDB::Postgres(crate::postgres::DB::Pool(pool)) => {
+                self.set("postgres", pool.size(), pool.num_idle() as u32)
+            }
+            RDB::Sqlite(crate::sqlite::DB::Pool(pool)) => {
+                self.set("sqlite", pool.size(), pool.num_idle() as u32)
+            }
+            _ => (),
+        }
+        if let Ok(RedisPools { primary, reader }) = self.redis.pools() {
+            for (name, pool) in [("redis_primary", primary), ("redis_reader", reader)] {
+                let state = pool.state();
+                self.set(name, state.connections, state.idle_connections);
+            }
+        }
+        self.connections.collect()
+    }
+}
+
+/// keeps a gauge incremented while alive.
+pub(crate) struct GaugeGuard(IntGauge);
+
+impl GaugeGuard {
+    pub(crate) fn new(gauge: &IntGauge) -> GaugeGuard {
+        gauge.inc();
+        GaugeGuard(gauge.clone())
+    }
+}
+
+impl Drop for GaugeGuard {
+    fn drop(&mut self) {
+        self.0.dec();
+    }
+}
+
+/// records the latency of a use case and the code of its error, if any.
+pub(crate) async fn observe_usecase<I, O>(handle: impl Future<Output = Result<O>>) -> Result<O> {
+    let usecase = usecase_name::<I>();
+    let timer = USECASE_DURATION.with_label_values(&[usecase]).start_timer();
+    let result = handle.await;
+    timer.observe_duration();
+    if let Err(e) = &result {
+        USECASE_ERRORS
+            .with_label_values(&[usecase, &error_code(e)])
+            .inc();
+    }
+    result
+}
+
+/// `application::usecase::channel::PublishInput` is labeled as `Publish`.
+pub(crate) fn usecase_name<I>() -> &'static str {
+    let name = std::any::type_name::<I>();
+    let name = name.rsplit("::").next().unwrap_or(name);
+    name.strip_suffix("Input").unwrap_or(name)
+}
+
+fn error_code(e: &anyhow::Error) -> String {
+    if let Some(e) = e.downcast_ref::<Error>() {
+        return e.codes().to_string();
+    }
+    if e.downcast_ref::<ValidationErrors>().is_some() {
+        return Codes::ValidationFailed.to_string();
+    }
+    Codes::CommonUnexpected.to_string()
+}
+
+/// renders all metrics in the prometheus text format.
+pub fn render() -> Result<(String, Vec<u8>)> {
+    let encoder = TextEncoder::new();
+    let mut buffer = vec![];
+    encoder.encode(&REGISTRY.gather(), &mut buffer)?;
+    Ok((encoder.format_type().to_string(), buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::usecase_name;
+
+    struct PublishInput;
+
+    #[test]
+    fn usecase_name_strips_path_and_suffix() {
+        assert_eq!(usecase_name::<PublishInput>(), "Publish");
+        assert_eq!(usecase_name::<String>(), "String");
+    }
+}
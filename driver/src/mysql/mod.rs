@@ -1,68 +1,19 @@
 use crate::mysql::config::Config;
-use anyhow::{Context as _, Result};
-use application::interface::repository::Transaction;
-use application::interface::{Component, UseContext};
-use async_trait::async_trait;
-use kernel::unexpected;
-use log;
+use crate::rdb::{Dialect, RDB};
+use anyhow::Result;
+use sea_query::MysqlQueryBuilder;
+use sqlx::migrate::Migrator;
 pub use sqlx::mysql::MySqlPoolOptions;
 pub use sqlx::pool::PoolConnection;
 pub use sqlx::MySql;
 pub use sqlx::Pool as MySqlPool;
-use sqlx::TransactionManager;
-
-use std::sync::Arc;
-use tokio::sync::Mutex;
 
 pub mod config;
-pub mod repository;
-
-#[derive(Clone, Debug)]
-pub enum DB {
-    Pool(MySqlPool<MySql>),
-    Conn(Arc<Mutex<PoolConnection<MySql>>>),
-}
 
-#[async_trait]
-pub trait MySQLContext: Component {
-    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<MySql>>>>;
-}
-
-#[async_trait]
-impl Transaction for DB {
-    async fn begin(&self) -> Result<DB> {
-        match self {
-            DB::Pool(_) => DB::begin(self.acquire().await?).await,
-            DB::Conn(conn) => DB::begin(conn.clone()).await,
-        }
-    }
+pub type DB = crate::rdb::db::DB<MySql>;
 
-    async fn commit(&self) -> Result<DB> {
-        match self {
-            DB::Pool(_) => {
-                log::warn!("{:?}", unexpected!("ignore DB:Pool commit."));
-                Ok(self.clone())
-            }
-            DB::Conn(conn) => DB::commit(conn.clone()).await,
-        }
-    }
-}
-impl Drop for DB {
-    fn drop(&mut self) {
-        match self {
-            DB::Pool(_) => (),
-            DB::Conn(conn) => {
-                tokio::task::spawn(DB::rollback(conn.clone()));
-            }
-        }
-    }
-}
-#[async_trait]
-impl MySQLContext for DB {
-    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<MySql>>>> {
-        self.acquire().await
-    }
-}
+/// the versioned schema in `driver/migrations/mysql`, embedded at compile time.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/mysql");
 
 impl DB {
     pub async fn new(config: Config) -> Result<DB> {
@@ -76,64 +27,19 @@ impl DB {
             .await?;
         Ok(DB::Pool(db_pool))
     }
-    async fn begin(conn: Arc<Mutex<PoolConnection<MySql>>>) -> Result<DB> {
-        <MySql as sqlx::Database>::TransactionManager::begin(&mut *conn.lock().await)
-            .await
-            .with_context(|| unexpected!("begin error"))?;
-        Ok(DB::Conn(conn))
-    }
-
-    async fn commit(conn: Arc<Mutex<PoolConnection<MySql>>>) -> Result<DB> {
-        <MySql as sqlx::Database>::TransactionManager::commit(&mut *conn.lock().await)
-            .await
-            .with_context(|| unexpected!("commit error"))?;
-        Ok(DB::Conn(conn))
-    }
-
-    async fn rollback(conn: Arc<Mutex<PoolConnection<MySql>>>) -> Result<()> {
-        <MySql as sqlx::Database>::TransactionManager::rollback(&mut *conn.lock().await)
-            .await
-            .with_context(|| unexpected!("rollback error"))?;
-        Ok(())
-    }
-    pub async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<MySql>>>> {
-        match self {
-            DB::Pool(pool) => Ok(Arc::new(Mutex::new(
-                pool.clone()
-                    .acquire()
-                    .await
-                    .with_context(|| unexpected!("acquire error"))?,
-            ))),
-            DB::Conn(conn) => Ok(conn.clone()),
-        }
-    }
 }
 
-#[async_trait]
-impl UseContext for DB {
-    type Context = DB;
-
-    async fn context(&self) -> Result<Self::Context> {
-        Ok(self.clone())
-    }
-}
+impl Dialect for MySql {
+    const SYSTEM: &'static str = "mysql";
+    type QueryBuilder = MysqlQueryBuilder;
 
-pub mod dsl {
-    pub use sea_query::*;
-    #[must_use]
-    pub fn alias(s: &str) -> Alias {
-        Alias::new(s)
+    fn migrator() -> &'static Migrator {
+        &MIGRATOR
     }
-    #[must_use]
-    pub fn tbl(s: &str) -> impl Iden {
-        alias(s)
-    }
-    #[must_use]
-    pub fn col(s: &str) -> impl Iden {
-        alias(s)
-    }
-    #[must_use]
-    pub fn cond(s: &str) -> Expr {
-        Expr::col(alias(s))
+    fn db(rdb: &RDB) -> Option<&DB> {
+        match rdb {
+            RDB::MySQL(db) => Some(db),
+            _ => None,
+        }
     }
 }
@@ -0,0 +1,45 @@
+use crate::postgres::config::Config;
+use crate::rdb::{Dialect, RDB};
+use anyhow::Result;
+use sea_query::PostgresQueryBuilder;
+use sqlx::migrate::Migrator;
+pub use sqlx::pool::PoolConnection;
+pub use sqlx::postgres::PgPoolOptions;
+pub use sqlx::Pool as PgPool;
+pub use sqlx::Postgres;
+
+pub mod config;
+
+pub type DB = crate::rdb::db::DB<Postgres>;
+
+/// the versioned schema in `driver/migrations/postgres`, embedded at compile time.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+
+impl DB {
+    pub async fn new(config: Config) -> Result<DB> {
+        let db_pool = PgPoolOptions::new()
+            .min_connections(config.min_connections)
+            .max_connections(config.max_connections)
+            .idle_timeout(config.idle_timeout)
+            .max_lifetime(config.max_lifetime)
+            .acquire_timeout(config.connect_timeout)
+            .connect(&config.url)
+            .await?;
+        Ok(DB::Pool(db_pool))
+    }
+}
+
+impl Dialect for Postgres {
+    const SYSTEM: &'static str = "postgres";
+    type QueryBuilder = PostgresQueryBuilder;
+
+    fn migrator() -> &'static Migrator {
+        &MIGRATOR
+    }
+    fn db(rdb: &RDB) -> Option<&DB> {
+        match rdb {
+            RDB::Postgres(db) => Some(db),
+            _ => None,
+        }
+    }
+}
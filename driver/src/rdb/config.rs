@@ -0,0 +1,57 @@
+use crate::mysql::config::Config as MySQLConfig;
+use crate::postgres::config::Config as PostgresConfig;
+use crate::sqlite::config::Config as SqliteConfig;
+use anyhow::bail;
+use kernel::{unexpected, Result};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub enum Config {
+    MySQL(MySQLConfig),
+    Postgres(PostgresConfig),
+    Sqlite(SqliteConfig),
+}
+
+impl Config {
+    /// chooses the backend by the scheme of `url`, `mysql://`, `postgres://` or `sqlite:`.
+    pub fn new(
+        url: String,
+        min_connections: u32,
+        max_connections: u32,
+        connect_timeout: Duration,
+        idle_timeout: Duration,
+        max_lifetime: Duration,
+    ) -> Result<Config> {
+        // `sqlite::memory:` and `sqlite:data.db` have no `//`, so only the scheme is split off.
+        let Some((scheme, _)) = url.split_once(':') else {
+            bail!(unexpected!("invalid database url"));
+        };
+        match scheme {
+            "mysql" => Ok(Config::MySQL(MySQLConfig::new(
+                url,
+                min_connections,
+                max_connections,
+                connect_timeout,
+                idle_timeout,
+                max_lifetime,
+            ))),
+            "postgres" | "postgresql" => Ok(Config::Postgres(PostgresConfig::new(
+                url,
+                min_connections,
+                max_connections,
+                connect_timeout,
+                idle_timeout,
+                max_lifetime,
+            ))),
+            "sqlite" => Ok(Config::Sqlite(SqliteConfig::new(
+                url,
+                min_connections,
+                max_connections,
+                connect_timeout,
+                idle_timeout,
+                max_lifetime,
+            ))),
+            _ => bail!(unexpected!("unsupported database url scheme: {}", scheme)),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::{Context as _, Result};
+use application::interface::repository::Transaction;
+use application::interface::{Component, UseContext};
+use async_trait::async_trait;
+use kernel::unexpected;
+use sqlx::pool::PoolConnection;
+use sqlx::{Database, Pool, TransactionManager};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// a pool of the backend `D`, or the connection of a transaction begun on it.
+#[derive(Debug)]
+pub enum DB<D: Database> {
+    Pool(Pool<D>),
+    Conn(Arc<Mutex<PoolConnection<D>>>),
+}
+
+/// a context the repositories of the backend `D` can run on.
+#[async_trait]
+pub trait DBContext<D: Database>: Component {
+    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<D>>>>;
+}
+
+impl<D: Database> Clone for DB<D> {
+    fn clone(&self) -> Self {
+        match self {
+            DB::Pool(pool) => DB::Pool(pool.clone()),
+            DB::Conn(conn) => DB::Conn(conn.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl<D: Database> Transaction for DB<D> {
+    async fn begin(&self) -> Result<DB<D>> {
+        match self {
+            DB::Pool(_) => DB::begin(self.acquire().await?).await,
+            DB::Conn(conn) => DB::begin(conn.clone()).await,
+        }
+    }
+
+    async fn commit(&self) -> Result<DB<D>> {
+        match self {
+            DB::Pool(_) => {
+                log::warn!("{:?}", unexpected!("ignore DB:Pool commit."));
+                Ok(self.clone())
+            }
+            DB::Conn(conn) => DB::commit(conn.clone()).await,
+        }
+    }
+}
+impl<D: Database> Drop for DB<D> {
+    fn drop(&mut self) {
+        match self {
+            DB::Pool(_) => (),
+            DB::Conn(conn) => {
+                tokio::task::spawn(DB::rollback(conn.clone()));
+            }
+        }
+    }
+}
+#[async_trait]
+impl<D: Database> DBContext<D> for DB<D> {
+    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<D>>>> {
+        self.acquire().await
+    }
+}
+
+impl<D: Database> DB<D> {
+    /// closes the pool, waiting for checked out connections to be returned.
+    pub async fn close(&self) {
+        match self {
+            DB::Pool(pool) => pool.close().await,
+            DB::Conn(_) => (),
+        }
+    }
+    async fn begin(conn: Arc<Mutex<PoolConnection<D>>>) -> Result<DB<D>> {
+        D::TransactionManager::begin(&mut *conn.lock().await)
+            .await
+            .with_context(|| unexpected!("begin error"))?;
+        Ok(DB::Conn(conn))
+    }
+
+    async fn commit(conn: Arc<Mutex<PoolConnection<D>>>) -> Result<DB<D>> {
+        D::TransactionManager::commit(&mut *conn.lock().await)
+            .await
+            .with_context(|| unexpected!("commit error"))?;
+        Ok(DB::Conn(conn))
+    }
+
+    async fn rollback(conn: Arc<Mutex<PoolConnection<D>>>) -> Result<()> {
+        D::TransactionManager::rollback(&mut *conn.lock().await)
+            .await
+            .with_context(|| unexpected!("rollback error"))?;
+        Ok(())
+    }
+    pub async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<D>>>> {
+        match self {
+            DB::Pool(pool) => Ok(Arc::new(Mutex::new(
+                pool.clone()
+                    .acquire()
+                    .await
+                    .with_context(|| unexpected!("acquire error"))?,
+            ))),
+            DB::Conn(conn) => Ok(conn.clone()),
+        }
+    }
+}
+
+#[async_trait]
+impl<D: Database> UseContext for DB<D> {
+    type Context = DB<D>;
+
+    async fn context(&self) -> Result<Self::Context> {
+        Ok(self.clone())
+    }
+}
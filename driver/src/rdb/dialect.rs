@@ -0,0 +1,127 @@
+use crate::rdb::db::DB;
+use crate::rdb::sql::{account, authentication, channel, comment, message, webhook};
+use crate::rdb::RDB;
+use async_trait::async_trait;
+use sea_query::QueryBuilder;
+use sea_query_binder::SqlxValues;
+use sqlx::migrate::Migrator;
+use sqlx::pool::PoolConnection;
+use sqlx::{Database, Executor, FromRow, IntoArguments};
+
+/// what sets a backend apart, the repositories, migrations and gateways in `rdb` are written once
+/// for every backend implementing it.
+pub trait Dialect:
+    Database
+    + Execute
+    + Fetch<account::Account>
+    + Fetch<authentication::Authentication>
+    + Fetch<comment::Comment>
+    + Fetch<webhook::Webhook>
+    + Fetch<webhook::WebhookDelivery>
+    + Fetch<message::Message>
+    + Fetch<message::MessageEdit>
+    + Fetch<channel::ReadPosition>
+    + Fetch<channel::ChannelModerator>
+    + Fetch<(String,)>
+    + Fetch<(String, i64)>
+    + Fetch<(String, Option<String>, i64)>
+{
+    /// the `db.system` of its spans.
+    const SYSTEM: &'static str;
+    type QueryBuilder: QueryBuilder + Default;
+
+    fn builder() -> Self::QueryBuilder {
+        Self::QueryBuilder::default()
+    }
+    /// the versioned schema of the backend.
+    fn migrator() -> &'static Migrator;
+    /// the database of `rdb` when it runs on this backend.
+    fn db(rdb: &RDB) -> Option<&DB<Self>>;
+}
+
+/// runs statements on any backend sqlx can bind [SqlxValues] for. a trait rather than functions,
+/// so that the bounds are proven once per backend instead of on every repository function.
+#[async_trait]
+pub trait Execute: Database {
+    async fn execute(
+        conn: &mut PoolConnection<Self>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<()>;
+    /// whether the query returns a row.
+    async fn exists(
+        conn: &mut PoolConnection<Self>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<bool>;
+}
+
+/// decodes the rows of queries into `T`, see [Execute].
+#[async_trait]
+pub trait Fetch<T>: Database {
+    async fn fetch_all(
+        conn: &mut PoolConnection<Self>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<Vec<T>>;
+    async fn fetch_optional(
+        conn: &mut PoolConnection<Self>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<Option<T>>;
+}
+
+#[async_trait]
+impl<D> Execute for D
+where
+    D: Database,
+    for<'c> &'c mut D::Connection: Executor<'c, Database = D>,
+    for<'q> SqlxValues: IntoArguments<'q, D>,
+{
+    async fn execute(
+        conn: &mut PoolConnection<D>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<()> {
+        sqlx::query_with(query, values).execute(&mut **conn).await?;
+        Ok(())
+    }
+    async fn exists(
+        conn: &mut PoolConnection<D>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<bool> {
+        let row = sqlx::query_with(query, values)
+            .fetch_optional(&mut **conn)
+            .await?;
+        Ok(row.is_some())
+    }
+}
+
+#[async_trait]
+impl<D, T> Fetch<T> for D
+where
+    D: Database,
+    T: for<'r> FromRow<'r, D::Row> + Send + Unpin,
+    for<'c> &'c mut D::Connection: Executor<'c, Database = D>,
+    for<'q> SqlxValues: IntoArguments<'q, D>,
+{
+    async fn fetch_all(
+        conn: &mut PoolConnection<D>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<Vec<T>> {
+        sqlx::query_as_with(query, values)
+            .fetch_all(&mut **conn)
+            .await
+    }
+    async fn fetch_optional(
+        conn: &mut PoolConnection<D>,
+        query: &str,
+        values: SqlxValues,
+    ) -> sqlx::Result<Option<T>> {
+        sqlx::query_as_with(query, values)
+            .fetch_optional(&mut **conn)
+            .await
+    }
+}
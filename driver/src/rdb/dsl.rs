@@ -0,0 +1,17 @@
+pub use sea_query::*;
+#[must_use]
+pub fn alias(s: &str) -> Alias {
+    Alias::new(s)
+}
+#[must_use]
+pub fn tbl(s: &str) -> impl Iden {
+    alias(s)
+}
+#[must_use]
+pub fn col(s: &str) -> impl Iden {
+    alias(s)
+}
+#[must_use]
+pub fn cond(s: &str) -> Expr {
+    Expr::col(alias(s))
+}
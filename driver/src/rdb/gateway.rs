@@ -0,0 +1,17 @@
+pub mod health {
+    use crate::rdb::{DBContext, Dialect};
+    use anyhow::Context as _;
+    use kernel::{unexpected, Result};
+    use sea_query::Values;
+    use sea_query_binder::SqlxValues;
+
+    pub async fn ping<D: Dialect>(db: impl DBContext<D>) -> Result<()> {
+        D::execute(
+            &mut *db.acquire().await?.lock().await,
+            "SELECT 1",
+            SqlxValues(Values(vec![])),
+        )
+        .await
+        .with_context(|| unexpected!("ping error"))
+    }
+}
@@ -0,0 +1,239 @@
+use crate::rdb::db::DB;
+use crate::rdb::{Dialect, MigrationStatus};
+use anyhow::Context as _;
+use kernel::{unexpected, Result};
+use sqlx::migrate::Migrate;
+use std::collections::HashSet;
+
+/// applies the pending migrations.
+pub async fn up<D: Dialect>(db: &DB<D>) -> Result<()>
+where
+    D::Connection: Migrate,
+{
+    let conn = db.acquire().await?;
+    let mut conn = conn.lock().await;
+    let conn: &mut D::Connection = &mut conn;
+    D::migrator()
+        .run_direct(conn)
+        .await
+        .with_context(|| unexpected!("migrate up error"))
+}
+
+/// reverts the applied migrations newer than `target`, only the latest one when `None`.
+pub async fn down<D: Dialect>(db: &DB<D>, target: Option<i64>) -> Result<()>
+where
+    D::Connection: Migrate,
+{
+    let conn = db.acquire().await?;
+    let mut conn = conn.lock().await;
+    let conn: &mut D::Connection = &mut conn;
+    conn.ensure_migrations_table()
+        .await
+        .with_context(|| unexpected!("migrate down error"))?;
+    let applied = applied_versions(conn).await?;
+    let target = match target {
+        Some(target) => target,
+        // the latest applied version is reverted to the one before it.
+        None => match applied.iter().max() {
+            Some(latest) => latest - 1,
+            None => return Ok(()),
+        },
+    };
+    conn.lock()
+        .await
+        .with_context(|| unexpected!("migrate lock error"))?;
+    let reverted = revert::<D>(conn, &applied, target).await;
+    conn.unlock()
+        .await
+        .with_context(|| unexpected!("migrate unlock error"))?;
+    reverted
+}
+
+/// every embedded migration, with whether it is applied to the database.
+pub async fn status<D: Dialect>(db: &DB<D>) -> Result<Vec<MigrationStatus>>
+where
+    D::Connection: Migrate,
+{
+    let conn = db.acquire().await?;
+    let mut conn = conn.lock().await;
+    let conn: &mut D::Connection = &mut conn;
+    conn.ensure_migrations_table()
+        .await
+        .with_context(|| unexpected!("migrate status error"))?;
+    let applied = applied_versions(conn).await?;
+    Ok(D::migrator()
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .map(|migration| {
+            MigrationStatus::new(
+                migration.version,
+                migration.description.to_string(),
+                applied.contains(&migration.version),
+            )
+        })
+        .collect())
+}
+
+async fn applied_versions(conn: &mut impl Migrate) -> Result<HashSet<i64>> {
+    Ok(conn
+        .list_applied_migrations()
+        .await
+        .with_context(|| unexpected!("migrate list_applied_migrations error"))?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect())
+}
+
+async fn revert<D: Dialect>(
+    conn: &mut impl Migrate,
+    applied: &HashSet<i64>,
+    target: i64,
+) -> Result<()> {
+    // the newest first, as a migration may depend on the ones before it.
+    let mut migrations: Vec<_> = D::migrator()
+        .iter()
+        .filter(|migration| migration.migration_type.is_down_migration())
+        .filter(|migration| migration.version > target && applied.contains(&migration.version))
+        .collect();
+    migrations.sort_by_key(|migration| std::cmp::Reverse(migration.version));
+    for migration in migrations {
+        conn.revert(migration)
+            .await
+            .with_context(|| unexpected!("migrate down error: {}", migration.version))?;
+        log::info!(
+            "reverted migration {} {}.",
+            migration.version,
+            migration.description
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rdb::migration::{down, status, up};
+    use crate::rdb::Dialect;
+    use crate::sqlite::config::Config;
+    use crate::sqlite::DB;
+    use sqlx::migrate::Migrator;
+    use sqlx::{MySql, Postgres, Sqlite};
+    use std::collections::HashSet;
+    use std::time::Duration;
+
+    /// a fresh in-memory database, with nothing applied.
+    async fn db() -> DB {
+        let config = Config::new(
+            "sqlite::memory:".to_string(),
+            0,
+            1,
+            Duration::from_secs(10),
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        );
+        DB::new(config).await.unwrap()
+    }
+
+    async fn applied(db: &DB) -> Vec<i64> {
+        status(db)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|migration| migration.applied)
+            .map(|migration| migration.version)
+            .collect()
+    }
+
+    async fn tables(db: &DB) -> HashSet<String> {
+        let conn = db.acquire().await.unwrap();
+        let mut conn = conn.lock().await;
+        sqlx::query_scalar::<_, String>("SELECT name FROM sqlite_master WHERE type = 'table'")
+            .fetch_all(&mut *conn)
+            .await
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    fn versions() -> Vec<i64> {
+        let mut versions: Vec<_> = Sqlite::migrator()
+            .iter()
+            .filter(|migration| !migration.migration_type.is_down_migration())
+            .map(|migration| migration.version)
+            .collect();
+        versions.sort();
+        versions
+    }
+
+    fn assert_reversible(migrator: &Migrator) {
+        let (down, up): (Vec<_>, Vec<_>) = migrator
+            .iter()
+            .partition(|migration| migration.migration_type.is_down_migration());
+        let down: HashSet<_> = down.iter().map(|migration| migration.version).collect();
+        assert!(!up.is_empty());
+        for migration in up {
+            assert!(down.contains(&migration.version), "{}", migration.version);
+        }
+    }
+
+    #[test]
+    fn every_migration_is_reversible() {
+        assert_reversible(MySql::migrator());
+        assert_reversible(Postgres::migrator());
+        assert_reversible(Sqlite::migrator());
+    }
+
+    #[tokio::test]
+    async fn status_lists_every_migration() {
+        let db = db().await;
+        let listed: Vec<_> = status(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|migration| migration.version)
+            .collect();
+        assert_eq!(listed, versions());
+        assert!(applied(&db).await.is_empty());
+        up(&db).await.unwrap();
+        assert_eq!(applied(&db).await, versions());
+    }
+
+    #[tokio::test]
+    async fn down_on_an_empty_database_is_a_noop() {
+        let db = db().await;
+        down(&db, None).await.unwrap();
+        down(&db, Some(0)).await.unwrap();
+        assert!(applied(&db).await.is_empty());
+        assert!(!tables(&db).await.contains("account"));
+    }
+
+    #[tokio::test]
+    async fn down_without_target_reverts_only_the_latest() {
+        let db = db().await;
+        up(&db).await.unwrap();
+        down(&db, None).await.unwrap();
+        let versions = versions();
+        assert_eq!(applied(&db).await, versions[..versions.len() - 1]);
+        // the reverted migration applies again, so its down script undid all of it.
+        up(&db).await.unwrap();
+        assert_eq!(applied(&db).await, versions);
+    }
+
+    #[tokio::test]
+    async fn down_to_target_reverts_the_newer_migrations() {
+        let db = db().await;
+        up(&db).await.unwrap();
+        let versions = versions();
+        down(&db, Some(versions[0])).await.unwrap();
+        assert_eq!(applied(&db).await, versions[..1]);
+        let remaining = tables(&db).await;
+        assert!(remaining.contains("account"), "{remaining:?}");
+        assert!(!remaining.contains("message"), "{remaining:?}");
+        assert!(!remaining.contains("webhook"), "{remaining:?}");
+
+        down(&db, Some(0)).await.unwrap();
+        assert!(applied(&db).await.is_empty());
+        assert!(!tables(&db).await.contains("account"));
+        up(&db).await.unwrap();
+        assert_eq!(applied(&db).await, versions);
+    }
+}
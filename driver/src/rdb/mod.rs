@@ -0,0 +1,154 @@
+use crate::mysql::MySql;
+use crate::postgres::Postgres;
+use crate::rdb::config::Config;
+use crate::sqlite::Sqlite;
+use anyhow::bail;
+use application::interface::repository::Transaction;
+use async_trait::async_trait;
+use derive_new::new;
+use kernel::{unexpected, Result};
+use sqlx::pool::PoolConnection;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub mod config;
+pub mod db;
+pub mod dialect;
+pub mod dsl;
+pub mod gateway;
+pub mod migration;
+pub mod sql;
+#[cfg(test)]
+mod tests;
+
+pub use db::DBContext;
+pub use dialect::{Dialect, Execute, Fetch};
+
+/// the relational database the repositories are backed by, chosen by configuration.
+#[derive(Clone, Debug)]
+pub enum RDB {
+    MySQL(crate::mysql::DB),
+    Postgres(crate::postgres::DB),
+    Sqlite(crate::sqlite::DB),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    MySQL,
+    Postgres,
+    Sqlite,
+}
+
+/// a context the repositories can run on any backend with, they dispatch on `backend`.
+pub trait RDBContext: DBContext<MySql> + DBContext<Postgres> + DBContext<Sqlite> {
+    fn backend(&self) -> Backend;
+}
+
+#[derive(Debug, Clone, new)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+}
+
+/// calls the repository function of the backend of `ctx`, e.g. `repository!(ctx, account::get(id))`.
+macro_rules! repository {
+    ($ctx:expr, $module:ident::$function:ident($($arg:expr),* $(,)?)) => {
+        match $crate::rdb::RDBContext::backend(&$ctx) {
+            $crate::rdb::Backend::MySQL => {
+                $crate::traced!("mysql", rdb::sql, $module::$function::<$crate::mysql::MySql>($ctx, $($arg),*))
+            }
+            $crate::rdb::Backend::Postgres => {
+                $crate::traced!("postgres", rdb::sql, $module::$function::<$crate::postgres::Postgres>($ctx, $($arg),*))
+            }
+            $crate::rdb::Backend::Sqlite => {
+                $crate::traced!("sqlite", rdb::sql, $module::$function::<$crate::sqlite::Sqlite>($ctx, $($arg),*))
+            }
+        }
+    };
+}
+pub(crate) use repository;
+
+impl RDB {
+    pub async fn new(config: Config) -> Result<RDB> {
+        match config {
+            Config::MySQL(config) => Ok(RDB::MySQL(crate::mysql::DB::new(config).await?)),
+            Config::Postgres(config) => Ok(RDB::Postgres(crate::postgres::DB::new(config).await?)),
+            Config::Sqlite(config) => Ok(RDB::Sqlite(crate::sqlite::DB::new(config).await?)),
+        }
+    }
+    /// closes the pool, waiting for checked out connections to be returned.
+    pub async fn close(&self) {
+        match self {
+            RDB::MySQL(db) => db.close().await,
+            RDB::Postgres(db) => db.close().await,
+            RDB::Sqlite(db) => db.close().await,
+        }
+    }
+    /// applies the pending migrations of the backend.
+    pub async fn migrate_up(&self) -> Result<()> {
+        match self {
+            RDB::MySQL(db) => migration::up(db).await,
+            RDB::Postgres(db) => migration::up(db).await,
+            RDB::Sqlite(db) => migration::up(db).await,
+        }
+    }
+    /// reverts the applied migrations newer than `target`, only the latest one when `None`.
+    pub async fn migrate_down(&self, target: Option<i64>) -> Result<()> {
+        match self {
+            RDB::MySQL(db) => migration::down(db, target).await,
+            RDB::Postgres(db) => migration::down(db, target).await,
+            RDB::Sqlite(db) => migration::down(db, target).await,
+        }
+    }
+    pub async fn migration_status(&self) -> Result<Vec<MigrationStatus>> {
+        match self {
+            RDB::MySQL(db) => migration::status(db).await,
+            RDB::Postgres(db) => migration::status(db).await,
+            RDB::Sqlite(db) => migration::status(db).await,
+        }
+    }
+}
+
+#[async_trait]
+impl Transaction for RDB {
+    async fn begin(&self) -> Result<RDB> {
+        match self {
+            RDB::MySQL(db) => Ok(RDB::MySQL(db.begin().await?)),
+            RDB::Postgres(db) => Ok(RDB::Postgres(db.begin().await?)),
+            RDB::Sqlite(db) => Ok(RDB::Sqlite(db.begin().await?)),
+        }
+    }
+
+    async fn commit(&self) -> Result<RDB> {
+        match self {
+            RDB::MySQL(db) => Ok(RDB::MySQL(db.commit().await?)),
+            RDB::Postgres(db) => Ok(RDB::Postgres(db.commit().await?)),
+            RDB::Sqlite(db) => Ok(RDB::Sqlite(db.commit().await?)),
+        }
+    }
+}
+
+#[async_trait]
+impl<D: Dialect> DBContext<D> for RDB {
+    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<D>>>> {
+        match D::db(self) {
+            Some(db) => db.acquire().await,
+            None => bail!(unexpected!(
+                "not a {} database: {:?}",
+                D::SYSTEM,
+                self.backend()
+            )),
+        }
+    }
+}
+
+impl RDBContext for RDB {
+    fn backend(&self) -> Backend {
+        match self {
+            RDB::MySQL(_) => Backend::MySQL,
+            RDB::Postgres(_) => Backend::Postgres,
+            RDB::Sqlite(_) => Backend::Sqlite,
+        }
+    }
+}
@@ -0,0 +1,1157 @@
+pub mod account {
+    use crate::rdb::dsl::{col, cond, tbl, Expr, Query};
+    use crate::rdb::{DBContext, Dialect, Fetch};
+    use anyhow::Context;
+    use chrono::{DateTime, Utc};
+    use kernel::i18n::Locale;
+    use kernel::Result;
+    use kernel::{entity, unexpected};
+    use sea_query_binder::{SqlxBinder, SqlxValues};
+
+    pub async fn get<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+    ) -> Result<Option<entity::Account>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("account"))
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        query_account_with(db, query, values).await
+    }
+
+    pub async fn create<D: Dialect>(
+        db: impl DBContext<D>,
+        new_account: entity::Account,
+    ) -> Result<entity::Account> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("account"))
+            .columns(vec![
+                col("id"),
+                col("name"),
+                col("display_name"),
+                col("create_time"),
+                col("locale"),
+            ])
+            .values(vec![
+                new_account.id.clone().into(),
+                new_account.name.clone().into(),
+                new_account.display_name.clone().into(),
+                new_account.create_time.into(),
+                new_account.locale.map(|locale| locale.to_string()).into(),
+            ])?
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("account create error")),
+            Ok(_) => Ok(new_account),
+        }
+    }
+    pub async fn update_locale<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+        locale: Locale,
+    ) -> Result<()> {
+        let (query, values) = Query::update()
+            .table(tbl("account"))
+            .values(vec![(col("locale"), locale.to_string().into())])
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("account update_locale error")),
+            Ok(_) => Ok(()),
+        }
+    }
+    async fn query_account_with<D: Dialect>(
+        db: impl DBContext<D>,
+        query: String,
+        values: SqlxValues,
+    ) -> Result<Option<entity::Account>> {
+        match Fetch::<Account>::fetch_optional(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("account query_account_with error")),
+            Ok(res) => Ok(res.map(entity::Account::from)),
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct Account {
+        pub id: String,
+        pub name: String,
+        pub display_name: String,
+        pub create_time: DateTime<Utc>,
+        pub locale: Option<String>,
+    }
+
+    impl From<Account> for entity::Account {
+        fn from(record: Account) -> Self {
+            let mut account = entity::Account::new(
+                record.id,
+                record.name,
+                record.display_name,
+                record.create_time,
+            );
+            // a locale dropped from the catalogs falls back to the request's.
+            account.locale = record.locale.and_then(|locale| locale.parse().ok());
+            account
+        }
+    }
+}
+
+pub mod authentication {
+    use crate::rdb::dsl::{col, cond, tbl, Expr, Query};
+    use crate::rdb::{DBContext, Dialect, Fetch};
+    use anyhow::Context;
+    use application::interface::repository::authentication::UpdatePassword;
+    use kernel::Result;
+    use kernel::{entity, unexpected};
+    use sea_query_binder::SqlxBinder;
+
+    pub async fn get_by_mail<D: Dialect>(
+        db: impl DBContext<D>,
+        mail: String,
+    ) -> Result<Option<entity::Authentication>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("authentication"))
+            .and_where(cond("mail").eq(mail))
+            .build_sqlx(D::builder());
+        match Fetch::<Authentication>::fetch_optional(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("authentication get_by_mail error")),
+            Ok(res) => Ok(res.map(entity::Authentication::from)),
+        }
+    }
+    pub async fn create<D: Dialect>(
+        db: impl DBContext<D>,
+        new_authentication: entity::Authentication,
+    ) -> Result<()> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("authentication"))
+            .columns(vec![
+                col("account_id"),
+                col("mail"),
+                col("salt"),
+                col("password"),
+            ])
+            .values(vec![
+                new_authentication.account_id.into(),
+                new_authentication.mail.into(),
+                new_authentication.salt.into(),
+                new_authentication.password_hash.into(),
+            ])?
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("authentication create error")),
+            Ok(_) => Ok(()),
+        }
+    }
+    pub async fn update_password<D: Dialect>(
+        db: impl DBContext<D>,
+        updated: UpdatePassword,
+    ) -> Result<()> {
+        let (query, values) = Query::update()
+            .table(tbl("authentication"))
+            .values(vec![(col("password"), updated.password.into())])
+            .and_where(cond("account_id").eq(updated.account_id))
+            .and_where(cond("mail").eq(updated.mail))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("update_password error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct Authentication {
+        pub account_id: String,
+        pub mail: String,
+        pub salt: String,
+        pub password: String,
+    }
+
+    impl From<Authentication> for entity::Authentication {
+        fn from(record: Authentication) -> Self {
+            entity::Authentication::new(
+                record.account_id,
+                record.mail,
+                record.salt,
+                record.password,
+            )
+        }
+    }
+}
+
+pub mod comment {
+    use crate::rdb::dsl::{col, cond, tbl, Expr, Query};
+    use crate::rdb::{DBContext, Dialect, Fetch};
+    use anyhow::Context;
+    use chrono::{DateTime, Utc};
+    use helper::time::current_time;
+    use kernel::Result;
+    use kernel::{entity, unexpected};
+    use sea_query_binder::SqlxBinder;
+
+    pub async fn get<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+    ) -> Result<Option<entity::Comment>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("comment"))
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        match Fetch::<Comment>::fetch_optional(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("comment get error")),
+            Ok(res) => Ok(res.map(entity::Comment::from)),
+        }
+    }
+
+    pub async fn put<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+        body: String,
+    ) -> Result<Option<entity::Comment>> {
+        let now = current_time();
+        let (query, values) = Query::insert()
+            .into_table(tbl("comment"))
+            .columns(vec![col("id"), col("body"), col("create_time")])
+            .values(vec![id.clone().into(), body.clone().into(), now.into()])?
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("comment put error")),
+            Ok(_) => Ok(Some(entity::Comment::new(id, body, now))),
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct Comment {
+        pub id: String,
+        pub body: String,
+        pub create_time: DateTime<Utc>,
+    }
+    impl From<Comment> for entity::Comment {
+        fn from(record: Comment) -> Self {
+            entity::Comment::new(record.id, record.body, record.create_time)
+        }
+    }
+}
+
+pub mod webhook {
+    use crate::rdb::dsl::{col, cond, tbl, Expr, Order, Query};
+    use crate::rdb::{DBContext, Dialect, Fetch};
+    use anyhow::Context;
+    use chrono::{DateTime, Utc};
+    use kernel::Result;
+    use kernel::{entity, unexpected};
+    use sea_query_binder::SqlxBinder;
+    use std::str::FromStr;
+
+    pub async fn get<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+    ) -> Result<Option<entity::Webhook>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("webhook"))
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        match Fetch::<Webhook>::fetch_optional(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook get error")),
+            Ok(res) => Ok(res.map(entity::Webhook::from)),
+        }
+    }
+
+    pub async fn find_by_channel<D: Dialect>(
+        db: impl DBContext<D>,
+        channel_id: String,
+    ) -> Result<Vec<entity::Webhook>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("webhook"))
+            .and_where(cond("channel_id").eq(channel_id))
+            .order_by(col("create_time"), Order::Asc)
+            .build_sqlx(D::builder());
+        match Fetch::<Webhook>::fetch_all(&mut *db.acquire().await?.lock().await, &query, values)
+            .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook find_by_channel error")),
+            Ok(res) => Ok(res.into_iter().map(entity::Webhook::from).collect()),
+        }
+    }
+
+    pub async fn find_channel_ids<D: Dialect>(db: impl DBContext<D>) -> Result<Vec<String>> {
+        let (query, values) = Query::select()
+            .distinct()
+            .column(col("channel_id"))
+            .from(tbl("webhook"))
+            .build_sqlx(D::builder());
+        match Fetch::<(String,)>::fetch_all(&mut *db.acquire().await?.lock().await, &query, values)
+            .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook find_channel_ids error")),
+            Ok(res) => Ok(res.into_iter().map(|(channel_id,)| channel_id).collect()),
+        }
+    }
+
+    pub async fn create<D: Dialect>(
+        db: impl DBContext<D>,
+        new_webhook: entity::Webhook,
+    ) -> Result<entity::Webhook> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("webhook"))
+            .columns(vec![
+                col("id"),
+                col("channel_id"),
+                col("account_id"),
+                col("url"),
+                col("secret"),
+                col("create_time"),
+            ])
+            .values(vec![
+                new_webhook.id.clone().into(),
+                new_webhook.channel_id.clone().into(),
+                new_webhook.account_id.clone().into(),
+                new_webhook.url.clone().into(),
+                new_webhook.secret.clone().into(),
+                new_webhook.create_time.into(),
+            ])?
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook create error")),
+            Ok(_) => Ok(new_webhook),
+        }
+    }
+
+    pub async fn delete<D: Dialect>(db: impl DBContext<D>, id: String) -> Result<()> {
+        let (query, values) = Query::delete()
+            .from_table(tbl("webhook"))
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook delete error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn create_delivery<D: Dialect>(
+        db: impl DBContext<D>,
+        delivery: entity::WebhookDelivery,
+    ) -> Result<()> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("webhook_delivery"))
+            .columns(vec![
+                col("id"),
+                col("webhook_id"),
+                col("channel_id"),
+                col("payload"),
+                col("status"),
+                col("attempts"),
+                col("response_status"),
+                col("last_error"),
+                col("create_time"),
+                col("update_time"),
+            ])
+            .values(vec![
+                delivery.id.into(),
+                delivery.webhook_id.into(),
+                delivery.channel_id.into(),
+                delivery.payload.into(),
+                delivery.status.to_string().into(),
+                delivery.attempts.into(),
+                delivery.response_status.into(),
+                delivery.last_error.into(),
+                delivery.create_time.into(),
+                delivery.update_time.into(),
+            ])?
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook create_delivery error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn update_delivery<D: Dialect>(
+        db: impl DBContext<D>,
+        delivery: entity::WebhookDelivery,
+    ) -> Result<()> {
+        let (query, values) = Query::update()
+            .table(tbl("webhook_delivery"))
+            .values(vec![
+                (col("status"), delivery.status.to_string().into()),
+                (col("attempts"), delivery.attempts.into()),
+                (col("response_status"), delivery.response_status.into()),
+                (col("last_error"), delivery.last_error.into()),
+                (col("update_time"), delivery.update_time.into()),
+            ])
+            .and_where(cond("id").eq(delivery.id))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook update_delivery error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn find_deliveries<D: Dialect>(
+        db: impl DBContext<D>,
+        webhook_id: String,
+    ) -> Result<Vec<entity::WebhookDelivery>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("webhook_delivery"))
+            .and_where(cond("webhook_id").eq(webhook_id))
+            .order_by(col("create_time"), Order::Desc)
+            .limit(100)
+            .build_sqlx(D::builder());
+        match Fetch::<WebhookDelivery>::fetch_all(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("webhook find_deliveries error")),
+            Ok(res) => res
+                .into_iter()
+                .map(entity::WebhookDelivery::try_from)
+                .collect(),
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct Webhook {
+        pub id: String,
+        pub channel_id: String,
+        pub account_id: String,
+        pub url: String,
+        pub secret: String,
+        pub create_time: DateTime<Utc>,
+    }
+
+    impl From<Webhook> for entity::Webhook {
+        fn from(record: Webhook) -> Self {
+            entity::Webhook::new(
+                record.id,
+                record.channel_id,
+                record.account_id,
+                record.url,
+                record.secret,
+                record.create_time,
+            )
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct WebhookDelivery {
+        pub id: String,
+        pub webhook_id: String,
+        pub channel_id: String,
+        pub payload: Vec<u8>,
+        pub status: String,
+        pub attempts: i32,
+        pub response_status: Option<i32>,
+        pub last_error: Option<String>,
+        pub create_time: DateTime<Utc>,
+        pub update_time: DateTime<Utc>,
+    }
+
+    impl TryFrom<WebhookDelivery> for entity::WebhookDelivery {
+        type Error = anyhow::Error;
+
+        fn try_from(record: WebhookDelivery) -> Result<Self> {
+            let status = entity::WebhookDeliveryStatus::from_str(&record.status)
+                .with_context(|| unexpected!("invalid webhook delivery status"))?;
+            Ok(entity::WebhookDelivery::new(
+                record.id,
+                record.webhook_id,
+                record.channel_id,
+                record.payload,
+                status,
+                record.attempts,
+                record.response_status,
+                record.last_error,
+                record.create_time,
+                record.update_time,
+            ))
+        }
+    }
+}
+
+pub mod message {
+    use crate::rdb::dsl::{
+        alias, col, cond, tbl, Cond, Expr, OnConflict, Order, Query, QueryStatementBuilder,
+        SimpleExpr,
+    };
+    use crate::rdb::{DBContext, Dialect, Fetch};
+    use anyhow::Context;
+    use chrono::{DateTime, Utc};
+    use kernel::Result;
+    use kernel::{entity, unexpected};
+    use sea_query_binder::SqlxBinder;
+
+    pub async fn get<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+    ) -> Result<Option<entity::Message>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("message"))
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        match Fetch::<Message>::fetch_optional(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("message get error")),
+            Ok(res) => Ok(res.map(entity::Message::from)),
+        }
+    }
+
+    pub async fn create<D: Dialect>(
+        db: impl DBContext<D>,
+        new_message: entity::Message,
+    ) -> Result<entity::Message> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("message"))
+            .columns(vec![
+                col("id"),
+                col("channel_id"),
+                col("account_id"),
+                col("body"),
+                col("parent_id"),
+                col("reply_count"),
+                col("create_time"),
+                col("update_time"),
+            ])
+            .values(vec![
+                new_message.id.clone().into(),
+                new_message.channel_id.clone().into(),
+                new_message.account_id.clone().into(),
+                new_message.body.clone().into(),
+                new_message.parent_id.clone().into(),
+                new_message.reply_count.into(),
+                new_message.create_time.into(),
+                new_message.update_time.into(),
+            ])?
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("message create error")),
+            Ok(_) => Ok(new_message),
+        }
+    }
+
+    pub async fn update<D: Dialect>(db: impl DBContext<D>, message: entity::Message) -> Result<()> {
+        let (query, values) = Query::update()
+            .table(tbl("message"))
+            .values(vec![
+                (col("body"), message.body.into()),
+                (col("update_time"), message.update_time.into()),
+            ])
+            .and_where(cond("id").eq(message.id))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("message update error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn find_replies<D: Dialect>(
+        db: impl DBContext<D>,
+        parent_id: String,
+    ) -> Result<Vec<entity::Message>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("message"))
+            .and_where(cond("parent_id").eq(parent_id))
+            .and_where(cond("delete_time").is_null())
+            .order_by(col("create_time"), Order::Asc)
+            .build_sqlx(D::builder());
+        match Fetch::<Message>::fetch_all(&mut *db.acquire().await?.lock().await, &query, values)
+            .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("message find_replies error")),
+            Ok(res) => Ok(res.into_iter().map(entity::Message::from).collect()),
+        }
+    }
+
+    /// the messages of the channel created after the given one, oldest first. messages created
+    /// at the same time are ordered by id, so that none is skipped or repeated between calls.
+    pub async fn find_after<D: Dialect>(
+        db: impl DBContext<D>,
+        channel_id: String,
+        message_id: String,
+        limit: u64,
+    ) -> Result<Vec<entity::Message>> {
+        let create_time = || {
+            SimpleExpr::SubQuery(
+                None,
+                Box::new(
+                    Query::select()
+                        .column(col("create_time"))
+                        .from(tbl("message"))
+                        .and_where(cond("id").eq(message_id.clone()))
+                        .to_owned()
+                        .into_sub_query_statement(),
+                ),
+            )
+        };
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("message"))
+            .and_where(cond("channel_id").eq(channel_id))
+            .and_where(cond("delete_time").is_null())
+            .cond_where(
+                Cond::any().add(cond("create_time").gt(create_time())).add(
+                    cond("create_time")
+                        .eq(create_time())
+                        .and(cond("id").gt(message_id.clone())),
+                ),
+            )
+            .order_by(col("create_time"), Order::Asc)
+            .order_by(col("id"), Order::Asc)
+            .limit(limit)
+            .build_sqlx(D::builder());
+        match Fetch::<Message>::fetch_all(&mut *db.acquire().await?.lock().await, &query, values)
+            .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("message find_after error")),
+            Ok(res) => Ok(res.into_iter().map(entity::Message::from).collect()),
+        }
+    }
+
+    /// the oldest message of the channel, deleted or not.
+    pub async fn find_first<D: Dialect>(
+        db: impl DBContext<D>,
+        channel_id: String,
+    ) -> Result<Option<entity::Message>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("message"))
+            .and_where(cond("channel_id").eq(channel_id))
+            .order_by(col("create_time"), Order::Asc)
+            .order_by(col("id"), Order::Asc)
+            .limit(1)
+            .build_sqlx(D::builder());
+        match Fetch::<Message>::fetch_optional(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("message find_first error")),
+            Ok(res) => Ok(res.map(entity::Message::from)),
+        }
+    }
+
+    pub async fn increment_reply_count<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+        delta: i64,
+    ) -> Result<()> {
+        let (query, values) = Query::update()
+            .table(tbl("message"))
+            .value(col("reply_count"), Expr::col(col("reply_count")).add(delta))
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => {
+                Err(err).with_context(|| unexpected!("message increment_reply_count error"))
+            }
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn delete<D: Dialect>(
+        db: impl DBContext<D>,
+        id: String,
+        deleted_by: String,
+        delete_time: DateTime<Utc>,
+    ) -> Result<()> {
+        let (query, values) = Query::update()
+            .table(tbl("message"))
+            .values(vec![
+                (col("deleted_by"), deleted_by.into()),
+                (col("delete_time"), delete_time.into()),
+            ])
+            .and_where(cond("id").eq(id))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("message delete error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn create_edit<D: Dialect>(
+        db: impl DBContext<D>,
+        edit: entity::MessageEdit,
+    ) -> Result<()> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("message_edit"))
+            .columns(vec![
+                col("id"),
+                col("message_id"),
+                col("body"),
+                col("edited_by"),
+                col("create_time"),
+            ])
+            .values(vec![
+                edit.id.into(),
+                edit.message_id.into(),
+                edit.body.into(),
+                edit.edited_by.into(),
+                edit.create_time.into(),
+            ])?
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("message create_edit error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn find_edits<D: Dialect>(
+        db: impl DBContext<D>,
+        message_id: String,
+    ) -> Result<Vec<entity::MessageEdit>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("message_edit"))
+            .and_where(cond("message_id").eq(message_id))
+            .order_by(col("create_time"), Order::Asc)
+            .build_sqlx(D::builder());
+        match Fetch::<MessageEdit>::fetch_all(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("message find_edits error")),
+            Ok(res) => Ok(res.into_iter().map(entity::MessageEdit::from).collect()),
+        }
+    }
+
+    pub async fn add_reaction<D: Dialect>(
+        db: impl DBContext<D>,
+        reaction: entity::Reaction,
+    ) -> Result<()> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("message_reaction"))
+            .columns(vec![
+                col("message_id"),
+                col("account_id"),
+                col("emoji"),
+                col("create_time"),
+            ])
+            .values(vec![
+                reaction.message_id.into(),
+                reaction.account_id.into(),
+                reaction.emoji.into(),
+                reaction.create_time.into(),
+            ])?
+            .on_conflict(
+                OnConflict::columns(vec![col("message_id"), col("account_id"), col("emoji")])
+                    .update_column(col("emoji"))
+                    .to_owned(),
+            )
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("message add_reaction error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn remove_reaction<D: Dialect>(
+        db: impl DBContext<D>,
+        message_id: String,
+        account_id: String,
+        emoji: String,
+    ) -> Result<()> {
+        let (query, values) = Query::delete()
+            .from_table(tbl("message_reaction"))
+            .and_where(cond("message_id").eq(message_id))
+            .and_where(cond("account_id").eq(account_id))
+            .and_where(cond("emoji").eq(emoji))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("message remove_reaction error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn count_reactions<D: Dialect>(
+        db: impl DBContext<D>,
+        message_id: String,
+    ) -> Result<Vec<entity::ReactionCount>> {
+        let (query, values) = Query::select()
+            .column(col("emoji"))
+            .expr_as(Expr::cust("COUNT(*)"), alias("count"))
+            .from(tbl("message_reaction"))
+            .and_where(cond("message_id").eq(message_id))
+            .group_by_col(col("emoji"))
+            .order_by_expr(Expr::cust("MIN(create_time)"), Order::Asc)
+            .build_sqlx(D::builder());
+        match Fetch::<(String, i64)>::fetch_all(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("message count_reactions error")),
+            Ok(res) => Ok(res
+                .into_iter()
+                .map(|(emoji, count)| entity::ReactionCount::new(emoji, count))
+                .collect()),
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct Message {
+        pub id: String,
+        pub channel_id: String,
+        pub account_id: String,
+        pub body: String,
+        pub parent_id: Option<String>,
+        pub reply_count: i64,
+        pub create_time: DateTime<Utc>,
+        pub update_time: DateTime<Utc>,
+        pub delete_time: Option<DateTime<Utc>>,
+        pub deleted_by: Option<String>,
+    }
+
+    impl From<Message> for entity::Message {
+        fn from(record: Message) -> Self {
+            entity::Message::new(
+                record.id,
+                record.channel_id,
+                record.account_id,
+                record.body,
+                record.parent_id,
+                record.reply_count,
+                record.create_time,
+                record.update_time,
+                record.delete_time,
+                record.deleted_by,
+            )
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct MessageEdit {
+        pub id: String,
+        pub message_id: String,
+        pub body: String,
+        pub edited_by: String,
+        pub create_time: DateTime<Utc>,
+    }
+
+    impl From<MessageEdit> for entity::MessageEdit {
+        fn from(record: MessageEdit) -> Self {
+            entity::MessageEdit::new(
+                record.id,
+                record.message_id,
+                record.body,
+                record.edited_by,
+                record.create_time,
+            )
+        }
+    }
+}
+
+pub mod channel {
+    use crate::rdb::dsl::{
+        alias, col, cond, tbl, Expr, JoinType, OnConflict, Order, Query, UnionType,
+    };
+    use crate::rdb::{DBContext, Dialect, Fetch};
+    use anyhow::Context;
+    use chrono::{DateTime, Utc};
+    use kernel::Result;
+    use kernel::{entity, unexpected};
+    use sea_query_binder::SqlxBinder;
+
+    pub async fn is_moderator<D: Dialect>(
+        db: impl DBContext<D>,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<bool> {
+        let (query, values) = Query::select()
+            .expr(Expr::val(1))
+            .from(tbl("channel_moderator"))
+            .and_where(cond("channel_id").eq(channel_id))
+            .and_where(cond("account_id").eq(account_id))
+            .build_sqlx(D::builder());
+        D::exists(&mut *db.acquire().await?.lock().await, &query, values)
+            .await
+            .with_context(|| unexpected!("channel is_moderator error"))
+    }
+
+    pub async fn find_moderators<D: Dialect>(
+        db: impl DBContext<D>,
+        channel_id: String,
+    ) -> Result<Vec<entity::ChannelModerator>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("channel_moderator"))
+            .and_where(cond("channel_id").eq(channel_id))
+            .order_by(col("create_time"), Order::Asc)
+            .order_by(col("account_id"), Order::Asc)
+            .build_sqlx(D::builder());
+        match Fetch::<ChannelModerator>::fetch_all(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("channel find_moderators error")),
+            Ok(res) => Ok(res
+                .into_iter()
+                .map(entity::ChannelModerator::from)
+                .collect()),
+        }
+    }
+
+    pub async fn add_moderator<D: Dialect>(
+        db: impl DBContext<D>,
+        moderator: entity::ChannelModerator,
+    ) -> Result<()> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("channel_moderator"))
+            .columns(vec![
+                col("channel_id"),
+                col("account_id"),
+                col("create_time"),
+            ])
+            .values(vec![
+                moderator.channel_id.into(),
+                moderator.account_id.into(),
+                moderator.create_time.into(),
+            ])?
+            .on_conflict(
+                OnConflict::columns(vec![col("channel_id"), col("account_id")])
+                    .update_column(col("account_id"))
+                    .to_owned(),
+            )
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("channel add_moderator error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn remove_moderator<D: Dialect>(
+        db: impl DBContext<D>,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<()> {
+        let (query, values) = Query::delete()
+            .from_table(tbl("channel_moderator"))
+            .and_where(cond("channel_id").eq(channel_id))
+            .and_where(cond("account_id").eq(account_id))
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("channel remove_moderator error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn get_read_position<D: Dialect>(
+        db: impl DBContext<D>,
+        channel_id: String,
+        account_id: String,
+    ) -> Result<Option<entity::ReadPosition>> {
+        let (query, values) = Query::select()
+            .expr(Expr::asterisk())
+            .from(tbl("channel_read_position"))
+            .and_where(cond("channel_id").eq(channel_id))
+            .and_where(cond("account_id").eq(account_id))
+            .build_sqlx(D::builder());
+        match Fetch::<ReadPosition>::fetch_optional(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("channel get_read_position error")),
+            Ok(res) => Ok(res.map(entity::ReadPosition::from)),
+        }
+    }
+
+    pub async fn save_read_position<D: Dialect>(
+        db: impl DBContext<D>,
+        position: entity::ReadPosition,
+    ) -> Result<()> {
+        let (query, values) = Query::insert()
+            .into_table(tbl("channel_read_position"))
+            .columns(vec![
+                col("channel_id"),
+                col("account_id"),
+                col("message_id"),
+                col("read_time"),
+                col("update_time"),
+            ])
+            .values(vec![
+                position.channel_id.into(),
+                position.account_id.into(),
+                position.message_id.into(),
+                position.read_time.into(),
+                position.update_time.into(),
+            ])?
+            .on_conflict(
+                OnConflict::columns(vec![col("channel_id"), col("account_id")])
+                    .update_columns(vec![
+                        col("message_id"),
+                        col("read_time"),
+                        col("update_time"),
+                    ])
+                    .to_owned(),
+            )
+            .build_sqlx(D::builder());
+        match D::execute(&mut *db.acquire().await?.lock().await, &query, values).await {
+            Err(err) => Err(err).with_context(|| unexpected!("channel save_read_position error")),
+            Ok(_) => Ok(()),
+        }
+    }
+
+    pub async fn count_unread<D: Dialect>(
+        db: impl DBContext<D>,
+        account_id: String,
+    ) -> Result<Vec<entity::UnreadCount>> {
+        // the channels of the account: where it posted, moderates or has read up to a message.
+        let channels = Query::select()
+            .column(col("channel_id"))
+            .from(tbl("message"))
+            .and_where(cond("account_id").eq(account_id.clone()))
+            .union(
+                UnionType::Distinct,
+                Query::select()
+                    .column(col("channel_id"))
+                    .from(tbl("channel_moderator"))
+                    .and_where(cond("account_id").eq(account_id.clone()))
+                    .to_owned(),
+            )
+            .union(
+                UnionType::Distinct,
+                Query::select()
+                    .column(col("channel_id"))
+                    .from(tbl("channel_read_position"))
+                    .and_where(cond("account_id").eq(account_id.clone()))
+                    .to_owned(),
+            )
+            .to_owned();
+        let (query, values) = Query::select()
+            .column((tbl("channel"), col("channel_id")))
+            .column((tbl("channel_read_position"), col("message_id")))
+            .expr_as(
+                Expr::col((tbl("message"), col("id"))).count(),
+                alias("count"),
+            )
+            .from_subquery(channels, tbl("channel"))
+            .join(
+                JoinType::LeftJoin,
+                tbl("channel_read_position"),
+                Expr::col((tbl("channel_read_position"), col("channel_id")))
+                    .equals((tbl("channel"), col("channel_id")))
+                    .and(
+                        Expr::col((tbl("channel_read_position"), col("account_id")))
+                            .eq(account_id.clone()),
+                    ),
+            )
+            // without a read position, every message of the channel is unread.
+            .join(
+                JoinType::LeftJoin,
+                tbl("message"),
+                Expr::col((tbl("message"), col("channel_id")))
+                    .equals((tbl("channel"), col("channel_id")))
+                    .and(
+                        Expr::col((tbl("channel_read_position"), col("read_time")))
+                            .is_null()
+                            .or(Expr::col((tbl("message"), col("create_time")))
+                                .gt(Expr::col((tbl("channel_read_position"), col("read_time"))))),
+                    )
+                    .and(Expr::col((tbl("message"), col("account_id"))).ne(account_id))
+                    .and(Expr::col((tbl("message"), col("delete_time"))).is_null()),
+            )
+            .group_by_columns(vec![
+                (tbl("channel"), col("channel_id")),
+                (tbl("channel_read_position"), col("message_id")),
+            ])
+            .order_by((tbl("channel"), col("channel_id")), Order::Asc)
+            .build_sqlx(D::builder());
+        match Fetch::<(String, Option<String>, i64)>::fetch_all(
+            &mut *db.acquire().await?.lock().await,
+            &query,
+            values,
+        )
+        .await
+        {
+            Err(err) => Err(err).with_context(|| unexpected!("channel count_unread error")),
+            Ok(res) => Ok(res
+                .into_iter()
+                .map(|(channel_id, message_id, count)| {
+                    entity::UnreadCount::new(channel_id, message_id, count)
+                })
+                .collect()),
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct ReadPosition {
+        pub channel_id: String,
+        pub account_id: String,
+        pub message_id: String,
+        pub read_time: DateTime<Utc>,
+        pub update_time: DateTime<Utc>,
+    }
+
+    impl From<ReadPosition> for entity::ReadPosition {
+        fn from(record: ReadPosition) -> Self {
+            entity::ReadPosition::new(
+                record.channel_id,
+                record.account_id,
+                record.message_id,
+                record.read_time,
+                record.update_time,
+            )
+        }
+    }
+
+    #[derive(sqlx::FromRow, Debug, Clone)]
+    pub struct ChannelModerator {
+        pub channel_id: String,
+        pub account_id: String,
+        pub create_time: DateTime<Utc>,
+    }
+
+    impl From<ChannelModerator> for entity::ChannelModerator {
+        fn from(record: ChannelModerator) -> Self {
+            entity::ChannelModerator::new(record.channel_id, record.account_id, record.create_time)
+        }
+    }
+}
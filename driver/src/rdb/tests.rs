@@ -0,0 +1,717 @@
+use crate::adapter::repository::account::AccountRepositoryImpl;
+use crate::adapter::repository::channel::ChannelRepositoryImpl;
+use crate::adapter::repository::comment::CommentRepositoryImpl;
+use crate::adapter::repository::message::MessageRepositoryImpl;
+use crate::adapter::repository::webhook::WebhookRepositoryImpl;
+use crate::mysql::PoolConnection;
+use crate::rdb::config::Config;
+use crate::rdb::{repository, Backend, DBContext, Dialect, RDBContext, RDB};
+use application::interface::repository::account::AccountRepository;
+use application::interface::repository::authentication::UpdatePassword;
+use application::interface::repository::channel::ChannelRepository;
+use application::interface::repository::comment::CommentRepository;
+use application::interface::repository::message::MessageRepository;
+use application::interface::repository::webhook::WebhookRepository;
+use application::interface::repository::Transaction;
+use async_trait::async_trait;
+use chrono::{DateTime, SubsecRound, Utc};
+use helper::env::get_var_opt;
+use helper::time::current_time;
+use helper::uuid::{self, ToBase62};
+use kernel::entity::{
+    Account, Authentication, ChannelModerator, Message, MessageEdit, Reaction, ReactionCount,
+    ReadPosition, UnreadCount, Webhook, WebhookDelivery, WebhookDeliveryStatus,
+};
+use kernel::i18n::Locale;
+use kernel::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// the backends the suite runs on, an in-memory sqlite always and the others when their url is set.
+async fn databases() -> Vec<RDB> {
+    let mut databases = vec![];
+    let urls = ["MYSQL_TEST_DATABASE_URL", "POSTGRES_TEST_DATABASE_URL"]
+        .into_iter()
+        .filter_map(get_var_opt::<String>)
+        .chain(["sqlite::memory:".to_string()]);
+    for url in urls {
+        let config = Config::new(
+            url,
+            0,
+            2,
+            Duration::from_secs(10),
+            Duration::from_secs(60),
+            Duration::from_secs(600),
+        )
+        .unwrap();
+        let db = RDB::new(config).await.unwrap();
+        db.migrate_up().await.unwrap();
+        databases.push(db);
+    }
+    databases
+}
+
+fn new_id() -> String {
+    uuid::new_v4().to_base62()
+}
+
+async fn create_account(db: &RDB) -> Account {
+    let id = new_id();
+    let mut account = Account::new(
+        id.clone(),
+        id.clone(),
+        "display name".to_string(),
+        current_time(),
+    );
+    account.locale = Some(Locale::Ja);
+    AccountRepositoryImpl
+        .create(db.clone(), account)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn account_round_trip() {
+    for db in databases().await {
+        let account = create_account(&db).await;
+        let found = AccountRepositoryImpl
+            .get(db.clone(), account.id.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, account.id);
+        assert_eq!(found.display_name, "display name");
+        assert_eq!(found.locale, Some(Locale::Ja));
+        let missing = AccountRepositoryImpl
+            .get(db.clone(), new_id())
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn update_locale_changes_the_account_locale() {
+    for db in databases().await {
+        let account = create_account(&db).await;
+        AccountRepositoryImpl
+            .update_locale(db.clone(), account.id.clone(), Locale::En)
+            .await
+            .unwrap();
+        let found = AccountRepositoryImpl
+            .get(db.clone(), account.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.locale, Some(Locale::En));
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn authentication_round_trip() {
+    for db in databases().await {
+        let account = create_account(&db).await;
+        let mail = format!("{}@example.com", account.id);
+        let authentication = Authentication::new(
+            account.id.clone(),
+            mail.clone(),
+            "salt".to_string(),
+            "a".repeat(128),
+        );
+        repository!(db.clone(), authentication::create(authentication)).unwrap();
+        let updated = UpdatePassword::new(account.id.clone(), mail.clone(), "b".repeat(128));
+        repository!(db.clone(), authentication::update_password(updated)).unwrap();
+        let found = repository!(db.clone(), authentication::get_by_mail(mail))
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.account_id, account.id);
+        assert_eq!(found.password_hash, "b".repeat(128));
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn comment_round_trip() {
+    for db in databases().await {
+        let id = new_id();
+        CommentRepositoryImpl
+            .put(db.clone(), id.clone(), "body".to_string())
+            .await
+            .unwrap();
+        let found = CommentRepositoryImpl
+            .get(db.clone(), id.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, id);
+        assert_eq!(found.body, "body");
+        db.close().await;
+    }
+}
+
+async fn create_message(db: &RDB, channel_id: &str, account_id: &str, seconds: i64) -> Message {
+    let time = current_time() + chrono::Duration::seconds(seconds);
+    let message = Message::new(
+        new_id(),
+        channel_id.to_string(),
+        account_id.to_string(),
+        "body".to_string(),
+        None,
+        0,
+        time,
+        time,
+        None,
+        None,
+    );
+    MessageRepositoryImpl
+        .create(db.clone(), message)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn unread_counts_include_channels_without_read_position() {
+    for db in databases().await {
+        let (account_id, other_id) = (new_id(), new_id());
+        let (read, unread, quiet) = (new_id(), new_id(), new_id());
+        let first = create_message(&db, &read, &other_id, 1).await;
+        create_message(&db, &read, &other_id, 2).await;
+        create_message(&db, &read, &account_id, 3).await;
+        create_message(&db, &unread, &account_id, 1).await;
+        create_message(&db, &unread, &other_id, 2).await;
+        create_message(&db, &unread, &other_id, 3).await;
+        create_message(&db, &quiet, &other_id, 1).await;
+        ChannelRepositoryImpl
+            .save_read_position(
+                db.clone(),
+                ReadPosition::new(
+                    read.clone(),
+                    account_id.clone(),
+                    first.id.clone(),
+                    first.create_time,
+                    current_time(),
+                ),
+            )
+            .await
+            .unwrap();
+        let mut counts = ChannelRepositoryImpl
+            .count_unread(db.clone(), account_id.clone())
+            .await
+            .unwrap();
+        counts.sort_by(|a, b| a.channel_id.cmp(&b.channel_id));
+        let mut expected = vec![
+            UnreadCount::new(read, Some(first.id), 1),
+            UnreadCount::new(unread, None, 2),
+        ];
+        expected.sort_by(|a, b| a.channel_id.cmp(&b.channel_id));
+        assert_eq!(counts, expected);
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn find_after_returns_the_later_messages_in_order() {
+    for db in databases().await {
+        let (channel_id, account_id) = (new_id(), new_id());
+        let first = create_message(&db, &channel_id, &account_id, 1).await;
+        let third = create_message(&db, &channel_id, &account_id, 3).await;
+        let second = create_message(&db, &channel_id, &account_id, 2).await;
+        create_message(&db, &new_id(), &account_id, 4).await;
+        let ids = |messages: Vec<Message>| messages.into_iter().map(|m| m.id).collect::<Vec<_>>();
+
+        let after = MessageRepositoryImpl
+            .find_after(db.clone(), channel_id.clone(), first.id.clone(), 10)
+            .await
+            .unwrap();
+        assert_eq!(ids(after), vec![second.id.clone(), third.id.clone()]);
+        let after = MessageRepositoryImpl
+            .find_after(db.clone(), channel_id.clone(), first.id.clone(), 1)
+            .await
+            .unwrap();
+        assert_eq!(ids(after), vec![second.id]);
+        let after = MessageRepositoryImpl
+            .find_after(db.clone(), channel_id.clone(), "unknown".to_string(), 10)
+            .await
+            .unwrap();
+        assert!(after.is_empty());
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn find_first_returns_the_oldest_message_deleted_or_not() {
+    for db in databases().await {
+        let (channel_id, account_id) = (new_id(), new_id());
+        assert!(MessageRepositoryImpl
+            .find_first(db.clone(), channel_id.clone())
+            .await
+            .unwrap()
+            .is_none());
+        create_message(&db, &channel_id, &new_id(), 2).await;
+        let first = create_message(&db, &channel_id, &account_id, 1).await;
+        create_message(&db, &new_id(), &new_id(), 0).await;
+        MessageRepositoryImpl
+            .delete(db.clone(), first.id.clone(), account_id, current_time())
+            .await
+            .unwrap();
+        let found = MessageRepositoryImpl
+            .find_first(db.clone(), channel_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, first.id);
+        db.close().await;
+    }
+}
+
+/// a whole second plus `micros`, so that every backend stores the time without rounding it.
+fn time_at(micros: i64) -> DateTime<Utc> {
+    current_time().trunc_subsecs(0) + chrono::Duration::microseconds(micros)
+}
+
+#[tokio::test]
+async fn message_update_edit_and_delete_round_trip() {
+    for db in databases().await {
+        let (channel_id, account_id) = (new_id(), new_id());
+        let message = create_message(&db, &channel_id, &account_id, 1).await;
+        let (edited, deleted) = (time_at(250_000), time_at(1_500_000));
+        let mut updated = message.clone();
+        updated.body = "edited".to_string();
+        updated.update_time = edited;
+        MessageRepositoryImpl
+            .update(db.clone(), updated)
+            .await
+            .unwrap();
+        for (body, micros) in [("second", 2), ("first", 1)] {
+            let edit = MessageEdit::new(
+                new_id(),
+                message.id.clone(),
+                body.to_string(),
+                account_id.clone(),
+                time_at(micros),
+            );
+            MessageRepositoryImpl
+                .create_edit(db.clone(), edit)
+                .await
+                .unwrap();
+        }
+        MessageRepositoryImpl
+            .increment_reply_count(db.clone(), message.id.clone(), 2)
+            .await
+            .unwrap();
+        MessageRepositoryImpl
+            .increment_reply_count(db.clone(), message.id.clone(), -1)
+            .await
+            .unwrap();
+
+        let found = MessageRepositoryImpl
+            .get(db.clone(), message.id.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.body, "edited");
+        assert_eq!(found.update_time, edited);
+        assert_eq!(found.reply_count, 1);
+        assert!(found.delete_time.is_none());
+        let edits = MessageRepositoryImpl
+            .find_edits(db.clone(), message.id.clone())
+            .await
+            .unwrap();
+        let bodies: Vec<_> = edits.iter().map(|edit| edit.body.as_str()).collect();
+        assert_eq!(bodies, vec!["first", "second"]);
+        assert_eq!(edits[0].create_time, time_at(1));
+
+        MessageRepositoryImpl
+            .delete(db.clone(), message.id.clone(), account_id.clone(), deleted)
+            .await
+            .unwrap();
+        let found = MessageRepositoryImpl
+            .get(db.clone(), message.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.delete_time, Some(deleted));
+        assert_eq!(found.deleted_by, Some(account_id));
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn replies_are_ordered_by_create_time_and_skip_deleted() {
+    for db in databases().await {
+        let (channel_id, account_id) = (new_id(), new_id());
+        let parent = create_message(&db, &channel_id, &account_id, 0).await;
+        // sqlite keeps times as TEXT with 0, 3, 6 or 9 fractional digits, which must still sort
+        // in time order within the same second.
+        let mut replies = vec![];
+        for micros in [1_000_000, 250_000, 250_001, 0, 999_999] {
+            let time = time_at(micros);
+            let reply = Message::new(
+                new_id(),
+                channel_id.clone(),
+                account_id.clone(),
+                format!("{micros}"),
+                Some(parent.id.clone()),
+                0,
+                time,
+                time,
+                None,
+                None,
+            );
+            replies.push(
+                MessageRepositoryImpl
+                    .create(db.clone(), reply)
+                    .await
+                    .unwrap(),
+            );
+        }
+        MessageRepositoryImpl
+            .delete(
+                db.clone(),
+                replies[2].id.clone(),
+                account_id,
+                current_time(),
+            )
+            .await
+            .unwrap();
+
+        let found = MessageRepositoryImpl
+            .find_replies(db.clone(), parent.id)
+            .await
+            .unwrap();
+        let bodies: Vec<_> = found.iter().map(|reply| reply.body.as_str()).collect();
+        assert_eq!(bodies, vec!["0", "250000", "999999", "1000000"]);
+        assert_eq!(found[1].create_time, time_at(250_000));
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn reactions_are_upserted_and_counted_in_first_reaction_order() {
+    for db in databases().await {
+        let message = create_message(&db, &new_id(), &new_id(), 1).await;
+        let (account_id, other_id) = (new_id(), new_id());
+        let reactions = [
+            (&account_id, "👍", 2),
+            (&other_id, "🎉", 1),
+            (&other_id, "👍", 3),
+            // the same reaction again is kept once.
+            (&account_id, "👍", 4),
+        ];
+        for (account_id, emoji, micros) in reactions {
+            let reaction = Reaction::new(
+                message.id.clone(),
+                account_id.clone(),
+                emoji.to_string(),
+                time_at(micros),
+            );
+            MessageRepositoryImpl
+                .add_reaction(db.clone(), reaction)
+                .await
+                .unwrap();
+        }
+        let counts = MessageRepositoryImpl
+            .count_reactions(db.clone(), message.id.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            counts,
+            vec![
+                ReactionCount::new("🎉".to_string(), 1),
+                ReactionCount::new("👍".to_string(), 2),
+            ]
+        );
+
+        MessageRepositoryImpl
+            .remove_reaction(db.clone(), message.id.clone(), other_id, "🎉".to_string())
+            .await
+            .unwrap();
+        let counts = MessageRepositoryImpl
+            .count_reactions(db.clone(), message.id)
+            .await
+            .unwrap();
+        assert_eq!(counts, vec![ReactionCount::new("👍".to_string(), 2)]);
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn moderators_round_trip() {
+    for db in databases().await {
+        let channel_id = new_id();
+        let (first, second) = (new_id(), new_id());
+        for (account_id, micros) in [(&second, 2), (&first, 1), (&second, 3)] {
+            let moderator =
+                ChannelModerator::new(channel_id.clone(), account_id.clone(), time_at(micros));
+            ChannelRepositoryImpl
+                .add_moderator(db.clone(), moderator)
+                .await
+                .unwrap();
+        }
+        let moderators = ChannelRepositoryImpl
+            .find_moderators(db.clone(), channel_id.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            moderators,
+            vec![
+                ChannelModerator::new(channel_id.clone(), first.clone(), time_at(1)),
+                ChannelModerator::new(channel_id.clone(), second.clone(), time_at(2)),
+            ]
+        );
+        assert!(ChannelRepositoryImpl
+            .is_moderator(db.clone(), channel_id.clone(), first.clone())
+            .await
+            .unwrap());
+
+        ChannelRepositoryImpl
+            .remove_moderator(db.clone(), channel_id.clone(), first.clone())
+            .await
+            .unwrap();
+        assert!(!ChannelRepositoryImpl
+            .is_moderator(db.clone(), channel_id.clone(), first)
+            .await
+            .unwrap());
+        assert!(!ChannelRepositoryImpl
+            .is_moderator(db.clone(), new_id(), second)
+            .await
+            .unwrap());
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn read_position_is_upserted() {
+    for db in databases().await {
+        let (channel_id, account_id) = (new_id(), new_id());
+        let missing = ChannelRepositoryImpl
+            .get_read_position(db.clone(), channel_id.clone(), account_id.clone())
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+        let first = ReadPosition::new(
+            channel_id.clone(),
+            account_id.clone(),
+            new_id(),
+            time_at(1),
+            time_at(2),
+        );
+        let second = ReadPosition::new(
+            channel_id.clone(),
+            account_id.clone(),
+            new_id(),
+            time_at(3),
+            time_at(4),
+        );
+        for position in [first, second.clone()] {
+            ChannelRepositoryImpl
+                .save_read_position(db.clone(), position)
+                .await
+                .unwrap();
+        }
+        let found = ChannelRepositoryImpl
+            .get_read_position(db.clone(), channel_id, account_id)
+            .await
+            .unwrap();
+        assert_eq!(found, Some(second));
+        db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn webhook_round_trip() {
+    for db in databases().await {
+        let (channel_id, account_id) = (new_id(), new_id());
+        let mut webhooks = vec![];
+        for micros in [2, 1] {
+            let webhook = Webhook::new(
+                new_id(),
+                channel_id.clone(),
+                account_id.clone(),
+                "https://example.com/hook".to_string(),
+                "secret".to_string(),
+                time_at(micros),
+            );
+            webhooks.push(
+                WebhookRepositoryImpl
+                    .create(db.clone(), webhook)
+                    .await
+                    .unwrap(),
+            );
+        }
+        let found = WebhookRepositoryImpl
+            .find_by_channel(db.clone(), channel_id.clone())
+            .await
+            .unwrap();
+        let ids: Vec<_> = found.into_iter().map(|webhook| webhook.id).collect();
+        assert_eq!(ids, vec![webhooks[1].id.clone(), webhooks[0].id.clone()]);
+        let channel_ids = WebhookRepositoryImpl
+            .find_channel_ids(db.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            channel_ids.iter().filter(|id| **id == channel_id).count(),
+            1
+        );
+
+        let webhook_id = webhooks[0].id.clone();
+        let deliveries: Vec<_> = [1, 2]
+            .into_iter()
+            .map(|micros| {
+                WebhookDelivery::new(
+                    new_id(),
+                    webhook_id.clone(),
+                    channel_id.clone(),
+                    vec![0, 159, 255],
+                    WebhookDeliveryStatus::Pending,
+                    0,
+                    None,
+                    None,
+                    time_at(micros),
+                    time_at(micros),
+                )
+            })
+            .collect();
+        for delivery in deliveries.clone() {
+            WebhookRepositoryImpl
+                .create_delivery(db.clone(), delivery)
+                .await
+                .unwrap();
+        }
+        let mut failed = deliveries[0].clone();
+        failed.status = WebhookDeliveryStatus::Retrying;
+        failed.attempts = 1;
+        failed.response_status = Some(503);
+        failed.last_error = Some("unavailable".to_string());
+        failed.update_time = time_at(3);
+        WebhookRepositoryImpl
+            .update_delivery(db.clone(), failed)
+            .await
+            .unwrap();
+        let found = WebhookRepositoryImpl
+            .find_deliveries(db.clone(), webhook_id.clone())
+            .await
+            .unwrap();
+        // the latest first.
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].id, deliveries[1].id);
+        assert_eq!(found[1].id, deliveries[0].id);
+        assert_eq!(found[1].payload, vec![0, 159, 255]);
+        assert_eq!(found[1].status, WebhookDeliveryStatus::Retrying);
+        assert_eq!(found[1].attempts, 1);
+        assert_eq!(found[1].response_status, Some(503));
+        assert_eq!(found[1].last_error.as_deref(), Some("unavailable"));
+        assert_eq!(found[1].update_time, time_at(3));
+
+        WebhookRepositoryImpl
+            .delete(db.clone(), webhook_id.clone())
+            .await
+            .unwrap();
+        let missing = WebhookRepositoryImpl
+            .get(db.clone(), webhook_id)
+            .await
+            .unwrap();
+        assert!(missing.is_none());
+        db.close().await;
+    }
+}
+
+/// shares a transaction between repository calls like the bin contexts do, as dropping a clone of
+/// a transactional `RDB` rolls it back.
+#[derive(Clone)]
+struct Context {
+    db: Arc<RDB>,
+}
+
+#[async_trait]
+impl Transaction for Context {
+    async fn begin(&self) -> Result<Context> {
+        Ok(Context {
+            db: Arc::new(self.db.begin().await?),
+        })
+    }
+
+    async fn commit(&self) -> Result<Context> {
+        Ok(Context {
+            db: Arc::new(self.db.commit().await?),
+        })
+    }
+}
+
+#[async_trait]
+impl<D: Dialect> DBContext<D> for Context {
+    async fn acquire(&self) -> Result<Arc<Mutex<PoolConnection<D>>>> {
+        DBContext::<D>::acquire(&*self.db).await
+    }
+}
+
+impl RDBContext for Context {
+    fn backend(&self) -> Backend {
+        self.db.backend()
+    }
+}
+
+#[tokio::test]
+async fn transaction_commit_and_rollback() {
+    for db in databases().await {
+        let ctx = Context { db: Arc::new(db) };
+        let committed = new_id();
+        let tx = ctx.begin().await.unwrap();
+        CommentRepositoryImpl
+            .put(tx.clone(), committed.clone(), "body".to_string())
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+        drop(tx);
+        let rolled_back = new_id();
+        let tx = ctx.begin().await.unwrap();
+        CommentRepositoryImpl
+            .put(tx.clone(), rolled_back.clone(), "body".to_string())
+            .await
+            .unwrap();
+        // dropped without a commit.
+        drop(tx);
+        let found = CommentRepositoryImpl
+            .get(ctx.clone(), committed)
+            .await
+            .unwrap();
+        assert!(found.is_some());
+        let found = CommentRepositoryImpl
+            .get(ctx.clone(), rolled_back)
+            .await
+            .unwrap();
+        assert!(found.is_none());
+        ctx.db.close().await;
+    }
+}
+
+#[tokio::test]
+async fn in_memory_pool_waits_for_the_transaction_connection() {
+    let config = Config::new(
+        "sqlite::memory:".to_string(),
+        0,
+        2,
+        Duration::from_millis(100),
+        Duration::from_secs(60),
+        Duration::from_secs(600),
+    )
+    .unwrap();
+    let db = RDB::new(config).await.unwrap();
+    db.migrate_up().await.unwrap();
+    let ctx = Context { db: Arc::new(db) };
+    let id = new_id();
+    let tx = ctx.begin().await.unwrap();
+    CommentRepositoryImpl
+        .put(tx.clone(), id.clone(), "body".to_string())
+        .await
+        .unwrap();
+    // the transaction holds the only connection, so the pool times out.
+    let blocked = CommentRepositoryImpl.get(ctx.clone(), id.clone()).await;
+    assert!(blocked.is_err());
+    let found = CommentRepositoryImpl.get(tx.clone(), id).await.unwrap();
+    assert!(found.is_some());
+    tx.commit().await.unwrap();
+}
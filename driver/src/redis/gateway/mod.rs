@@ -1,11 +1,19 @@
 pub mod pubsub {
+    use crate::metrics::{GaugeGuard, PUBSUB_PUBLISHED, PUBSUB_RECEIVED, PUBSUB_SUBSCRIPTIONS};
     use crate::redis::{RedisPrimaryContext, RedisReaderContext};
     use anyhow::Context as _;
+    use application::interface::gateway::pubsub::SubscriptionEvent;
     use futures::StreamExt;
     use kernel::{unexpected, Result};
     use log;
+    use rand::Rng;
+    use redis::aio::PubSub;
     use redis::AsyncCommands;
-    use tokio::sync::mpsc::Receiver;
+    use std::time::Duration;
+    use tokio::sync::mpsc::{Receiver, Sender};
+
+    const RECONNECT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+    const RECONNECT_MAX_BACKOFF: Duration = Duration::from_secs(30);
 
     pub async fn publish(
         ctx: impl RedisPrimaryContext,
@@ -13,36 +21,196 @@ pub mod pubsub {
         message: &[u8],
     ) -> Result<()> {
         let mut conn = ctx.primary().await?;
-        conn.publish(channel, message)
+        conn.publish::<_, _, ()>(channel, message)
             .await
-            .with_context(|| unexpected!("publish error"))
+            .with_context(|| unexpected!("publish error"))?;
+        PUBSUB_PUBLISHED.inc();
+        Ok(())
     }
 
+    /// subscribes a channel. the first subscription fails fast with an error, after that
+    /// a dropped connection is re-established with jittered backoff and reported as
+    /// [`SubscriptionEvent::Reconnected`].
     pub async fn subscribe(
         ctx: impl RedisReaderContext,
         channel: String,
-    ) -> Result<Receiver<Vec<u8>>> {
-        let (tx, rx) = tokio::sync::mpsc::channel::<Vec<u8>>(1000);
-        let redis = ctx;
+    ) -> Result<Receiver<SubscriptionEvent>> {
+        let mut pubsub = connect(&ctx, &channel).await?;
+        let (tx, rx) = tokio::sync::mpsc::channel::<SubscriptionEvent>(1000);
         tokio::spawn(async move {
-            let mut subscribe_conn = match redis.subscribe_connection().await {
-                Ok(sub) => sub.into_pubsub(),
-                Err(e) => panic!("{e:?}"),
-            };
-            let mut subscribe_stream = match subscribe_conn.subscribe(channel).await {
-                Ok(_) => subscribe_conn.on_message(),
-                Err(e) => panic!("{e:?}"),
-            };
-            while let Some(sub) = subscribe_stream.next().await {
-                match tx.send(sub.get_payload_bytes().to_vec()).await {
-                    Ok(_) => (),
-                    Err(e) => {
-                        log::debug!("send error: {}", e);
-                        break;
+            let _subscription = GaugeGuard::new(&PUBSUB_SUBSCRIPTIONS);
+            loop {
+                {
+                    let mut stream = pubsub.on_message();
+                    loop {
+                        let msg = tokio::select! {
+                            msg = stream.next() => msg,
+                            _ = tx.closed() => return,
+                        };
+                        let Some(msg) = msg else {
+                            break;
+                        };
+                        PUBSUB_RECEIVED.inc();
+                        let event = SubscriptionEvent::Message(msg.get_payload_bytes().to_vec());
+                        if let Err(e) = tx.send(event).await {
+                            log::debug!("send error: {}", e);
+                            return;
+                        }
                     }
                 }
+                log::warn!("subscription to {} is lost. reconnecting.", channel);
+                pubsub = match reconnect(&ctx, &channel, &tx).await {
+                    Some(pubsub) => pubsub,
+                    None => return,
+                };
+                if let Err(e) = tx.send(SubscriptionEvent::Reconnected).await {
+                    log::debug!("send error: {}", e);
+                    return;
+                }
             }
         });
         Ok(rx)
     }
+
+    async fn connect(ctx: &impl RedisReaderContext, channel: &str) -> Result<PubSub> {
+        let mut pubsub = ctx.subscribe_connection().await?.into_pubsub();
+        pubsub
+            .subscribe(channel)
+            .await
+            .with_context(|| unexpected!("subscribe error"))?;
+        Ok(pubsub)
+    }
+
+    /// retries until subscribed, or gives up when the receiver is dropped.
+    async fn reconnect(
+        ctx: &impl RedisReaderContext,
+        channel: &str,
+        tx: &Sender<SubscriptionEvent>,
+    ) -> Option<PubSub> {
+        let mut attempts = 0;
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(backoff(attempts)) => (),
+                _ = tx.closed() => return None,
+            }
+            match connect(ctx, channel).await {
+                Ok(pubsub) => {
+                    log::info!(
+                        "resubscribed to {} after {} attempts.",
+                        channel,
+                        attempts + 1
+                    );
+                    return Some(pubsub);
+                }
+                Err(e) => log::warn!("resubscribe to {} error: {:?}", channel, e),
+            }
+            attempts += 1;
+        }
+    }
+
+    /// exponential backoff with equal jitter, so that subscribers do not reconnect in lockstep.
+    fn backoff(attempts: u32) -> Duration {
+        let ceiling = RECONNECT_INITIAL_BACKOFF
+            .saturating_mul(2u32.saturating_pow(attempts))
+            .min(RECONNECT_MAX_BACKOFF);
+        let half = ceiling / 2;
+        half + half.mul_f64(rand::thread_rng().gen::<f64>())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{backoff, RECONNECT_INITIAL_BACKOFF, RECONNECT_MAX_BACKOFF};
+
+        #[test]
+        fn backoff_is_jittered_and_capped() {
+            for attempts in [0, 1, 5, 100] {
+                let ceiling = RECONNECT_INITIAL_BACKOFF
+                    .saturating_mul(2u32.saturating_pow(attempts))
+                    .min(RECONNECT_MAX_BACKOFF);
+                let d = backoff(attempts);
+                assert!(ceiling / 2 <= d && d <= ceiling, "{attempts}: {d:?}");
+            }
+        }
+    }
+}
+
+pub mod rate_limit {
+    use crate::redis::RedisPrimaryContext;
+    use anyhow::Context as _;
+    use application::interface::gateway::rate_limit::Bucket;
+    use kernel::{unexpected, Result};
+    use once_cell::sync::Lazy;
+    use redis::Script;
+    use std::time::Duration;
+
+    /// takes a token from the bucket at KEYS[1] atomically, refilling it by the redis server clock
+    /// so that every node sees the same bucket.
+    /// returns `{allowed, remaining, retry_after_ms, reset_after_ms}`.
+    static TOKEN_BUCKET: Lazy<Script> = Lazy::new(|| {
+        Script::new(
+            r"
+local capacity = tonumber(ARGV[1])
+local rate = tonumber(ARGV[2]) / 1000
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'ts')
+local tokens = tonumber(bucket[1]) or capacity
+local ts = tonumber(bucket[2]) or now
+tokens = math.min(capacity, tokens + math.max(0, now - ts) * rate)
+local allowed = 0
+if tokens >= 1 then
+  tokens = tokens - 1
+  allowed = 1
+end
+local reset_after = math.ceil((capacity - tokens) / rate)
+redis.call('HSET', KEYS[1], 'tokens', tostring(tokens), 'ts', now)
+redis.call('PEXPIRE', KEYS[1], reset_after + 1000)
+return {allowed, math.floor(tokens), math.ceil(math.max(0, 1 - tokens) / rate), reset_after}
+",
+        )
+    });
+
+    pub async fn acquire(
+        ctx: impl RedisPrimaryContext,
+        key: String,
+        capacity: u32,
+        refill_per_second: f64,
+    ) -> Result<Bucket> {
+        let mut conn = ctx.primary().await?;
+        let (allowed, remaining, retry_after, reset_after): (i64, u32, u64, u64) = TOKEN_BUCKET
+            .key(crate::redis::compose_key("rate_limit", &key))
+            .arg(capacity)
+            .arg(refill_per_second)
+            .invoke_async(&mut *conn)
+            .await
+            .with_context(|| unexpected!("rate limit acquire error"))?;
+        Ok(Bucket::new(
+            allowed == 1,
+            remaining,
+            Duration::from_millis(retry_after),
+            Duration::from_millis(reset_after),
+        ))
+    }
+}
+
+pub mod health {
+    use crate::redis::{RedisPrimaryContext, RedisReaderContext};
+    use anyhow::Context as _;
+    use kernel::{unexpected, Result};
+
+    pub async fn ping_primary(ctx: impl RedisPrimaryContext) -> Result<()> {
+        let mut conn = ctx.primary().await?;
+        redis::cmd("PING")
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .with_context(|| unexpected!("ping primary error"))
+    }
+
+    pub async fn ping_reader(ctx: impl RedisReaderContext) -> Result<()> {
+        let mut conn = ctx.reader().await?;
+        redis::cmd("PING")
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .with_context(|| unexpected!("ping reader error"))
+    }
 }
@@ -6,8 +6,10 @@ pub use bb8_redis::bb8::Pool as RedisPool;
 pub use bb8_redis::bb8::PooledConnection as PooledRedisConnection;
 pub use bb8_redis::RedisConnectionManager;
 pub type RedisConnection = <RedisConnectionManager as bb8::ManageConnection>::Connection;
-use kernel::Result;
+use anyhow::bail;
+use kernel::{unexpected, Result};
 use marker_blanket::marker_blanket;
+use std::sync::{Arc, PoisonError, RwLock};
 
 pub mod config;
 pub mod gateway;
@@ -15,10 +17,14 @@ pub mod repository;
 
 #[derive(Clone, Debug)]
 pub enum Redis {
-    Pool {
-        primary: RedisPool<RedisConnectionManager>,
-        reader: RedisPool<RedisConnectionManager>,
-    },
+    /// emptied on close, so that the pools drop as the connections in use are returned.
+    Pool(Arc<RwLock<Option<RedisPools>>>),
+}
+
+#[derive(Clone, Debug)]
+pub struct RedisPools {
+    pub primary: RedisPool<RedisConnectionManager>,
+    pub reader: RedisPool<RedisConnectionManager>,
 }
 
 #[async_trait]
@@ -49,31 +55,46 @@ impl Redis {
             .max_size(config.max_size)
             .build(manager)
             .await?;
-        Ok(Redis::Pool { primary, reader })
+        let pools = RedisPools { primary, reader };
+        Ok(Redis::Pool(Arc::new(RwLock::new(Some(pools)))))
+    }
+
+    /// the pools, unless closed.
+    pub fn pools(&self) -> Result<RedisPools> {
+        match self {
+            Redis::Pool(pools) => match &*pools.read().unwrap_or_else(PoisonError::into_inner) {
+                Some(pools) => Ok(pools.clone()),
+                None => bail!(unexpected!("redis is closed")),
+            },
+        }
+    }
+
+    /// stops handing out connections, bb8 has no explicit close.
+    /// the idle connections are closed right away, the others as they are returned.
+    pub fn close(&self) {
+        match self {
+            Redis::Pool(pools) => {
+                pools.write().unwrap_or_else(PoisonError::into_inner).take();
+            }
+        }
     }
 }
 
 #[async_trait]
 impl RedisPrimaryContext for Redis {
     async fn primary(&self) -> Result<PooledRedisConnection<RedisConnectionManager>> {
-        match self {
-            Redis::Pool { primary, reader: _ } => Ok(primary.get().await?),
-        }
+        Ok(self.pools()?.primary.get_owned().await?)
     }
 }
 
 #[async_trait]
 impl RedisReaderContext for Redis {
     async fn reader(&self) -> Result<PooledRedisConnection<RedisConnectionManager>> {
-        match self {
-            Redis::Pool { primary: _, reader } => Ok(reader.get().await?),
-        }
+        Ok(self.pools()?.reader.get_owned().await?)
     }
 
     async fn subscribe_connection(&self) -> Result<RedisConnection> {
-        match self {
-            Redis::Pool { primary: _, reader } => Ok(reader.dedicated_connection().await?),
-        }
+        Ok(self.pools()?.reader.dedicated_connection().await?)
     }
 }
 
@@ -1,3 +1,33 @@
+pub mod analytics {
+    use crate::redis::{compose_key, RedisPrimaryContext};
+    use anyhow::Context as _;
+    use helper::json::ToJson;
+    use kernel::entity::AnalyticsEvent;
+    use kernel::{unexpected, Result};
+    use std::time::Duration;
+
+    /// appends to a list per tracking id, trimmed to the latest `max_events`.
+    pub async fn record(
+        ctx: impl RedisPrimaryContext,
+        event: AnalyticsEvent,
+        max_events: usize,
+        retention: Duration,
+    ) -> Result<()> {
+        let mut conn = ctx.primary().await?;
+        let key = compose_key("analytics", &event.tracking_id);
+        redis::pipe()
+            .atomic()
+            .rpush(&key, event.to_json_vec()?)
+            .ignore()
+            .ltrim(&key, -(max_events as isize), -1)
+            .ignore()
+            .pexpire(&key, retention.as_millis() as usize)
+            .ignore()
+            .query_async::<_, ()>(&mut *conn)
+            .await
+            .with_context(|| unexpected!("analytics record error"))
+    }
+}
 pub mod authentication {
     use crate::redis::{compose_key, RedisPrimaryContext, RedisReaderContext};
     use anyhow::Context as _;
@@ -91,9 +121,73 @@ pub mod session {
     }
     pub async fn delete(ctx: impl RedisPrimaryContext, id: String) -> Result<()> {
         let mut conn = ctx.primary().await?;
-        conn.del(compose_key("session", &id))
+        conn.del::<_, ()>(compose_key("session", &id))
             .await
             .with_context(|| unexpected!("session delete error"))?;
         Ok(())
     }
 }
+pub mod idempotency {
+    use crate::redis::{compose_key, RedisPrimaryContext};
+    use anyhow::Context as _;
+    use helper::json::{FromJson, ToJson};
+    use kernel::entity::IdempotencyRecord;
+    use kernel::{unexpected, Result};
+    use once_cell::sync::Lazy;
+    use redis::{AsyncCommands, Script};
+    use std::time::Duration;
+
+    /// sets KEYS[1] unless it exists, in which case the stored value is returned instead.
+    static INSERT: Lazy<Script> = Lazy::new(|| {
+        Script::new(
+            r"
+if redis.call('SET', KEYS[1], ARGV[1], 'NX', 'PX', ARGV[2]) then
+  return false
+end
+return redis.call('GET', KEYS[1])
+",
+        )
+    });
+
+    pub async fn insert(
+        ctx: impl RedisPrimaryContext,
+        key: String,
+        record: IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<Option<IdempotencyRecord>> {
+        let mut conn = ctx.primary().await?;
+        let existing: Option<Vec<u8>> = INSERT
+            .key(compose_key("idempotency", &key))
+            .arg(record.to_json_vec()?)
+            .arg(ttl.as_millis() as u64)
+            .invoke_async(&mut *conn)
+            .await
+            .with_context(|| unexpected!("idempotency insert error"))?;
+        match existing {
+            Some(x) => Ok(Some(x.deserialize()?)),
+            None => Ok(None),
+        }
+    }
+    pub async fn set(
+        ctx: impl RedisPrimaryContext,
+        key: String,
+        record: IdempotencyRecord,
+        ttl: Duration,
+    ) -> Result<()> {
+        let mut conn = ctx.primary().await?;
+        conn.pset_ex(
+            compose_key("idempotency", &key),
+            record.to_json_vec()?.as_slice(),
+            ttl.as_millis() as usize,
+        )
+        .await
+        .with_context(|| unexpected!("idempotency set error"))
+    }
+    pub async fn delete(ctx: impl RedisPrimaryContext, key: String) -> Result<()> {
+        let mut conn = ctx.primary().await?;
+        conn.del::<_, ()>(compose_key("idempotency", &key))
+            .await
+            .with_context(|| unexpected!("idempotency delete error"))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::rdb::{Dialect, RDB};
+use crate::sqlite::config::Config;
+use anyhow::Result;
+use sea_query::SqliteQueryBuilder;
+use sqlx::migrate::Migrator;
+pub use sqlx::pool::PoolConnection;
+pub use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+pub use sqlx::Pool as SqlitePool;
+pub use sqlx::Sqlite;
+
+use std::str::FromStr;
+
+pub mod config;
+
+pub type DB = crate::rdb::db::DB<Sqlite>;
+
+/// the versioned schema in `driver/migrations/sqlite`, embedded at compile time.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+
+impl DB {
+    /// opens `sqlite://<path>`, creating the file if missing, or `sqlite::memory:`.
+    ///
+    /// an in-memory database has a single connection, which a transaction holds until it ends.
+    /// a query made on the pool meanwhile waits for it, and fails after `connect_timeout`, so
+    /// everything a transaction reads has to go through the transaction. it suits tests and
+    /// local runs, concurrent use wants a file.
+    pub async fn new(config: Config) -> Result<DB> {
+        let options = SqliteConnectOptions::from_str(&config.url)?.create_if_missing(true);
+        let pool_options = if config.url.contains(":memory:") {
+            // an in-memory database lives as long as its connections, so a single one is kept
+            // open. it also serializes the writes sqlite would reject as locked otherwise.
+            SqlitePoolOptions::new()
+                .min_connections(1)
+                .max_connections(1)
+                .idle_timeout(None)
+                .max_lifetime(None)
+        } else {
+            SqlitePoolOptions::new()
+                .min_connections(config.min_connections)
+                .max_connections(config.max_connections)
+                .idle_timeout(config.idle_timeout)
+                .max_lifetime(config.max_lifetime)
+        };
+        let db_pool = pool_options
+            .acquire_timeout(config.connect_timeout)
+            .connect_with(options)
+            .await?;
+        Ok(DB::Pool(db_pool))
+    }
+}
+
+impl Dialect for Sqlite {
+    const SYSTEM: &'static str = "sqlite";
+    type QueryBuilder = SqliteQueryBuilder;
+
+    fn migrator() -> &'static Migrator {
+        &MIGRATOR
+    }
+    fn db(rdb: &RDB) -> Option<&DB> {
+        match rdb {
+            RDB::Sqlite(db) => Some(db),
+            _ => None,
+        }
+    }
+}
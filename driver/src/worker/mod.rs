@@ -0,0 +1,25 @@
+use crate::dispatch;
+use crate::{Present, UsePresenter};
+use application::interface::Component;
+use application::usecase::webhook::{WebhookWorkerInput, WebhookWorkerOutput};
+use application::usecase::UseUseCase;
+use kernel::Result;
+use std::time::Duration;
+use trait_set::trait_set;
+
+pub async fn start_webhook_worker<M: Mods<P>, P: Presenter>(
+    rescan_interval: Duration,
+    mods: M,
+) -> Result<()> {
+    dispatch(WebhookWorkerInput::new(rescan_interval), mods).await;
+    Ok(())
+}
+
+trait_set! {
+    pub trait Mods<P: Presenter> = Component
+    + UseUseCase<WebhookWorkerInput, WebhookWorkerOutput>
+    + UsePresenter<Presenter = P>
+    ;
+    pub trait Presenter = Component + Present<Result<WebhookWorkerOutput>, Output = ()>
+    ;
+}
@@ -1,5 +1,6 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
+use hmac::{Hmac, Mac};
 use sha2;
 use sha2::Digest;
 
@@ -12,3 +13,14 @@ pub fn stretch_password(origin: &str, salt: &str, pepper: &str, count: i64) -> R
     }
     Ok(output)
 }
+
+pub fn hmac_sha256(key: &str, message: &[u8]) -> Result<String> {
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(key.as_bytes())
+        .map_err(|e| anyhow!("hmac_sha256 key error: {e}"))?;
+    mac.update(message);
+    Ok(format!("{:x}", mac.finalize().into_bytes()))
+}
+
+pub fn sha256(message: &[u8]) -> String {
+    format!("{:x}", sha2::Sha256::digest(message))
+}
@@ -0,0 +1,53 @@
+## problem titles, keyed by the error code with `/` replaced by `-`.
+
+common-bad_request = Bad request
+common-unauthorized = Unauthorized
+common-forbidden = Forbidden
+common-not_found = Not found
+common-unexpected = Unexpected error
+common-validation_failed = Validation failed
+common-payload_too_large = Payload too large
+common-rate_limited = Too many requests
+common-csrf_rejected = Cross-site request rejected
+common-idempotency_conflict = Request with the same idempotency key is in progress
+common-idempotency_key_reused = Idempotency key was used for another request
+auth-invalid_email_or_password = Invalid email or password
+auth-session_required = Session required
+auth-invalid_session = Invalid session
+auth-invalid_password_reset_code = Invalid password reset code
+auth-mail_delivery_failed = Mail delivery failed
+account-already_exists = Account already exists
+account-not_found = Account not found
+account-unsupported_locale = Unsupported locale
+channel-message_rejected = Message rejected
+channel-invalid_filter_pattern = Invalid filter pattern
+channel-event_encoding_failed = Channel event encoding failed
+channel-not_moderator = Not a moderator of the channel
+channel-last_moderator = The last moderator of the channel
+message-not_found = Message not found
+message-not_editable = Only the author or a channel moderator can change the message
+webhook-invalid_url = Invalid webhook url
+webhook-not_found = Webhook not found
+webhook-not_owner = Not the owner of the webhook
+
+## problem details.
+
+validation-failed-detail = request has invalid parameters.
+rate-limited-detail = rate limit exceeded.
+session-invalid-detail = invalid session
+session-not-found-detail = session not found.
+session-state-invalid-detail = invalid session state.
+account-already-exists-detail = already exists. { $mail }
+account-not-found-detail = account not found.
+account-unsupported-locale-detail = unsupported locale. { $locale }
+signin-failed-detail = invalid email or password.
+password-reset-code-not-found-detail = invalid password reset code. code not found.
+password-reset-account-not-found-detail = invalid password reset code. account not found
+mail-delivery-failed-detail = failed to send mail
+
+## mails.
+
+signup-mail-subject = signup link
+signup-mail-body = signup here! { $url }
+password-reset-mail-subject = password reset link
+password-reset-mail-body = password reset here! { $url }
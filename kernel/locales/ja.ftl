@@ -0,0 +1,53 @@
+## problem titles, keyed by the error code with `/` replaced by `-`.
+
+common-bad_request = 不正なリクエストです
+common-unauthorized = 認証されていません
+common-forbidden = 許可されていません
+common-not_found = 見つかりません
+common-unexpected = 予期しないエラーが発生しました
+common-validation_failed = 入力内容に誤りがあります
+common-payload_too_large = リクエストが大きすぎます
+common-rate_limited = リクエストが多すぎます
+common-csrf_rejected = クロスサイトリクエストを拒否しました
+common-idempotency_conflict = 同じ冪等キーのリクエストを処理中です
+common-idempotency_key_reused = 冪等キーが別のリクエストで使われています
+auth-invalid_email_or_password = メールアドレスまたはパスワードが正しくありません
+auth-session_required = ログインが必要です
+auth-invalid_session = セッションが無効です
+auth-invalid_password_reset_code = パスワード再設定コードが無効です
+auth-mail_delivery_failed = メールを送信できませんでした
+account-already_exists = アカウントは既に存在します
+account-not_found = アカウントが見つかりません
+account-unsupported_locale = 対応していない言語です
+channel-message_rejected = メッセージが拒否されました
+channel-invalid_filter_pattern = フィルタのパターンが不正です
+channel-event_encoding_failed = チャンネルイベントを変換できませんでした
+channel-not_moderator = チャンネルのモデレーターではありません
+channel-last_moderator = チャンネルの最後のモデレーターです
+message-not_found = メッセージが見つかりません
+message-not_editable = メッセージを変更できるのは投稿者かチャンネルのモデレーターのみです
+webhook-invalid_url = Webhook の URL が不正です
+webhook-not_found = Webhook が見つかりません
+webhook-not_owner = Webhook の所有者ではありません
+
+## problem details.
+
+validation-failed-detail = リクエストのパラメータに誤りがあります。
+rate-limited-detail = リクエストが多すぎます。しばらくしてから再度お試しください。
+session-invalid-detail = セッションが無効です。
+session-not-found-detail = セッションが見つかりません。
+session-state-invalid-detail = セッションの状態が不正です。
+account-already-exists-detail = { $mail } は既に登録されています。
+account-not-found-detail = アカウントが見つかりません。
+account-unsupported-locale-detail = { $locale } は対応していない言語です。
+signin-failed-detail = メールアドレスまたはパスワードが正しくありません。
+password-reset-code-not-found-detail = パスワード再設定コードが見つかりません。
+password-reset-account-not-found-detail = パスワード再設定コードのアカウントが見つかりません。
+mail-delivery-failed-detail = メールを送信できませんでした。
+
+## mails.
+
+signup-mail-subject = 会員登録のご案内
+signup-mail-body = 以下のリンクから会員登録を完了してください。 { $url }
+password-reset-mail-subject = パスワード再設定のご案内
+password-reset-mail-body = 以下のリンクからパスワードを再設定してください。 { $url }
@@ -1,11 +1,17 @@
+use crate::i18n::Locale;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use strum::{Display, EnumString};
 use validator::Validate;
 
 #[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
 pub struct ProvisionalSession {
     pub code: String,
     pub authentication: ProvisionalAuthentication,
+    /// becomes the preference of the account created from this session.
+    #[serde(default)]
+    pub locale: Locale,
 }
 
 #[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
@@ -24,6 +30,10 @@ pub struct Account {
     #[validate(length(max = 100))]
     pub display_name: String,
     pub create_time: DateTime<Utc>,
+    /// preferred over the `Accept-Language` of requests in the account's sessions.
+    #[new(default)]
+    #[serde(default)]
+    pub locale: Option<Locale>,
 }
 
 #[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
@@ -51,3 +61,274 @@ pub struct Comment {
     pub body: String,
     pub create_time: DateTime<Utc>,
 }
+
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
+pub struct Webhook {
+    pub id: String,
+    pub channel_id: String,
+    pub account_id: String,
+    #[validate(url)]
+    pub url: String,
+    pub secret: String,
+    pub create_time: DateTime<Utc>,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub webhook_id: String,
+    pub channel_id: String,
+    pub payload: Vec<u8>,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub create_time: DateTime<Utc>,
+    pub update_time: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, EnumString, Display)]
+pub enum WebhookDeliveryStatus {
+    #[strum(to_string = "pending")]
+    Pending,
+    #[strum(to_string = "retrying")]
+    Retrying,
+    #[strum(to_string = "succeeded")]
+    Succeeded,
+    #[strum(to_string = "dead_letter")]
+    DeadLetter,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MessageFilterRule {
+    Profanity {
+        words: Vec<String>,
+        #[serde(default)]
+        action: ProfanityAction,
+    },
+    Link {
+        #[serde(default)]
+        allowed_hosts: Vec<String>,
+    },
+    Regex {
+        pattern: String,
+        replacement: Option<String>,
+        reason: Option<String>,
+    },
+    Length {
+        #[serde(default)]
+        min: usize,
+        max: usize,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfanityAction {
+    #[default]
+    Mask,
+    Reject,
+}
+
+/// a token bucket for requests matching `methods` and `path`.
+/// `path` is matched per segment, `:name` matches any segment and a trailing `*` matches the rest.
+/// it is the whole request path, with the `/api/v1` prefix the api is mounted at.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RateLimitRule {
+    pub path: String,
+    #[serde(default)]
+    pub methods: Vec<String>,
+    #[serde(default)]
+    pub key: RateLimitKey,
+    pub capacity: u32,
+    pub refill_per_second: f64,
+}
+
+impl RateLimitRule {
+    pub fn matches(&self, method: &str, path: &str) -> bool {
+        if !self.methods.is_empty() && !self.methods.iter().any(|m| m.eq_ignore_ascii_case(method))
+        {
+            return false;
+        }
+        let mut segments = path.trim_end_matches('/').split('/');
+        for pattern in self.path.trim_end_matches('/').split('/') {
+            if pattern == "*" {
+                return true;
+            }
+            match segments.next() {
+                Some(segment) if pattern.starts_with(':') && !segment.is_empty() => continue,
+                Some(segment) if segment == pattern => continue,
+                _ => return false,
+            }
+        }
+        segments.next().is_none()
+    }
+}
+
+/// what a rate limit bucket is kept per. a request without a session or tracking id falls back to its ip.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RateLimitKey {
+    #[default]
+    Ip,
+    Tracking,
+    Session,
+}
+
+/// what is kept per idempotency key. `fingerprint` identifies the request the key was first used for.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum IdempotencyRecord {
+    InProgress {
+        fingerprint: String,
+    },
+    Completed {
+        fingerprint: String,
+        response: IdempotentResponse,
+    },
+}
+
+impl IdempotencyRecord {
+    pub fn fingerprint(&self) -> &str {
+        match self {
+            IdempotencyRecord::InProgress { fingerprint }
+            | IdempotencyRecord::Completed { fingerprint, .. } => fingerprint,
+        }
+    }
+}
+
+/// the first response for an idempotency key, replayed on retries.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, new)]
+pub struct IdempotentResponse {
+    pub status: u16,
+    pub headers: Vec<(String, Vec<u8>)>,
+    pub body: Vec<u8>,
+}
+
+/// an event reported by a client that consented to be tracked.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
+pub struct AnalyticsEvent {
+    pub tracking_id: String,
+    #[validate(length(min = 1, max = 64))]
+    pub name: String,
+    #[validate(length(max = 32))]
+    pub properties: HashMap<String, String>,
+    pub occur_time: DateTime<Utc>,
+}
+
+#[allow(clippy::too_many_arguments)]
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
+pub struct Message {
+    pub id: String,
+    pub channel_id: String,
+    pub account_id: String,
+    #[validate(length(min = 1, max = 4000))]
+    pub body: String,
+    /// the root message of the thread this message replies to.
+    pub parent_id: Option<String>,
+    pub reply_count: i64,
+    pub create_time: DateTime<Utc>,
+    pub update_time: DateTime<Utc>,
+    pub delete_time: Option<DateTime<Utc>>,
+    pub deleted_by: Option<String>,
+}
+
+/// the body a message had before an edit.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
+pub struct MessageEdit {
+    pub id: String,
+    pub message_id: String,
+    pub body: String,
+    pub edited_by: String,
+    pub create_time: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, new)]
+pub struct Reaction {
+    pub message_id: String,
+    pub account_id: String,
+    #[validate(length(min = 1, max = 32))]
+    pub emoji: String,
+    pub create_time: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, new)]
+pub struct ReactionCount {
+    pub emoji: String,
+    pub count: i64,
+}
+
+/// how far an account has read a channel.
+/// `read_time` is the create time of the last read message, so that positions only move forward.
+#[derive(Serialize, Deserialize, Validate, Debug, Clone, PartialEq, Eq, new)]
+pub struct ReadPosition {
+    pub channel_id: String,
+    pub account_id: String,
+    pub message_id: String,
+    pub read_time: DateTime<Utc>,
+    pub update_time: DateTime<Utc>,
+}
+
+/// an account allowed to edit and delete the messages of others in a channel.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, new)]
+pub struct ChannelModerator {
+    pub channel_id: String,
+    pub account_id: String,
+    pub create_time: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, new)]
+pub struct UnreadCount {
+    pub channel_id: String,
+    /// the message read up to, none until the account reads the channel.
+    pub message_id: Option<String>,
+    pub count: i64,
+}
+
+/// marks a thread reply delivered on the parent channel, so that clients can render collapsed threads.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, new)]
+pub struct ThreadMarker {
+    pub parent_id: String,
+    pub reply_count: i64,
+}
+
+/// events broadcast on a channel so that subscribers can update messages in place.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChannelEvent {
+    MessageCreated {
+        message: Message,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        thread: Option<ThreadMarker>,
+    },
+    MessageEdited {
+        message: Message,
+    },
+    MessageDeleted {
+        message_id: String,
+        deleted_by: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        thread: Option<ThreadMarker>,
+    },
+    ReactionsUpdated {
+        message_id: String,
+        reactions: Vec<ReactionCount>,
+    },
+    /// delivered on the account topic only, so that read state stays private.
+    ReadPositionUpdated {
+        channel_id: String,
+        account_id: String,
+        message_id: String,
+    },
+    /// the subscription was re-established; messages may have been missed in between.
+    Reconnected,
+    /// sent back only to the connection whose message was not published, never broadcast.
+    Rejected {
+        code: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        detail: Option<String>,
+    },
+}
@@ -1,6 +1,8 @@
+use crate::i18n::Locale;
 use serde::Serialize;
 use std::fmt::{Debug, Display, Formatter};
-use strum::{Display, EnumString};
+use strum::{Display, EnumIter, EnumMessage, EnumString};
+use validator::{ValidationErrors, ValidationErrorsKind};
 
 #[derive(Clone, Debug, Serialize)]
 pub enum Error {
@@ -27,6 +29,15 @@ impl Error {
     pub fn unexpected(codes: Codes, s: impl Into<String>) -> Error {
         Error::Unexpected(codes, Some(s.into()))
     }
+    pub fn codes(&self) -> &Codes {
+        match self {
+            Error::BadRequest(codes, _)
+            | Error::Unauthorized(codes, _)
+            | Error::Forbidden(codes, _)
+            | Error::NotFound(codes, _)
+            | Error::Unexpected(codes, _) => codes,
+        }
+    }
     #[must_use]
     pub fn with_codes(&self, codes: Codes) -> Error {
         match self {
@@ -55,22 +66,177 @@ impl Display for Error {
 
 impl std::error::Error for Error {}
 
-#[derive(Serialize, Debug, Clone, EnumString, Display)]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, EnumString, Display, EnumMessage, EnumIter)]
 pub enum Codes {
     // common error
-    #[strum(to_string = "common/bad_request")]
+    #[strum(to_string = "common/bad_request", message = "Bad request")]
     CommonBadRequest,
-    #[strum(to_string = "common/unauthorized")]
+    #[strum(to_string = "common/unauthorized", message = "Unauthorized")]
     CommonUnauthorized,
-    #[strum(to_string = "common/forbidden")]
+    #[strum(to_string = "common/forbidden", message = "Forbidden")]
     CommonForbidden,
-    #[strum(to_string = "common/not_found")]
+    #[strum(to_string = "common/not_found", message = "Not found")]
     CommonNotFound,
-    #[strum(to_string = "common/unexpected")]
+    #[strum(to_string = "common/unexpected", message = "Unexpected error")]
     CommonUnexpected,
+    #[strum(to_string = "common/validation_failed", message = "Validation failed")]
+    ValidationFailed,
+    #[strum(to_string = "common/payload_too_large", message = "Payload too large")]
+    PayloadTooLarge,
+    #[strum(to_string = "common/rate_limited", message = "Too many requests")]
+    RateLimited,
+    #[strum(
+        to_string = "common/csrf_rejected",
+        message = "Cross-site request rejected"
+    )]
+    CsrfRejected,
+    #[strum(
+        to_string = "common/idempotency_conflict",
+        message = "Request with the same idempotency key is in progress"
+    )]
+    IdempotencyConflict,
+    #[strum(
+        to_string = "common/idempotency_key_reused",
+        message = "Idempotency key was used for another request"
+    )]
+    IdempotencyKeyReused,
     // special error
-    #[strum(to_string = "auth/invalid_email_or_password")]
+    #[strum(
+        to_string = "auth/invalid_email_or_password",
+        message = "Invalid email or password"
+    )]
     InvalidEmailOrPassword,
+    #[strum(to_string = "auth/session_required", message = "Session required")]
+    SessionRequired,
+    #[strum(to_string = "auth/invalid_session", message = "Invalid session")]
+    InvalidSession,
+    #[strum(
+        to_string = "auth/invalid_password_reset_code",
+        message = "Invalid password reset code"
+    )]
+    InvalidPasswordResetCode,
+    #[strum(
+        to_string = "auth/mail_delivery_failed",
+        message = "Mail delivery failed"
+    )]
+    MailDeliveryFailed,
+    #[strum(
+        to_string = "account/already_exists",
+        message = "Account already exists"
+    )]
+    AccountAlreadyExists,
+    #[strum(to_string = "account/not_found", message = "Account not found")]
+    AccountNotFound,
+    #[strum(
+        to_string = "account/unsupported_locale",
+        message = "Unsupported locale"
+    )]
+    AccountUnsupportedLocale,
+    #[strum(to_string = "channel/message_rejected", message = "Message rejected")]
+    MessageRejected,
+    #[strum(
+        to_string = "channel/invalid_filter_pattern",
+        message = "Invalid filter pattern"
+    )]
+    InvalidFilterPattern,
+    #[strum(
+        to_string = "channel/event_encoding_failed",
+        message = "Channel event encoding failed"
+    )]
+    ChannelEventEncodingFailed,
+    #[strum(
+        to_string = "channel/not_moderator",
+        message = "Not a moderator of the channel"
+    )]
+    NotChannelModerator,
+    #[strum(
+        to_string = "channel/last_moderator",
+        message = "The last moderator of the channel"
+    )]
+    LastChannelModerator,
+    #[strum(to_string = "message/not_found", message = "Message not found")]
+    MessageNotFound,
+    #[strum(
+        to_string = "message/not_editable",
+        message = "Only the author or a channel moderator can change the message"
+    )]
+    MessageNotEditable,
+    #[strum(to_string = "webhook/invalid_url", message = "Invalid webhook url")]
+    InvalidWebhookUrl,
+    #[strum(to_string = "webhook/not_found", message = "Webhook not found")]
+    WebhookNotFound,
+    #[strum(
+        to_string = "webhook/not_owner",
+        message = "Not the owner of the webhook"
+    )]
+    NotWebhookOwner,
+}
+
+impl Codes {
+    /// RFC 7807 problem type, a relative URI reference derived from the code.
+    pub fn problem_type(&self) -> String {
+        format!("/problems/{self}")
+    }
+
+    /// RFC 7807 problem title, constant for every occurrence of the code.
+    pub fn title(&self) -> &'static str {
+        self.get_message().unwrap_or_default()
+    }
+
+    /// `title` from the message catalog of `locale`.
+    pub fn localized_title(&self, locale: Locale) -> String {
+        let id = self.to_string().replace('/', "-");
+        locale
+            .lookup(&id, &[])
+            .unwrap_or_else(|| self.title().to_string())
+    }
+}
+
+/// A single failed constraint of a validated field, addressed by its dotted path.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct FieldViolation {
+    pub field: String,
+    pub code: String,
+    pub message: Option<String>,
+}
+
+impl FieldViolation {
+    pub fn from_errors(errors: &ValidationErrors) -> Vec<FieldViolation> {
+        let mut violations = Vec::new();
+        collect_violations(None, errors, &mut violations);
+        violations.sort_by(|a, b| a.field.cmp(&b.field).then(a.code.cmp(&b.code)));
+        violations
+    }
+}
+
+fn collect_violations(
+    prefix: Option<&str>,
+    errors: &ValidationErrors,
+    violations: &mut Vec<FieldViolation>,
+) {
+    for (field, kind) in errors.errors() {
+        let path = match prefix {
+            Some(prefix) => format!("{prefix}.{field}"),
+            None => field.to_string(),
+        };
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                violations.extend(errors.iter().map(|e| FieldViolation {
+                    field: path.clone(),
+                    code: e.code.to_string(),
+                    message: e.message.as_ref().map(|m| m.to_string()),
+                }))
+            }
+            ValidationErrorsKind::Struct(errors) => {
+                collect_violations(Some(&path), errors, violations)
+            }
+            ValidationErrorsKind::List(list) => {
+                for (index, errors) in list {
+                    collect_violations(Some(&format!("{path}[{index}]")), errors, violations)
+                }
+            }
+        }
+    }
 }
 
 #[macro_export]
@@ -79,7 +245,7 @@ macro_rules! bad_request {
         $crate::error::Error::bad_request($crate::error::Codes::CommonBadRequest, $msg)
     };
     ($fmt:expr, $($arg:tt)*) => {
-        $crate::error::Error::bad_request(format!($crate::error::Codes::CommonBadRequest, $fmt, $($arg)*))
+        $crate::error::Error::bad_request($crate::error::Codes::CommonBadRequest, format!($fmt, $($arg)*))
     };
 }
 
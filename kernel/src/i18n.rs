@@ -0,0 +1,106 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, EnumString};
+use unic_langid::LanguageIdentifier;
+
+type Bundle = FluentBundle<FluentResource>;
+
+static EN: Lazy<Bundle> = Lazy::new(|| bundle("en", include_str!("../locales/en.ftl")));
+static JA: Lazy<Bundle> = Lazy::new(|| bundle("ja", include_str!("../locales/ja.ftl")));
+
+/// a language of the message catalogs in `locales`.
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Display,
+    EnumString,
+    EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Locale {
+    #[default]
+    En,
+    Ja,
+}
+
+impl Locale {
+    pub fn message(&self, id: &str) -> String {
+        self.format(id, &[])
+    }
+
+    /// falls back to the english catalog, and to `id` itself when no catalog has the message.
+    pub fn format(&self, id: &str, args: &[(&str, &str)]) -> String {
+        self.lookup(id, args)
+            .or_else(|| Locale::En.lookup(id, args))
+            .unwrap_or_else(|| id.to_string())
+    }
+
+    pub(crate) fn lookup(&self, id: &str, args: &[(&str, &str)]) -> Option<String> {
+        let bundle = match self {
+            Locale::En => &*EN,
+            Locale::Ja => &*JA,
+        };
+        let pattern = bundle.get_message(id)?.value()?;
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.to_string());
+        }
+        let mut errors = vec![];
+        let message = bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+        Some(message.into_owned())
+    }
+}
+
+fn bundle(language: &str, source: &str) -> Bundle {
+    let language: LanguageIdentifier = language.parse().expect("invalid catalog language");
+    let resource = FluentResource::try_new(source.to_string()).expect("invalid message catalog");
+    let mut bundle = FluentBundle::new_concurrent(vec![language]);
+    // the messages are not embedded in text of another direction, so no isolation marks.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .expect("duplicated message in catalog");
+    bundle
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Codes;
+    use crate::i18n::Locale;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn every_code_has_a_title_in_every_locale() {
+        for locale in Locale::iter() {
+            for code in Codes::iter() {
+                let id = code.to_string().replace('/', "-");
+                assert!(locale.lookup(&id, &[]).is_some(), "{locale}: {id}");
+            }
+        }
+        for code in Codes::iter() {
+            assert_eq!(code.localized_title(Locale::En), code.title());
+        }
+    }
+
+    #[test]
+    fn formats_arguments() {
+        assert_eq!(
+            Locale::Ja.format(
+                "account-already-exists-detail",
+                &[("mail", "a@example.com")]
+            ),
+            "a@example.com は既に登録されています。"
+        );
+        assert_eq!(Locale::Ja.message("unknown-message"), "unknown-message");
+    }
+}
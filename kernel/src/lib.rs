@@ -5,6 +5,8 @@ pub mod entity;
 
 pub mod error;
 
+pub mod i18n;
+
 pub use error::Error;
 
 pub type Result<T, E = anyhow::Error> = anyhow::Result<T, E>;
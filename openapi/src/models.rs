@@ -173,41 +173,44 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ChannelMessage {
-    #[serde(rename = "topic")]
-    pub topic: String,
+pub struct AnalyticsEventRequest {
+    #[serde(rename = "name")]
+    pub name: String,
 
-    #[serde(rename = "payload")]
-    pub payload: String,
+    #[serde(rename = "properties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<std::collections::HashMap<String, String>>,
 }
 
-impl ChannelMessage {
+impl AnalyticsEventRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(topic: String, payload: String) -> ChannelMessage {
-        ChannelMessage { topic, payload }
+    pub fn new(name: String) -> AnalyticsEventRequest {
+        AnalyticsEventRequest {
+            name,
+            properties: None,
+        }
     }
 }
 
-/// Converts the ChannelMessage value to the Query Parameters representation (style=form, explode=false)
+/// Converts the AnalyticsEventRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ChannelMessage {
+impl std::string::ToString for AnalyticsEventRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("topic".to_string()),
-            Some(self.topic.to_string()),
-            Some("payload".to_string()),
-            Some(self.payload.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            // Skipping properties in query parameter serialization
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ChannelMessage value
+/// Converts Query Parameters representation (style=form, explode=false) to a AnalyticsEventRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ChannelMessage {
+impl std::str::FromStr for AnalyticsEventRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -215,8 +218,8 @@ impl std::str::FromStr for ChannelMessage {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub topic: Vec<String>,
-            pub payload: Vec<String>,
+            pub name: Vec<String>,
+            pub properties: Vec<std::collections::HashMap<String, String>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -230,7 +233,7 @@ impl std::str::FromStr for ChannelMessage {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ChannelMessage".to_string(),
+                        "Missing value while parsing AnalyticsEventRequest".to_string(),
                     )
                 }
             };
@@ -239,16 +242,18 @@ impl std::str::FromStr for ChannelMessage {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "topic" => intermediate_rep.topic.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "payload" => intermediate_rep.payload.push(
+                    "name" => intermediate_rep.name.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    "properties" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in AnalyticsEventRequest"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ChannelMessage".to_string(),
+                            "Unexpected key while parsing AnalyticsEventRequest".to_string(),
                         )
                     }
                 }
@@ -259,35 +264,33 @@ impl std::str::FromStr for ChannelMessage {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ChannelMessage {
-            topic: intermediate_rep
-                .topic
-                .into_iter()
-                .next()
-                .ok_or_else(|| "topic missing in ChannelMessage".to_string())?,
-            payload: intermediate_rep
-                .payload
+        std::result::Result::Ok(AnalyticsEventRequest {
+            name: intermediate_rep
+                .name
                 .into_iter()
                 .next()
-                .ok_or_else(|| "payload missing in ChannelMessage".to_string())?,
+                .ok_or_else(|| "name missing in AnalyticsEventRequest".to_string())?,
+            properties: intermediate_rep.properties.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ChannelMessage> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<AnalyticsEventRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ChannelMessage>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<AnalyticsEventRequest>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ChannelMessage>,
+        hdr_value: header::IntoHeaderValue<AnalyticsEventRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ChannelMessage - value: {} is invalid {}",
+                "Invalid header value for AnalyticsEventRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -295,18 +298,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ChannelMessage>> for hyper::h
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ChannelMessage> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<AnalyticsEventRequest>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ChannelMessage as std::str::FromStr>::from_str(value) {
+                match <AnalyticsEventRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ChannelMessage - {}",
+                        "Unable to convert header value '{}' into AnalyticsEventRequest - {}",
                         value, err
                     )),
                 }
@@ -321,52 +326,41 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ErrorMessage {
-    #[serde(rename = "status")]
-    pub status: i32,
-
-    #[serde(rename = "type")]
-    pub r#type: String,
+pub struct ChannelMessage {
+    #[serde(rename = "topic")]
+    pub topic: String,
 
-    #[serde(rename = "message")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub message: Option<String>,
+    #[serde(rename = "payload")]
+    pub payload: String,
 }
 
-impl ErrorMessage {
+impl ChannelMessage {
     #[allow(clippy::new_without_default)]
-    pub fn new(status: i32, r#type: String) -> ErrorMessage {
-        ErrorMessage {
-            status,
-            r#type,
-            message: None,
-        }
+    pub fn new(topic: String, payload: String) -> ChannelMessage {
+        ChannelMessage { topic, payload }
     }
 }
 
-/// Converts the ErrorMessage value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ChannelMessage value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ErrorMessage {
+impl std::string::ToString for ChannelMessage {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("status".to_string()),
-            Some(self.status.to_string()),
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
-            self.message
-                .as_ref()
-                .map(|message| vec!["message".to_string(), message.to_string()].join(",")),
+            Some("topic".to_string()),
+            Some(self.topic.to_string()),
+            Some("payload".to_string()),
+            Some(self.payload.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ErrorMessage value
+/// Converts Query Parameters representation (style=form, explode=false) to a ChannelMessage value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ErrorMessage {
+impl std::str::FromStr for ChannelMessage {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -374,9 +368,8 @@ impl std::str::FromStr for ErrorMessage {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub status: Vec<i32>,
-            pub r#type: Vec<String>,
-            pub message: Vec<String>,
+            pub topic: Vec<String>,
+            pub payload: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -390,7 +383,7 @@ impl std::str::FromStr for ErrorMessage {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ErrorMessage".to_string(),
+                        "Missing value while parsing ChannelMessage".to_string(),
                     )
                 }
             };
@@ -399,20 +392,16 @@ impl std::str::FromStr for ErrorMessage {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
-                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
+                    "topic" => intermediate_rep.topic.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
+                    "payload" => intermediate_rep.payload.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ErrorMessage".to_string(),
+                            "Unexpected key while parsing ChannelMessage".to_string(),
                         )
                     }
                 }
@@ -423,36 +412,35 @@ impl std::str::FromStr for ErrorMessage {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ErrorMessage {
-            status: intermediate_rep
-                .status
+        std::result::Result::Ok(ChannelMessage {
+            topic: intermediate_rep
+                .topic
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in ErrorMessage".to_string())?,
-            r#type: intermediate_rep
-                .r#type
+                .ok_or_else(|| "topic missing in ChannelMessage".to_string())?,
+            payload: intermediate_rep
+                .payload
                 .into_iter()
                 .next()
-                .ok_or_else(|| "type missing in ErrorMessage".to_string())?,
-            message: intermediate_rep.message.into_iter().next(),
+                .ok_or_else(|| "payload missing in ChannelMessage".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ErrorMessage> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ChannelMessage> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ErrorMessage>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<ChannelMessage>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ErrorMessage>,
+        hdr_value: header::IntoHeaderValue<ChannelMessage>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ErrorMessage - value: {} is invalid {}",
+                "Invalid header value for ChannelMessage - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -460,18 +448,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ErrorMessage>> for hyper::hea
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ErrorMessage> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ChannelMessage> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ErrorMessage as std::str::FromStr>::from_str(value) {
+                match <ChannelMessage as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ErrorMessage - {}",
+                        "Unable to convert header value '{}' into ChannelMessage - {}",
                         value, err
                     )),
                 }
@@ -486,34 +474,50 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ForgetPasswordRequest {
-    #[serde(rename = "mail")]
-    pub mail: String,
+pub struct ChannelModerator {
+    #[serde(rename = "channel_id")]
+    pub channel_id: String,
+
+    #[serde(rename = "account_id")]
+    pub account_id: String,
+
+    #[serde(rename = "create_time")]
+    pub create_time: String,
 }
 
-impl ForgetPasswordRequest {
+impl ChannelModerator {
     #[allow(clippy::new_without_default)]
-    pub fn new(mail: String) -> ForgetPasswordRequest {
-        ForgetPasswordRequest { mail }
+    pub fn new(channel_id: String, account_id: String, create_time: String) -> ChannelModerator {
+        ChannelModerator {
+            channel_id,
+            account_id,
+            create_time,
+        }
     }
 }
 
-/// Converts the ForgetPasswordRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the ChannelModerator value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ForgetPasswordRequest {
+impl std::string::ToString for ChannelModerator {
     fn to_string(&self) -> String {
-        let params: Vec<Option<String>> =
-            vec![Some("mail".to_string()), Some(self.mail.to_string())];
+        let params: Vec<Option<String>> = vec![
+            Some("channel_id".to_string()),
+            Some(self.channel_id.to_string()),
+            Some("account_id".to_string()),
+            Some(self.account_id.to_string()),
+            Some("create_time".to_string()),
+            Some(self.create_time.to_string()),
+        ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ForgetPasswordRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a ChannelModerator value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ForgetPasswordRequest {
+impl std::str::FromStr for ChannelModerator {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -521,7 +525,9 @@ impl std::str::FromStr for ForgetPasswordRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub mail: Vec<String>,
+            pub channel_id: Vec<String>,
+            pub account_id: Vec<String>,
+            pub create_time: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -535,7 +541,7 @@ impl std::str::FromStr for ForgetPasswordRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ForgetPasswordRequest".to_string(),
+                        "Missing value while parsing ChannelModerator".to_string(),
                     )
                 }
             };
@@ -544,12 +550,20 @@ impl std::str::FromStr for ForgetPasswordRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "mail" => intermediate_rep.mail.push(
+                    "channel_id" => intermediate_rep.channel_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "account_id" => intermediate_rep.account_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "create_time" => intermediate_rep.create_time.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ForgetPasswordRequest".to_string(),
+                            "Unexpected key while parsing ChannelModerator".to_string(),
                         )
                     }
                 }
@@ -560,32 +574,42 @@ impl std::str::FromStr for ForgetPasswordRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ForgetPasswordRequest {
-            mail: intermediate_rep
-                .mail
+        std::result::Result::Ok(ChannelModerator {
+            channel_id: intermediate_rep
+                .channel_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "mail missing in ForgetPasswordRequest".to_string())?,
+                .ok_or_else(|| "channel_id missing in ChannelModerator".to_string())?,
+            account_id: intermediate_rep
+                .account_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "account_id missing in ChannelModerator".to_string())?,
+            create_time: intermediate_rep
+                .create_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "create_time missing in ChannelModerator".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ForgetPasswordRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<ChannelModerator> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ForgetPasswordRequest>>
+impl std::convert::TryFrom<header::IntoHeaderValue<ChannelModerator>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ForgetPasswordRequest>,
+        hdr_value: header::IntoHeaderValue<ChannelModerator>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ForgetPasswordRequest - value: {} is invalid {}",
+                "Invalid header value for ChannelModerator - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -594,19 +618,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ForgetPasswordRequest>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<ForgetPasswordRequest>
+    for header::IntoHeaderValue<ChannelModerator>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ForgetPasswordRequest as std::str::FromStr>::from_str(value) {
+                match <ChannelModerator as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ForgetPasswordRequest - {}",
+                        "Unable to convert header value '{}' into ChannelModerator - {}",
                         value, err
                     )),
                 }
@@ -621,41 +645,33 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ResetPasswordRequest {
-    #[serde(rename = "code")]
-    pub code: String,
-
-    #[serde(rename = "password")]
-    pub password: String,
+pub struct CreateWebhookRequest {
+    #[serde(rename = "url")]
+    pub url: String,
 }
 
-impl ResetPasswordRequest {
+impl CreateWebhookRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(code: String, password: String) -> ResetPasswordRequest {
-        ResetPasswordRequest { code, password }
+    pub fn new(url: String) -> CreateWebhookRequest {
+        CreateWebhookRequest { url }
     }
 }
 
-/// Converts the ResetPasswordRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the CreateWebhookRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for ResetPasswordRequest {
+impl std::string::ToString for CreateWebhookRequest {
     fn to_string(&self) -> String {
-        let params: Vec<Option<String>> = vec![
-            Some("code".to_string()),
-            Some(self.code.to_string()),
-            Some("password".to_string()),
-            Some(self.password.to_string()),
-        ];
+        let params: Vec<Option<String>> = vec![Some("url".to_string()), Some(self.url.to_string())];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ResetPasswordRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateWebhookRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ResetPasswordRequest {
+impl std::str::FromStr for CreateWebhookRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -663,8 +679,7 @@ impl std::str::FromStr for ResetPasswordRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
-            pub password: Vec<String>,
+            pub url: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -678,7 +693,7 @@ impl std::str::FromStr for ResetPasswordRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ResetPasswordRequest".to_string(),
+                        "Missing value while parsing CreateWebhookRequest".to_string(),
                     )
                 }
             };
@@ -687,16 +702,12 @@ impl std::str::FromStr for ResetPasswordRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "password" => intermediate_rep.password.push(
+                    "url" => intermediate_rep.url.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ResetPasswordRequest".to_string(),
+                            "Unexpected key while parsing CreateWebhookRequest".to_string(),
                         )
                     }
                 }
@@ -707,37 +718,32 @@ impl std::str::FromStr for ResetPasswordRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ResetPasswordRequest {
-            code: intermediate_rep
-                .code
-                .into_iter()
-                .next()
-                .ok_or_else(|| "code missing in ResetPasswordRequest".to_string())?,
-            password: intermediate_rep
-                .password
+        std::result::Result::Ok(CreateWebhookRequest {
+            url: intermediate_rep
+                .url
                 .into_iter()
                 .next()
-                .ok_or_else(|| "password missing in ResetPasswordRequest".to_string())?,
+                .ok_or_else(|| "url missing in CreateWebhookRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<ResetPasswordRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<CreateWebhookRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<ResetPasswordRequest>>
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateWebhookRequest>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<ResetPasswordRequest>,
+        hdr_value: header::IntoHeaderValue<CreateWebhookRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for ResetPasswordRequest - value: {} is invalid {}",
+                "Invalid header value for CreateWebhookRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -746,19 +752,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<ResetPasswordRequest>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<ResetPasswordRequest>
+    for header::IntoHeaderValue<CreateWebhookRequest>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <ResetPasswordRequest as std::str::FromStr>::from_str(value) {
+                match <CreateWebhookRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into ResetPasswordRequest - {}",
+                        "Unable to convert header value '{}' into CreateWebhookRequest - {}",
                         value, err
                     )),
                 }
@@ -773,34 +779,34 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SignUpFinishRequest {
-    #[serde(rename = "code")]
-    pub code: String,
+pub struct EditMessageRequest {
+    #[serde(rename = "body")]
+    pub body: String,
 }
 
-impl SignUpFinishRequest {
+impl EditMessageRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new(code: String) -> SignUpFinishRequest {
-        SignUpFinishRequest { code }
+    pub fn new(body: String) -> EditMessageRequest {
+        EditMessageRequest { body }
     }
 }
 
-/// Converts the SignUpFinishRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the EditMessageRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SignUpFinishRequest {
+impl std::string::ToString for EditMessageRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> =
-            vec![Some("code".to_string()), Some(self.code.to_string())];
+            vec![Some("body".to_string()), Some(self.body.to_string())];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SignUpFinishRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a EditMessageRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SignUpFinishRequest {
+impl std::str::FromStr for EditMessageRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -808,7 +814,7 @@ impl std::str::FromStr for SignUpFinishRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub code: Vec<String>,
+            pub body: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -822,7 +828,7 @@ impl std::str::FromStr for SignUpFinishRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SignUpFinishRequest".to_string(),
+                        "Missing value while parsing EditMessageRequest".to_string(),
                     )
                 }
             };
@@ -831,12 +837,12 @@ impl std::str::FromStr for SignUpFinishRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "code" => intermediate_rep.code.push(
+                    "body" => intermediate_rep.body.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SignUpFinishRequest".to_string(),
+                            "Unexpected key while parsing EditMessageRequest".to_string(),
                         )
                     }
                 }
@@ -847,32 +853,32 @@ impl std::str::FromStr for SignUpFinishRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SignUpFinishRequest {
-            code: intermediate_rep
-                .code
+        std::result::Result::Ok(EditMessageRequest {
+            body: intermediate_rep
+                .body
                 .into_iter()
                 .next()
-                .ok_or_else(|| "code missing in SignUpFinishRequest".to_string())?,
+                .ok_or_else(|| "body missing in EditMessageRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SignUpFinishRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<EditMessageRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SignUpFinishRequest>>
+impl std::convert::TryFrom<header::IntoHeaderValue<EditMessageRequest>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SignUpFinishRequest>,
+        hdr_value: header::IntoHeaderValue<EditMessageRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SignUpFinishRequest - value: {} is invalid {}",
+                "Invalid header value for EditMessageRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -881,19 +887,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SignUpFinishRequest>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<SignUpFinishRequest>
+    for header::IntoHeaderValue<EditMessageRequest>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SignUpFinishRequest as std::str::FromStr>::from_str(value) {
+                match <EditMessageRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SignUpFinishRequest - {}",
+                        "Unable to convert header value '{}' into EditMessageRequest - {}",
                         value, err
                     )),
                 }
@@ -908,41 +914,2882 @@ impl std::convert::TryFrom<hyper::header::HeaderValue>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SignUpRequest {
-    #[serde(rename = "mail")]
+pub struct ErrorMessage {
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "status")]
+    pub status: i32,
+
+    #[serde(rename = "code")]
+    pub code: String,
+
+    #[serde(rename = "detail")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    #[serde(rename = "instance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+
+    #[serde(rename = "request_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+
+    #[serde(rename = "invalid_params")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invalid_params: Option<Vec<models::InvalidParam>>,
+}
+
+impl ErrorMessage {
+    #[allow(clippy::new_without_default)]
+    pub fn new(r#type: String, title: String, status: i32, code: String) -> ErrorMessage {
+        ErrorMessage {
+            r#type,
+            title,
+            status,
+            code,
+            detail: None,
+            instance: None,
+            request_id: None,
+            invalid_params: None,
+        }
+    }
+}
+
+/// Converts the ErrorMessage value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ErrorMessage {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            self.detail
+                .as_ref()
+                .map(|detail| vec!["detail".to_string(), detail.to_string()].join(",")),
+            self.instance
+                .as_ref()
+                .map(|instance| vec!["instance".to_string(), instance.to_string()].join(",")),
+            self.request_id
+                .as_ref()
+                .map(|request_id| vec!["request_id".to_string(), request_id.to_string()].join(",")),
+            // Skipping invalid_params in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ErrorMessage value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ErrorMessage {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub r#type: Vec<String>,
+            pub title: Vec<String>,
+            pub status: Vec<i32>,
+            pub code: Vec<String>,
+            pub detail: Vec<String>,
+            pub instance: Vec<String>,
+            pub request_id: Vec<String>,
+            pub invalid_params: Vec<Vec<models::InvalidParam>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ErrorMessage".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "detail" => intermediate_rep.detail.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "instance" => intermediate_rep.instance.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "request_id" => intermediate_rep.request_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "invalid_params" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in ErrorMessage"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ErrorMessage".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ErrorMessage {
+            r#type: intermediate_rep
+                .r#type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "type missing in ErrorMessage".to_string())?,
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in ErrorMessage".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in ErrorMessage".to_string())?,
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in ErrorMessage".to_string())?,
+            detail: intermediate_rep.detail.into_iter().next(),
+            instance: intermediate_rep.instance.into_iter().next(),
+            request_id: intermediate_rep.request_id.into_iter().next(),
+            invalid_params: intermediate_rep.invalid_params.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ErrorMessage> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ErrorMessage>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ErrorMessage>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ErrorMessage - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ErrorMessage> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ErrorMessage as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ErrorMessage - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ForgetPasswordRequest {
+    #[serde(rename = "mail")]
     pub mail: String,
+}
+
+impl ForgetPasswordRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(mail: String) -> ForgetPasswordRequest {
+        ForgetPasswordRequest { mail }
+    }
+}
+
+/// Converts the ForgetPasswordRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ForgetPasswordRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> =
+            vec![Some("mail".to_string()), Some(self.mail.to_string())];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ForgetPasswordRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ForgetPasswordRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub mail: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ForgetPasswordRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "mail" => intermediate_rep.mail.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ForgetPasswordRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ForgetPasswordRequest {
+            mail: intermediate_rep
+                .mail
+                .into_iter()
+                .next()
+                .ok_or_else(|| "mail missing in ForgetPasswordRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ForgetPasswordRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ForgetPasswordRequest>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ForgetPasswordRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ForgetPasswordRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ForgetPasswordRequest>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ForgetPasswordRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ForgetPasswordRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Health {
+    #[serde(rename = "status")]
+    pub status: String,
+
+    #[serde(rename = "checks")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checks: Option<Vec<models::HealthCheck>>,
+}
+
+impl Health {
+    #[allow(clippy::new_without_default)]
+    pub fn new(status: String) -> Health {
+        Health {
+            status,
+            checks: None,
+        }
+    }
+}
+
+/// Converts the Health value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Health {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            // Skipping checks in query parameter serialization
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Health value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Health {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub status: Vec<String>,
+            pub checks: Vec<Vec<models::HealthCheck>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing Health".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "checks" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Health"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Health".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Health {
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in Health".to_string())?,
+            checks: intermediate_rep.checks.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Health> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Health>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Health>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Health - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Health> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <Health as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into Health - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct HealthCheck {
+    /// データベースは動いているバックエンド (mysql, postgres, sqlite) の名前
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "status")]
+    pub status: String,
+
+    #[serde(rename = "latency_ms")]
+    pub latency_ms: i64,
+}
+
+impl HealthCheck {
+    #[allow(clippy::new_without_default)]
+    pub fn new(name: String, status: String, latency_ms: i64) -> HealthCheck {
+        HealthCheck {
+            name,
+            status,
+            latency_ms,
+        }
+    }
+}
+
+/// Converts the HealthCheck value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for HealthCheck {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            Some("latency_ms".to_string()),
+            Some(self.latency_ms.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a HealthCheck value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for HealthCheck {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub status: Vec<String>,
+            pub latency_ms: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing HealthCheck".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "latency_ms" => intermediate_rep.latency_ms.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing HealthCheck".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(HealthCheck {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in HealthCheck".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in HealthCheck".to_string())?,
+            latency_ms: intermediate_rep
+                .latency_ms
+                .into_iter()
+                .next()
+                .ok_or_else(|| "latency_ms missing in HealthCheck".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<HealthCheck> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<HealthCheck>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<HealthCheck>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for HealthCheck - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<HealthCheck> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <HealthCheck as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into HealthCheck - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct InvalidParam {
+    #[serde(rename = "name")]
+    pub name: String,
+
+    #[serde(rename = "code")]
+    pub code: String,
+
+    #[serde(rename = "reason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl InvalidParam {
+    #[allow(clippy::new_without_default)]
+    pub fn new(name: String, code: String) -> InvalidParam {
+        InvalidParam {
+            name,
+            code,
+            reason: None,
+        }
+    }
+}
+
+/// Converts the InvalidParam value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for InvalidParam {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            self.reason
+                .as_ref()
+                .map(|reason| vec!["reason".to_string(), reason.to_string()].join(",")),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a InvalidParam value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for InvalidParam {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub code: Vec<String>,
+            pub reason: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing InvalidParam".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "reason" => intermediate_rep.reason.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing InvalidParam".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(InvalidParam {
+            name: intermediate_rep
+                .name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "name missing in InvalidParam".to_string())?,
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in InvalidParam".to_string())?,
+            reason: intermediate_rep.reason.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<InvalidParam> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<InvalidParam>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<InvalidParam>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for InvalidParam - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<InvalidParam> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <InvalidParam as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into InvalidParam - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct MarkReadRequest {
+    #[serde(rename = "message_id")]
+    pub message_id: String,
+}
+
+impl MarkReadRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(message_id: String) -> MarkReadRequest {
+        MarkReadRequest { message_id }
+    }
+}
+
+/// Converts the MarkReadRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for MarkReadRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("message_id".to_string()),
+            Some(self.message_id.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a MarkReadRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for MarkReadRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub message_id: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing MarkReadRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "message_id" => intermediate_rep.message_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing MarkReadRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(MarkReadRequest {
+            message_id: intermediate_rep
+                .message_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message_id missing in MarkReadRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<MarkReadRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<MarkReadRequest>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<MarkReadRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for MarkReadRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<MarkReadRequest>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <MarkReadRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into MarkReadRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Message {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "channel_id")]
+    pub channel_id: String,
+
+    #[serde(rename = "account_id")]
+    pub account_id: String,
+
+    #[serde(rename = "body")]
+    pub body: String,
+
+    #[serde(rename = "parent_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+
+    #[serde(rename = "reply_count")]
+    pub reply_count: i64,
+
+    #[serde(rename = "create_time")]
+    pub create_time: String,
+
+    #[serde(rename = "update_time")]
+    pub update_time: String,
+}
+
+impl Message {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        id: String,
+        channel_id: String,
+        account_id: String,
+        body: String,
+        reply_count: i64,
+        create_time: String,
+        update_time: String,
+    ) -> Message {
+        Message {
+            id,
+            channel_id,
+            account_id,
+            body,
+            parent_id: None,
+            reply_count,
+            create_time,
+            update_time,
+        }
+    }
+}
+
+/// Converts the Message value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Message {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("channel_id".to_string()),
+            Some(self.channel_id.to_string()),
+            Some("account_id".to_string()),
+            Some(self.account_id.to_string()),
+            Some("body".to_string()),
+            Some(self.body.to_string()),
+            self.parent_id
+                .as_ref()
+                .map(|parent_id| vec!["parent_id".to_string(), parent_id.to_string()].join(",")),
+            Some("reply_count".to_string()),
+            Some(self.reply_count.to_string()),
+            Some("create_time".to_string()),
+            Some(self.create_time.to_string()),
+            Some("update_time".to_string()),
+            Some(self.update_time.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Message value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Message {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub channel_id: Vec<String>,
+            pub account_id: Vec<String>,
+            pub body: Vec<String>,
+            pub parent_id: Vec<String>,
+            pub reply_count: Vec<i64>,
+            pub create_time: Vec<String>,
+            pub update_time: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing Message".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "channel_id" => intermediate_rep.channel_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "account_id" => intermediate_rep.account_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "body" => intermediate_rep.body.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "parent_id" => intermediate_rep.parent_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "reply_count" => intermediate_rep.reply_count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "create_time" => intermediate_rep.create_time.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "update_time" => intermediate_rep.update_time.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Message".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Message {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in Message".to_string())?,
+            channel_id: intermediate_rep
+                .channel_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "channel_id missing in Message".to_string())?,
+            account_id: intermediate_rep
+                .account_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "account_id missing in Message".to_string())?,
+            body: intermediate_rep
+                .body
+                .into_iter()
+                .next()
+                .ok_or_else(|| "body missing in Message".to_string())?,
+            parent_id: intermediate_rep.parent_id.into_iter().next(),
+            reply_count: intermediate_rep
+                .reply_count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "reply_count missing in Message".to_string())?,
+            create_time: intermediate_rep
+                .create_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "create_time missing in Message".to_string())?,
+            update_time: intermediate_rep
+                .update_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "update_time missing in Message".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Message> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<Message>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Message>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Message - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Message> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <Message as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into Message - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct MessageEdit {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "message_id")]
+    pub message_id: String,
+
+    #[serde(rename = "body")]
+    pub body: String,
+
+    #[serde(rename = "edited_by")]
+    pub edited_by: String,
+
+    #[serde(rename = "create_time")]
+    pub create_time: String,
+}
+
+impl MessageEdit {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        id: String,
+        message_id: String,
+        body: String,
+        edited_by: String,
+        create_time: String,
+    ) -> MessageEdit {
+        MessageEdit {
+            id,
+            message_id,
+            body,
+            edited_by,
+            create_time,
+        }
+    }
+}
+
+/// Converts the MessageEdit value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for MessageEdit {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("message_id".to_string()),
+            Some(self.message_id.to_string()),
+            Some("body".to_string()),
+            Some(self.body.to_string()),
+            Some("edited_by".to_string()),
+            Some(self.edited_by.to_string()),
+            Some("create_time".to_string()),
+            Some(self.create_time.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a MessageEdit value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for MessageEdit {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<String>,
+            pub message_id: Vec<String>,
+            pub body: Vec<String>,
+            pub edited_by: Vec<String>,
+            pub create_time: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing MessageEdit".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message_id" => intermediate_rep.message_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "body" => intermediate_rep.body.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "edited_by" => intermediate_rep.edited_by.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "create_time" => intermediate_rep.create_time.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing MessageEdit".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(MessageEdit {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in MessageEdit".to_string())?,
+            message_id: intermediate_rep
+                .message_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message_id missing in MessageEdit".to_string())?,
+            body: intermediate_rep
+                .body
+                .into_iter()
+                .next()
+                .ok_or_else(|| "body missing in MessageEdit".to_string())?,
+            edited_by: intermediate_rep
+                .edited_by
+                .into_iter()
+                .next()
+                .ok_or_else(|| "edited_by missing in MessageEdit".to_string())?,
+            create_time: intermediate_rep
+                .create_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "create_time missing in MessageEdit".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<MessageEdit> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<MessageEdit>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<MessageEdit>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for MessageEdit - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<MessageEdit> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <MessageEdit as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into MessageEdit - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ReactionCount {
+    #[serde(rename = "emoji")]
+    pub emoji: String,
+
+    #[serde(rename = "count")]
+    pub count: i64,
+}
+
+impl ReactionCount {
+    #[allow(clippy::new_without_default)]
+    pub fn new(emoji: String, count: i64) -> ReactionCount {
+        ReactionCount { emoji, count }
+    }
+}
+
+/// Converts the ReactionCount value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ReactionCount {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("emoji".to_string()),
+            Some(self.emoji.to_string()),
+            Some("count".to_string()),
+            Some(self.count.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ReactionCount value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ReactionCount {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub emoji: Vec<String>,
+            pub count: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ReactionCount".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "emoji" => intermediate_rep.emoji.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "count" => intermediate_rep.count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ReactionCount".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ReactionCount {
+            emoji: intermediate_rep
+                .emoji
+                .into_iter()
+                .next()
+                .ok_or_else(|| "emoji missing in ReactionCount".to_string())?,
+            count: intermediate_rep
+                .count
+                .into_iter()
+                .next()
+                .ok_or_else(|| "count missing in ReactionCount".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ReactionCount> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ReactionCount>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ReactionCount>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ReactionCount - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ReactionCount> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ReactionCount as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ReactionCount - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ReadPosition {
+    #[serde(rename = "channel_id")]
+    pub channel_id: String,
+
+    #[serde(rename = "account_id")]
+    pub account_id: String,
+
+    #[serde(rename = "message_id")]
+    pub message_id: String,
+
+    #[serde(rename = "update_time")]
+    pub update_time: String,
+}
+
+impl ReadPosition {
+    #[allow(clippy::new_without_default)]
+    pub fn new(
+        channel_id: String,
+        account_id: String,
+        message_id: String,
+        update_time: String,
+    ) -> ReadPosition {
+        ReadPosition {
+            channel_id,
+            account_id,
+            message_id,
+            update_time,
+        }
+    }
+}
+
+/// Converts the ReadPosition value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ReadPosition {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("channel_id".to_string()),
+            Some(self.channel_id.to_string()),
+            Some("account_id".to_string()),
+            Some(self.account_id.to_string()),
+            Some("message_id".to_string()),
+            Some(self.message_id.to_string()),
+            Some("update_time".to_string()),
+            Some(self.update_time.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ReadPosition value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ReadPosition {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub channel_id: Vec<String>,
+            pub account_id: Vec<String>,
+            pub message_id: Vec<String>,
+            pub update_time: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ReadPosition".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "channel_id" => intermediate_rep.channel_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "account_id" => intermediate_rep.account_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message_id" => intermediate_rep.message_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "update_time" => intermediate_rep.update_time.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ReadPosition".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ReadPosition {
+            channel_id: intermediate_rep
+                .channel_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "channel_id missing in ReadPosition".to_string())?,
+            account_id: intermediate_rep
+                .account_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "account_id missing in ReadPosition".to_string())?,
+            message_id: intermediate_rep
+                .message_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message_id missing in ReadPosition".to_string())?,
+            update_time: intermediate_rep
+                .update_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "update_time missing in ReadPosition".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ReadPosition> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ReadPosition>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ReadPosition>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ReadPosition - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<ReadPosition> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ReadPosition as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ReadPosition - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ResetPasswordRequest {
+    #[serde(rename = "code")]
+    pub code: String,
+
+    #[serde(rename = "password")]
+    pub password: String,
+}
+
+impl ResetPasswordRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(code: String, password: String) -> ResetPasswordRequest {
+        ResetPasswordRequest { code, password }
+    }
+}
+
+/// Converts the ResetPasswordRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for ResetPasswordRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("password".to_string()),
+            Some(self.password.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ResetPasswordRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ResetPasswordRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+            pub password: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ResetPasswordRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "password" => intermediate_rep.password.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ResetPasswordRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ResetPasswordRequest {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in ResetPasswordRequest".to_string())?,
+            password: intermediate_rep
+                .password
+                .into_iter()
+                .next()
+                .ok_or_else(|| "password missing in ResetPasswordRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ResetPasswordRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<ResetPasswordRequest>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ResetPasswordRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ResetPasswordRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<ResetPasswordRequest>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ResetPasswordRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ResetPasswordRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SignUpFinishRequest {
+    #[serde(rename = "code")]
+    pub code: String,
+}
+
+impl SignUpFinishRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(code: String) -> SignUpFinishRequest {
+        SignUpFinishRequest { code }
+    }
+}
+
+/// Converts the SignUpFinishRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SignUpFinishRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> =
+            vec![Some("code".to_string()), Some(self.code.to_string())];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SignUpFinishRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SignUpFinishRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub code: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SignUpFinishRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SignUpFinishRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SignUpFinishRequest {
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in SignUpFinishRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SignUpFinishRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SignUpFinishRequest>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SignUpFinishRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SignUpFinishRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<SignUpFinishRequest>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SignUpFinishRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SignUpFinishRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SignUpRequest {
+    #[serde(rename = "mail")]
+    pub mail: String,
+
+    #[serde(rename = "password")]
+    pub password: String,
+}
+
+impl SignUpRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(mail: String, password: String) -> SignUpRequest {
+        SignUpRequest { mail, password }
+    }
+}
+
+/// Converts the SignUpRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SignUpRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("mail".to_string()),
+            Some(self.mail.to_string()),
+            Some("password".to_string()),
+            Some(self.password.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SignUpRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SignUpRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub mail: Vec<String>,
+            pub password: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SignUpRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "mail" => intermediate_rep.mail.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "password" => intermediate_rep.password.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SignUpRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SignUpRequest {
+            mail: intermediate_rep
+                .mail
+                .into_iter()
+                .next()
+                .ok_or_else(|| "mail missing in SignUpRequest".to_string())?,
+            password: intermediate_rep
+                .password
+                .into_iter()
+                .next()
+                .ok_or_else(|| "password missing in SignUpRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SignUpRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SignUpRequest>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SignUpRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SignUpRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SignUpRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SignUpRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SignUpRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct SigninRequest {
+    #[serde(rename = "mail")]
+    pub mail: String,
+
+    #[serde(rename = "password")]
+    pub password: String,
+
+    #[serde(rename = "remember_me")]
+    pub remember_me: bool,
+}
+
+impl SigninRequest {
+    #[allow(clippy::new_without_default)]
+    pub fn new(mail: String, password: String) -> SigninRequest {
+        SigninRequest {
+            mail,
+            password,
+            remember_me: false,
+        }
+    }
+}
+
+/// Converts the SigninRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for SigninRequest {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("mail".to_string()),
+            Some(self.mail.to_string()),
+            Some("password".to_string()),
+            Some(self.password.to_string()),
+            Some("remember_me".to_string()),
+            Some(self.remember_me.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a SigninRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for SigninRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub mail: Vec<String>,
+            pub password: Vec<String>,
+            pub remember_me: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing SigninRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "mail" => intermediate_rep.mail.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "password" => intermediate_rep.password.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "remember_me" => intermediate_rep.remember_me.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing SigninRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(SigninRequest {
+            mail: intermediate_rep
+                .mail
+                .into_iter()
+                .next()
+                .ok_or_else(|| "mail missing in SigninRequest".to_string())?,
+            password: intermediate_rep
+                .password
+                .into_iter()
+                .next()
+                .ok_or_else(|| "password missing in SigninRequest".to_string())?,
+            remember_me: intermediate_rep
+                .remember_me
+                .into_iter()
+                .next()
+                .ok_or_else(|| "remember_me missing in SigninRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SigninRequest> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<SigninRequest>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<SigninRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for SigninRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SigninRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <SigninRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into SigninRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct StatusOk {
+    #[serde(rename = "status")]
+    pub status: String,
+}
+
+impl StatusOk {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> StatusOk {
+        StatusOk {
+            status: "OK".to_string(),
+        }
+    }
+}
+
+/// Converts the StatusOk value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for StatusOk {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> =
+            vec![Some("status".to_string()), Some(self.status.to_string())];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a StatusOk value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for StatusOk {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub status: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing StatusOk".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing StatusOk".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(StatusOk {
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in StatusOk".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<StatusOk> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<StatusOk>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<StatusOk>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for StatusOk - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StatusOk> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <StatusOk as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into StatusOk - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct StatusResponse {
+    #[serde(rename = "status")]
+    pub status: String,
+
+    #[serde(rename = "version")]
+    pub version: String,
+
+    #[serde(rename = "build_timestamp")]
+    pub build_timestamp: String,
+}
+
+impl StatusResponse {
+    #[allow(clippy::new_without_default)]
+    pub fn new(status: String, version: String, build_timestamp: String) -> StatusResponse {
+        StatusResponse {
+            status,
+            version,
+            build_timestamp,
+        }
+    }
+}
+
+/// Converts the StatusResponse value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for StatusResponse {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+            Some("build_timestamp".to_string()),
+            Some(self.build_timestamp.to_string()),
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a StatusResponse value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for StatusResponse {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub status: Vec<String>,
+            pub version: Vec<String>,
+            pub build_timestamp: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing StatusResponse".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "build_timestamp" => intermediate_rep.build_timestamp.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing StatusResponse".to_string(),
+                        )
+                    }
+                }
+            }
 
-    #[serde(rename = "password")]
-    pub password: String,
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(StatusResponse {
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in StatusResponse".to_string())?,
+            version: intermediate_rep
+                .version
+                .into_iter()
+                .next()
+                .ok_or_else(|| "version missing in StatusResponse".to_string())?,
+            build_timestamp: intermediate_rep
+                .build_timestamp
+                .into_iter()
+                .next()
+                .ok_or_else(|| "build_timestamp missing in StatusResponse".to_string())?,
+        })
+    }
 }
 
-impl SignUpRequest {
+// Methods for converting between header::IntoHeaderValue<StatusResponse> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<StatusResponse>> for hyper::header::HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<StatusResponse>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for StatusResponse - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StatusResponse> {
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <StatusResponse as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into StatusResponse - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct StatusWithMessage {
+    #[serde(rename = "status")]
+    pub status: String,
+
+    #[serde(rename = "message")]
+    pub message: String,
+}
+
+impl StatusWithMessage {
     #[allow(clippy::new_without_default)]
-    pub fn new(mail: String, password: String) -> SignUpRequest {
-        SignUpRequest { mail, password }
+    pub fn new(status: String, message: String) -> StatusWithMessage {
+        StatusWithMessage { status, message }
     }
 }
 
-/// Converts the SignUpRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the StatusWithMessage value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SignUpRequest {
+impl std::string::ToString for StatusWithMessage {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("mail".to_string()),
-            Some(self.mail.to_string()),
-            Some("password".to_string()),
-            Some(self.password.to_string()),
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            Some("message".to_string()),
+            Some(self.message.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SignUpRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a StatusWithMessage value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for StatusWithMessage {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub status: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing StatusWithMessage".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing StatusWithMessage".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(StatusWithMessage {
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in StatusWithMessage".to_string())?,
+            message: intermediate_rep
+                .message
+                .into_iter()
+                .next()
+                .ok_or_else(|| "message missing in StatusWithMessage".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<StatusWithMessage> and hyper::header::HeaderValue
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<header::IntoHeaderValue<StatusWithMessage>>
+    for hyper::header::HeaderValue
+{
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<StatusWithMessage>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match hyper::header::HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for StatusWithMessage - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(any(feature = "client", feature = "server"))]
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<StatusWithMessage>
+{
+    type Error = String;
+
+    fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <StatusWithMessage as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into StatusWithMessage - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Thread {
+    #[serde(rename = "parent")]
+    pub parent: models::Message,
+
+    #[serde(rename = "replies")]
+    pub replies: Vec<models::Message>,
+}
+
+impl Thread {
+    #[allow(clippy::new_without_default)]
+    pub fn new(parent: models::Message, replies: Vec<models::Message>) -> Thread {
+        Thread { parent, replies }
+    }
+}
+
+/// Converts the Thread value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::string::ToString for Thread {
+    fn to_string(&self) -> String {
+        let params: Vec<Option<String>> = vec![
+            // Skipping parent in query parameter serialization
+
+            // Skipping replies in query parameter serialization
+
+        ];
+
+        params.into_iter().flatten().collect::<Vec<_>>().join(",")
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Thread value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SignUpRequest {
+impl std::str::FromStr for Thread {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -950,8 +3797,8 @@ impl std::str::FromStr for SignUpRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub mail: Vec<String>,
-            pub password: Vec<String>,
+            pub parent: Vec<models::Message>,
+            pub replies: Vec<Vec<models::Message>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -965,7 +3812,7 @@ impl std::str::FromStr for SignUpRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SignUpRequest".to_string(),
+                        "Missing value while parsing Thread".to_string(),
                     )
                 }
             };
@@ -974,16 +3821,19 @@ impl std::str::FromStr for SignUpRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "mail" => intermediate_rep.mail.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
-                    ),
-                    #[allow(clippy::redundant_clone)]
-                    "password" => intermediate_rep.password.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "parent" => intermediate_rep.parent.push(
+                        <models::Message as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
+                    "replies" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Thread"
+                                .to_string(),
+                        )
+                    }
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SignUpRequest".to_string(),
+                            "Unexpected key while parsing Thread".to_string(),
                         )
                     }
                 }
@@ -994,35 +3844,35 @@ impl std::str::FromStr for SignUpRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SignUpRequest {
-            mail: intermediate_rep
-                .mail
+        std::result::Result::Ok(Thread {
+            parent: intermediate_rep
+                .parent
                 .into_iter()
                 .next()
-                .ok_or_else(|| "mail missing in SignUpRequest".to_string())?,
-            password: intermediate_rep
-                .password
+                .ok_or_else(|| "parent missing in Thread".to_string())?,
+            replies: intermediate_rep
+                .replies
                 .into_iter()
                 .next()
-                .ok_or_else(|| "password missing in SignUpRequest".to_string())?,
+                .ok_or_else(|| "replies missing in Thread".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SignUpRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Thread> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SignUpRequest>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Thread>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SignUpRequest>,
+        hdr_value: header::IntoHeaderValue<Thread>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SignUpRequest - value: {} is invalid {}",
+                "Invalid header value for Thread - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1030,18 +3880,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SignUpRequest>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SignUpRequest> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Thread> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SignUpRequest as std::str::FromStr>::from_str(value) {
+                match <Thread as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SignUpRequest - {}",
+                        "Unable to convert header value '{}' into Thread - {}",
                         value, err
                     )),
                 }
@@ -1056,50 +3906,52 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct SigninRequest {
-    #[serde(rename = "mail")]
-    pub mail: String,
+pub struct UnreadCount {
+    #[serde(rename = "channel_id")]
+    pub channel_id: String,
 
-    #[serde(rename = "password")]
-    pub password: String,
+    #[serde(rename = "message_id")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<String>,
 
-    #[serde(rename = "remember_me")]
-    pub remember_me: bool,
+    #[serde(rename = "count")]
+    pub count: i64,
 }
 
-impl SigninRequest {
+impl UnreadCount {
     #[allow(clippy::new_without_default)]
-    pub fn new(mail: String, password: String) -> SigninRequest {
-        SigninRequest {
-            mail,
-            password,
-            remember_me: false,
+    pub fn new(channel_id: String, count: i64) -> UnreadCount {
+        UnreadCount {
+            channel_id,
+            message_id: None,
+            count,
         }
     }
 }
 
-/// Converts the SigninRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the UnreadCount value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for SigninRequest {
+impl std::string::ToString for UnreadCount {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("mail".to_string()),
-            Some(self.mail.to_string()),
-            Some("password".to_string()),
-            Some(self.password.to_string()),
-            Some("remember_me".to_string()),
-            Some(self.remember_me.to_string()),
+            Some("channel_id".to_string()),
+            Some(self.channel_id.to_string()),
+            self.message_id
+                .as_ref()
+                .map(|message_id| vec!["message_id".to_string(), message_id.to_string()].join(",")),
+            Some("count".to_string()),
+            Some(self.count.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a SigninRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a UnreadCount value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for SigninRequest {
+impl std::str::FromStr for UnreadCount {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1107,9 +3959,9 @@ impl std::str::FromStr for SigninRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub mail: Vec<String>,
-            pub password: Vec<String>,
-            pub remember_me: Vec<bool>,
+            pub channel_id: Vec<String>,
+            pub message_id: Vec<String>,
+            pub count: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1123,7 +3975,7 @@ impl std::str::FromStr for SigninRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing SigninRequest".to_string(),
+                        "Missing value while parsing UnreadCount".to_string(),
                     )
                 }
             };
@@ -1132,20 +3984,20 @@ impl std::str::FromStr for SigninRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "mail" => intermediate_rep.mail.push(
+                    "channel_id" => intermediate_rep.channel_id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "password" => intermediate_rep.password.push(
+                    "message_id" => intermediate_rep.message_id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "remember_me" => intermediate_rep.remember_me.push(
-                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "count" => intermediate_rep.count.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing SigninRequest".to_string(),
+                            "Unexpected key while parsing UnreadCount".to_string(),
                         )
                     }
                 }
@@ -1156,40 +4008,36 @@ impl std::str::FromStr for SigninRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(SigninRequest {
-            mail: intermediate_rep
-                .mail
-                .into_iter()
-                .next()
-                .ok_or_else(|| "mail missing in SigninRequest".to_string())?,
-            password: intermediate_rep
-                .password
+        std::result::Result::Ok(UnreadCount {
+            channel_id: intermediate_rep
+                .channel_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "password missing in SigninRequest".to_string())?,
-            remember_me: intermediate_rep
-                .remember_me
+                .ok_or_else(|| "channel_id missing in UnreadCount".to_string())?,
+            message_id: intermediate_rep.message_id.into_iter().next(),
+            count: intermediate_rep
+                .count
                 .into_iter()
                 .next()
-                .ok_or_else(|| "remember_me missing in SigninRequest".to_string())?,
+                .ok_or_else(|| "count missing in UnreadCount".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<SigninRequest> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<UnreadCount> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<SigninRequest>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<UnreadCount>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<SigninRequest>,
+        hdr_value: header::IntoHeaderValue<UnreadCount>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for SigninRequest - value: {} is invalid {}",
+                "Invalid header value for UnreadCount - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1197,18 +4045,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<SigninRequest>> for hyper::he
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<SigninRequest> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<UnreadCount> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <SigninRequest as std::str::FromStr>::from_str(value) {
+                match <UnreadCount as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into SigninRequest - {}",
+                        "Unable to convert header value '{}' into UnreadCount - {}",
                         value, err
                     )),
                 }
@@ -1223,36 +4071,34 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct StatusOk {
-    #[serde(rename = "status")]
-    pub status: String,
+pub struct UpdateLocaleRequest {
+    #[serde(rename = "locale")]
+    pub locale: String,
 }
 
-impl StatusOk {
+impl UpdateLocaleRequest {
     #[allow(clippy::new_without_default)]
-    pub fn new() -> StatusOk {
-        StatusOk {
-            status: "OK".to_string(),
-        }
+    pub fn new(locale: String) -> UpdateLocaleRequest {
+        UpdateLocaleRequest { locale }
     }
 }
 
-/// Converts the StatusOk value to the Query Parameters representation (style=form, explode=false)
+/// Converts the UpdateLocaleRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for StatusOk {
+impl std::string::ToString for UpdateLocaleRequest {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> =
-            vec![Some("status".to_string()), Some(self.status.to_string())];
+            vec![Some("locale".to_string()), Some(self.locale.to_string())];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a StatusOk value
+/// Converts Query Parameters representation (style=form, explode=false) to a UpdateLocaleRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for StatusOk {
+impl std::str::FromStr for UpdateLocaleRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1260,7 +4106,7 @@ impl std::str::FromStr for StatusOk {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub status: Vec<String>,
+            pub locale: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1274,7 +4120,7 @@ impl std::str::FromStr for StatusOk {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing StatusOk".to_string(),
+                        "Missing value while parsing UpdateLocaleRequest".to_string(),
                     )
                 }
             };
@@ -1283,12 +4129,12 @@ impl std::str::FromStr for StatusOk {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
+                    "locale" => intermediate_rep.locale.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing StatusOk".to_string(),
+                            "Unexpected key while parsing UpdateLocaleRequest".to_string(),
                         )
                     }
                 }
@@ -1299,30 +4145,32 @@ impl std::str::FromStr for StatusOk {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(StatusOk {
-            status: intermediate_rep
-                .status
+        std::result::Result::Ok(UpdateLocaleRequest {
+            locale: intermediate_rep
+                .locale
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in StatusOk".to_string())?,
+                .ok_or_else(|| "locale missing in UpdateLocaleRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<StatusOk> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<UpdateLocaleRequest> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<StatusOk>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<UpdateLocaleRequest>>
+    for hyper::header::HeaderValue
+{
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<StatusOk>,
+        hdr_value: header::IntoHeaderValue<UpdateLocaleRequest>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for StatusOk - value: {} is invalid {}",
+                "Invalid header value for UpdateLocaleRequest - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1330,18 +4178,20 @@ impl std::convert::TryFrom<header::IntoHeaderValue<StatusOk>> for hyper::header:
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StatusOk> {
+impl std::convert::TryFrom<hyper::header::HeaderValue>
+    for header::IntoHeaderValue<UpdateLocaleRequest>
+{
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <StatusOk as std::str::FromStr>::from_str(value) {
+                match <UpdateLocaleRequest as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into StatusOk - {}",
+                        "Unable to convert header value '{}' into UpdateLocaleRequest - {}",
                         value, err
                     )),
                 }
@@ -1356,50 +4206,64 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct StatusResponse {
-    #[serde(rename = "status")]
-    pub status: String,
+pub struct Webhook {
+    #[serde(rename = "id")]
+    pub id: String,
 
-    #[serde(rename = "version")]
-    pub version: String,
+    #[serde(rename = "channel_id")]
+    pub channel_id: String,
 
-    #[serde(rename = "build_timestamp")]
-    pub build_timestamp: String,
+    #[serde(rename = "url")]
+    pub url: String,
+
+    #[serde(rename = "secret")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+
+    #[serde(rename = "create_time")]
+    pub create_time: String,
 }
 
-impl StatusResponse {
+impl Webhook {
     #[allow(clippy::new_without_default)]
-    pub fn new(status: String, version: String, build_timestamp: String) -> StatusResponse {
-        StatusResponse {
-            status,
-            version,
-            build_timestamp,
+    pub fn new(id: String, channel_id: String, url: String, create_time: String) -> Webhook {
+        Webhook {
+            id,
+            channel_id,
+            url,
+            secret: None,
+            create_time,
         }
     }
 }
 
-/// Converts the StatusResponse value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Webhook value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for StatusResponse {
+impl std::string::ToString for Webhook {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
-            Some("status".to_string()),
-            Some(self.status.to_string()),
-            Some("version".to_string()),
-            Some(self.version.to_string()),
-            Some("build_timestamp".to_string()),
-            Some(self.build_timestamp.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("channel_id".to_string()),
+            Some(self.channel_id.to_string()),
+            Some("url".to_string()),
+            Some(self.url.to_string()),
+            self.secret
+                .as_ref()
+                .map(|secret| vec!["secret".to_string(), secret.to_string()].join(",")),
+            Some("create_time".to_string()),
+            Some(self.create_time.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a StatusResponse value
+/// Converts Query Parameters representation (style=form, explode=false) to a Webhook value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for StatusResponse {
+impl std::str::FromStr for Webhook {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1407,9 +4271,11 @@ impl std::str::FromStr for StatusResponse {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub status: Vec<String>,
-            pub version: Vec<String>,
-            pub build_timestamp: Vec<String>,
+            pub id: Vec<String>,
+            pub channel_id: Vec<String>,
+            pub url: Vec<String>,
+            pub secret: Vec<String>,
+            pub create_time: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1423,7 +4289,7 @@ impl std::str::FromStr for StatusResponse {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing StatusResponse".to_string(),
+                        "Missing value while parsing Webhook".to_string(),
                     )
                 }
             };
@@ -1432,20 +4298,28 @@ impl std::str::FromStr for StatusResponse {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "status" => intermediate_rep.status.push(
+                    "id" => intermediate_rep.id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "version" => intermediate_rep.version.push(
+                    "channel_id" => intermediate_rep.channel_id.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "build_timestamp" => intermediate_rep.build_timestamp.push(
+                    "url" => intermediate_rep.url.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "secret" => intermediate_rep.secret.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "create_time" => intermediate_rep.create_time.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing StatusResponse".to_string(),
+                            "Unexpected key while parsing Webhook".to_string(),
                         )
                     }
                 }
@@ -1456,40 +4330,46 @@ impl std::str::FromStr for StatusResponse {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(StatusResponse {
-            status: intermediate_rep
-                .status
+        std::result::Result::Ok(Webhook {
+            id: intermediate_rep
+                .id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in StatusResponse".to_string())?,
-            version: intermediate_rep
-                .version
+                .ok_or_else(|| "id missing in Webhook".to_string())?,
+            channel_id: intermediate_rep
+                .channel_id
                 .into_iter()
                 .next()
-                .ok_or_else(|| "version missing in StatusResponse".to_string())?,
-            build_timestamp: intermediate_rep
-                .build_timestamp
+                .ok_or_else(|| "channel_id missing in Webhook".to_string())?,
+            url: intermediate_rep
+                .url
                 .into_iter()
                 .next()
-                .ok_or_else(|| "build_timestamp missing in StatusResponse".to_string())?,
+                .ok_or_else(|| "url missing in Webhook".to_string())?,
+            secret: intermediate_rep.secret.into_iter().next(),
+            create_time: intermediate_rep
+                .create_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "create_time missing in Webhook".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<StatusResponse> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<Webhook> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<StatusResponse>> for hyper::header::HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Webhook>> for hyper::header::HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<StatusResponse>,
+        hdr_value: header::IntoHeaderValue<Webhook>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for StatusResponse - value: {} is invalid {}",
+                "Invalid header value for Webhook - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1497,18 +4377,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<StatusResponse>> for hyper::h
 }
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<StatusResponse> {
+impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderValue<Webhook> {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <StatusResponse as std::str::FromStr>::from_str(value) {
+                match <Webhook as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into StatusResponse - {}",
+                        "Unable to convert header value '{}' into Webhook - {}",
                         value, err
                     )),
                 }
@@ -1523,41 +4403,91 @@ impl std::convert::TryFrom<hyper::header::HeaderValue> for header::IntoHeaderVal
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct StatusWithMessage {
+pub struct WebhookDelivery {
+    #[serde(rename = "id")]
+    pub id: String,
+
+    #[serde(rename = "webhook_id")]
+    pub webhook_id: String,
+
     #[serde(rename = "status")]
     pub status: String,
 
-    #[serde(rename = "message")]
-    pub message: String,
+    #[serde(rename = "attempts")]
+    pub attempts: i32,
+
+    #[serde(rename = "response_status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_status: Option<i32>,
+
+    #[serde(rename = "last_error")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+
+    #[serde(rename = "create_time")]
+    pub create_time: String,
+
+    #[serde(rename = "update_time")]
+    pub update_time: String,
 }
 
-impl StatusWithMessage {
+impl WebhookDelivery {
     #[allow(clippy::new_without_default)]
-    pub fn new(status: String, message: String) -> StatusWithMessage {
-        StatusWithMessage { status, message }
+    pub fn new(
+        id: String,
+        webhook_id: String,
+        status: String,
+        attempts: i32,
+        create_time: String,
+        update_time: String,
+    ) -> WebhookDelivery {
+        WebhookDelivery {
+            id,
+            webhook_id,
+            status,
+            attempts,
+            response_status: None,
+            last_error: None,
+            create_time,
+            update_time,
+        }
     }
 }
 
-/// Converts the StatusWithMessage value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WebhookDelivery value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::string::ToString for StatusWithMessage {
+impl std::string::ToString for WebhookDelivery {
     fn to_string(&self) -> String {
         let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("webhook_id".to_string()),
+            Some(self.webhook_id.to_string()),
             Some("status".to_string()),
             Some(self.status.to_string()),
-            Some("message".to_string()),
-            Some(self.message.to_string()),
+            Some("attempts".to_string()),
+            Some(self.attempts.to_string()),
+            self.response_status.as_ref().map(|response_status| {
+                vec!["response_status".to_string(), response_status.to_string()].join(",")
+            }),
+            self.last_error
+                .as_ref()
+                .map(|last_error| vec!["last_error".to_string(), last_error.to_string()].join(",")),
+            Some("create_time".to_string()),
+            Some(self.create_time.to_string()),
+            Some("update_time".to_string()),
+            Some(self.update_time.to_string()),
         ];
 
         params.into_iter().flatten().collect::<Vec<_>>().join(",")
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a StatusWithMessage value
+/// Converts Query Parameters representation (style=form, explode=false) to a WebhookDelivery value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for StatusWithMessage {
+impl std::str::FromStr for WebhookDelivery {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -1565,8 +4495,14 @@ impl std::str::FromStr for StatusWithMessage {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
+            pub id: Vec<String>,
+            pub webhook_id: Vec<String>,
             pub status: Vec<String>,
-            pub message: Vec<String>,
+            pub attempts: Vec<i32>,
+            pub response_status: Vec<i32>,
+            pub last_error: Vec<String>,
+            pub create_time: Vec<String>,
+            pub update_time: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -1580,7 +4516,7 @@ impl std::str::FromStr for StatusWithMessage {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing StatusWithMessage".to_string(),
+                        "Missing value while parsing WebhookDelivery".to_string(),
                     )
                 }
             };
@@ -1588,17 +4524,41 @@ impl std::str::FromStr for StatusWithMessage {
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "webhook_id" => intermediate_rep.webhook_id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     #[allow(clippy::redundant_clone)]
                     "status" => intermediate_rep.status.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "message" => intermediate_rep.message.push(
+                    "attempts" => intermediate_rep.attempts.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "response_status" => intermediate_rep.response_status.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last_error" => intermediate_rep.last_error.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "create_time" => intermediate_rep.create_time.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "update_time" => intermediate_rep.update_time.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing StatusWithMessage".to_string(),
+                            "Unexpected key while parsing WebhookDelivery".to_string(),
                         )
                     }
                 }
@@ -1609,37 +4569,59 @@ impl std::str::FromStr for StatusWithMessage {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(StatusWithMessage {
+        std::result::Result::Ok(WebhookDelivery {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in WebhookDelivery".to_string())?,
+            webhook_id: intermediate_rep
+                .webhook_id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "webhook_id missing in WebhookDelivery".to_string())?,
             status: intermediate_rep
                 .status
                 .into_iter()
                 .next()
-                .ok_or_else(|| "status missing in StatusWithMessage".to_string())?,
-            message: intermediate_rep
-                .message
+                .ok_or_else(|| "status missing in WebhookDelivery".to_string())?,
+            attempts: intermediate_rep
+                .attempts
                 .into_iter()
                 .next()
-                .ok_or_else(|| "message missing in StatusWithMessage".to_string())?,
+                .ok_or_else(|| "attempts missing in WebhookDelivery".to_string())?,
+            response_status: intermediate_rep.response_status.into_iter().next(),
+            last_error: intermediate_rep.last_error.into_iter().next(),
+            create_time: intermediate_rep
+                .create_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "create_time missing in WebhookDelivery".to_string())?,
+            update_time: intermediate_rep
+                .update_time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "update_time missing in WebhookDelivery".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<StatusWithMessage> and hyper::header::HeaderValue
+// Methods for converting between header::IntoHeaderValue<WebhookDelivery> and hyper::header::HeaderValue
 
 #[cfg(any(feature = "client", feature = "server"))]
-impl std::convert::TryFrom<header::IntoHeaderValue<StatusWithMessage>>
+impl std::convert::TryFrom<header::IntoHeaderValue<WebhookDelivery>>
     for hyper::header::HeaderValue
 {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<StatusWithMessage>,
+        hdr_value: header::IntoHeaderValue<WebhookDelivery>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match hyper::header::HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for StatusWithMessage - value: {} is invalid {}",
+                "Invalid header value for WebhookDelivery - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -1648,19 +4630,19 @@ impl std::convert::TryFrom<header::IntoHeaderValue<StatusWithMessage>>
 
 #[cfg(any(feature = "client", feature = "server"))]
 impl std::convert::TryFrom<hyper::header::HeaderValue>
-    for header::IntoHeaderValue<StatusWithMessage>
+    for header::IntoHeaderValue<WebhookDelivery>
 {
     type Error = String;
 
     fn try_from(hdr_value: hyper::header::HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <StatusWithMessage as std::str::FromStr>::from_str(value) {
+                match <WebhookDelivery as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into StatusWithMessage - {}",
+                        "Unable to convert header value '{}' into WebhookDelivery - {}",
                         value, err
                     )),
                 }
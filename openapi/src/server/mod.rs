@@ -245,7 +245,7 @@ where
                                         .expect("Unable to turn 0 into a StatusCode");
                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for GET_ACCOUNT_STATUS0"));
                                     let body = serde_json::to_string(&body)
                                         .expect("impossible to fail to serialize");
@@ -332,7 +332,7 @@ where
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for FORGET_PASSWORD_STATUS0"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
@@ -389,7 +389,7 @@ where
                                     .expect("Unable to turn 0 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for GET_AUTH_STATUS_STATUS0"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
@@ -475,7 +475,7 @@ where
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for RESET_PASSWORD_STATUS0"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
@@ -566,7 +566,7 @@ where
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for SIGNIN_STATUS0"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
@@ -626,7 +626,7 @@ where
                                         .expect("Unable to turn 0 into a StatusCode");
                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for SIGNOUT_STATUS0"));
                                     let body = serde_json::to_string(&body)
                                         .expect("impossible to fail to serialize");
@@ -713,7 +713,7 @@ where
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for SIGNUP_STATUS0"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
@@ -804,7 +804,7 @@ where
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for SIGNUP_FINISH_STATUS0"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
@@ -884,7 +884,7 @@ where
                                     .expect("Unable to turn 0 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for CHANNEL_COCKET_STATUS0"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
@@ -994,7 +994,7 @@ where
                                                     *response.status_mut() = StatusCode::from_u16(0).expect("Unable to turn 0 into a StatusCode");
                                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for PUBLISH_CHANNEL_STATUS0"));
                                                     let body = serde_json::to_string(&body).expect("impossible to fail to serialize");
                                                     *response.body_mut() = Body::from(body);
@@ -1074,7 +1074,7 @@ where
                                     .expect("Unable to turn 0 into a StatusCode");
                                 response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for SUBSCRIBE_CHANNEL_STATUS0"));
                                 let body = serde_json::to_string(&body)
                                     .expect("impossible to fail to serialize");
@@ -1127,7 +1127,7 @@ where
                                         .expect("Unable to turn 0 into a StatusCode");
                                     response.headers_mut().insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json")
+                                                        HeaderValue::from_str("application/problem+json")
                                                             .expect("Unable to create Content-Type header for GET_STATUS_STATUS0"));
                                     let body = serde_json::to_string(&body)
                                         .expect("impossible to fail to serialize");